@@ -0,0 +1,122 @@
+//! Search interactions and endpoints for the Algolia-backed Hacker News search API.
+
+use crate::{
+    client::RequestOptions,
+    errors::{HackerNewsClientError, HackerNewsResult},
+    http::InternalHttpClient,
+    items::threads::HackerNewsThread,
+    urls, HackerNewsID,
+};
+
+use super::{
+    HackerNewsSearchItem, HackerNewsSearchQuery, HackerNewsSearchResults, HackerNewsSearchUser,
+};
+
+const SEARCH_ENDPOINT: &str = "search";
+const SEARCH_BY_DATE_ENDPOINT: &str = "search_by_date";
+const ITEMS_ENDPOINT: &str = "items";
+const USERS_ENDPOINT: &str = "users";
+
+/// An internal search client for interacting with the search API.
+#[derive(Debug, Clone)]
+pub struct HackerNewsSearchClient {
+    internal_client: InternalHttpClient,
+}
+
+impl HackerNewsSearchClient {
+    /// Constructs a new instance of the search client from an HTTP client pointing to the search API.
+    pub fn new(internal_client: InternalHttpClient) -> Self {
+        Self { internal_client }
+    }
+
+    /// Searches stories and comments, ordering results by relevance, then points, then number of comments.
+    pub async fn search(
+        &self,
+        query: &HackerNewsSearchQuery,
+    ) -> HackerNewsResult<HackerNewsSearchResults> {
+        self.search_with(query, RequestOptions::default()).await
+    }
+
+    /// Searches stories and comments with the request options applied, ordering results by relevance, then points,
+    /// then number of comments.
+    pub async fn search_with(
+        &self,
+        query: &HackerNewsSearchQuery,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsSearchResults> {
+        self.internal_client
+            .get_resource(SEARCH_ENDPOINT, &query.parameters(), &options)
+            .await
+    }
+
+    /// Searches stories and comments, ordering results by date with the most recent first.
+    pub async fn search_by_date(
+        &self,
+        query: &HackerNewsSearchQuery,
+    ) -> HackerNewsResult<HackerNewsSearchResults> {
+        self.search_by_date_with(query, RequestOptions::default())
+            .await
+    }
+
+    /// Searches stories and comments with the request options applied, ordering results by date with the most
+    /// recent first.
+    pub async fn search_by_date_with(
+        &self,
+        query: &HackerNewsSearchQuery,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsSearchResults> {
+        self.internal_client
+            .get_resource(SEARCH_BY_DATE_ENDPOINT, &query.parameters(), &options)
+            .await
+    }
+
+    /// Retrieves an item along with its full tree of children in a single request.
+    pub async fn get_item(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsSearchItem> {
+        self.get_item_with(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves an item along with its full tree of children in a single request with the request options applied.
+    pub async fn get_item_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsSearchItem> {
+        self.internal_client
+            .get_resource(&format!("{}/{}", ITEMS_ENDPOINT, id), &[], &options)
+            .await
+    }
+
+    /// Retrieves an item's full comment thread in a single request, rather than an item at a time.
+    pub async fn get_thread(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsThread> {
+        self.get_thread_with(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves an item's full comment thread in a single request with the request options applied.
+    pub async fn get_thread_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsThread> {
+        self.get_item_with(id, options).await?.into_thread()
+    }
+
+    /// Retrieves a user's profile based on the provided username.
+    pub async fn get_user(&self, username: &str) -> HackerNewsResult<HackerNewsSearchUser> {
+        self.get_user_with(username, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves a user's profile based on the provided username with the request options applied.
+    pub async fn get_user_with(
+        &self,
+        username: &str,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsSearchUser> {
+        let username = urls::encode_path_segment(username)
+            .ok_or_else(|| HackerNewsClientError::ItemOrUserNotFound(username.to_string()))?;
+
+        self.internal_client
+            .get_resource(&format!("{}/{}", USERS_ENDPOINT, username), &[], &options)
+            .await
+    }
+}
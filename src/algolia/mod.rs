@@ -0,0 +1,252 @@
+//! Bindings for the [Algolia-backed search API](https://hn.algolia.com/api), covering full-text search over
+//! stories and comments, whole threads in a single request and user profiles. Results are mapped onto the
+//! crate's item types where possible, though the search API omits some fields, e.g. comment rankings.
+//!
+//! ```no_run
+//! use newswrap::{
+//!     algolia::{HackerNewsSearchQuery, HackerNewsSearchTag},
+//!     client::HackerNewsClient,
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!
+//!     let query = HackerNewsSearchQuery::new("rust")
+//!         .tag(HackerNewsSearchTag::Story)
+//!         .min_points(100)
+//!         .hits_per_page(10);
+//!
+//!     for hit in client.search.search(&query).await?.hits {
+//!         println!("{} {}", hit.points.unwrap_or_default(), hit.title.unwrap_or_default());
+//!     }
+//!
+//!     let thread = client.search.get_thread(8863).await?;
+//!     println!("{} comments", thread.len());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub mod client;
+mod query;
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    errors::{HackerNewsClientError, HackerNewsResult},
+    items::{comments::HackerNewsComment, threads::HackerNewsThread, HackerNewsItem},
+    HackerNewsID,
+};
+
+pub use query::{
+    HackerNewsSearchComparison, HackerNewsSearchField, HackerNewsSearchQuery, HackerNewsSearchTag,
+};
+
+/// Item types carried within a hit's tags.
+const ITEM_TYPE_TAGS: [&str; 5] = ["story", "comment", "poll", "pollopt", "job"];
+
+/// A page of results returned by the search endpoints.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HackerNewsSearchResults {
+    /// The matching items on this page.
+    pub hits: Vec<HackerNewsSearchHit>,
+    /// The total number of matching items across all pages.
+    pub nb_hits: u32,
+    /// The zero-based page of results.
+    pub page: u32,
+    /// The total number of pages available.
+    pub nb_pages: u32,
+    /// The number of hits per page.
+    pub hits_per_page: u32,
+    /// The query text the results were matched against.
+    pub query: String,
+}
+
+impl HackerNewsSearchResults {
+    /// Determines if further pages of results are available.
+    pub fn has_next_page(&self) -> bool {
+        self.page + 1 < self.nb_pages
+    }
+
+    /// Maps the hits onto items, returning an error if a hit's ID is invalid.
+    pub fn into_items(self) -> HackerNewsResult<Vec<HackerNewsItem>> {
+        self.hits
+            .into_iter()
+            .map(HackerNewsItem::try_from)
+            .collect()
+    }
+}
+
+/// A story or comment matching a search query.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HackerNewsSearchHit {
+    /// The item's unique id, as a string.
+    #[serde(rename = "objectID")]
+    pub object_id: String,
+    /// Creation date of the item.
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// The title of the story.
+    pub title: Option<String>,
+    /// The URL of the story.
+    pub url: Option<String>,
+    /// The username of the item's author.
+    pub author: Option<String>,
+    /// The story's score.
+    pub points: Option<u32>,
+    /// The story's text. HTML.
+    pub story_text: Option<String>,
+    /// The comment's text. HTML.
+    pub comment_text: Option<String>,
+    /// In the case of stories, the total comment count.
+    pub num_comments: Option<u32>,
+    /// In the case of comments, the story the comment belongs to.
+    pub story_id: Option<HackerNewsID>,
+    /// In the case of comments, the comment's parent: either another comment or the story.
+    pub parent_id: Option<HackerNewsID>,
+    /// Tags describing the item, e.g. `story`, `author_pg` and `story_8863`.
+    #[serde(rename = "_tags", default)]
+    pub tags: Vec<String>,
+}
+
+impl HackerNewsSearchHit {
+    /// Determines the item type from the hit's tags, e.g. `story` or `comment`.
+    pub fn item_type(&self) -> Option<&str> {
+        self.tags
+            .iter()
+            .map(String::as_str)
+            .find(|tag| ITEM_TYPE_TAGS.contains(tag))
+    }
+}
+
+impl TryFrom<HackerNewsSearchHit> for HackerNewsItem {
+    type Error = HackerNewsClientError;
+
+    fn try_from(hit: HackerNewsSearchHit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: hit.object_id.parse()?,
+            deleted: None,
+            response_type: hit.item_type().map(str::to_string),
+            by: hit.author,
+            created_at: hit.created_at,
+            dead: None,
+            parent: hit.parent_id,
+            poll: None,
+            kids: None,
+            url: hit.url,
+            score: hit.points,
+            title: hit.title,
+            text: hit.story_text.or(hit.comment_text),
+            parts: None,
+            descendants: hit.num_comments,
+        })
+    }
+}
+
+/// An item along with its full tree of children, returned by the search API's item endpoint.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HackerNewsSearchItem {
+    /// The item's unique id.
+    pub id: HackerNewsID,
+    /// Creation date of the item.
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// The type of item. One of "job", "story", "comment", "poll", or "pollopt".
+    #[serde(rename = "type")]
+    pub item_type: Option<String>,
+    /// The username of the item's author, missing for deleted items.
+    pub author: Option<String>,
+    /// The title of the story, poll or job.
+    pub title: Option<String>,
+    /// The URL of the story.
+    pub url: Option<String>,
+    /// The comment, story or poll text. HTML.
+    pub text: Option<String>,
+    /// The story's score.
+    pub points: Option<u32>,
+    /// The item's parent: either another comment or the story.
+    pub parent_id: Option<HackerNewsID>,
+    /// The story the item belongs to.
+    pub story_id: Option<HackerNewsID>,
+    /// The item's children, each with their own children.
+    #[serde(default)]
+    pub children: Vec<HackerNewsSearchItem>,
+}
+
+impl HackerNewsSearchItem {
+    /// Counts all nested children of the item.
+    pub fn descendants(&self) -> usize {
+        self.children
+            .iter()
+            .map(|child| 1 + child.descendants())
+            .sum()
+    }
+
+    /// Maps the item onto a generic item, without its children. Items missing both an author and text are
+    /// considered deleted, as the search API does not flag them.
+    pub fn to_item(&self) -> HackerNewsItem {
+        let kids: Vec<HackerNewsID> = self.children.iter().map(|child| child.id).collect();
+        let deleted = self.author.is_none() && self.text.is_none() && self.title.is_none();
+
+        HackerNewsItem {
+            id: self.id,
+            deleted: deleted.then_some(true),
+            response_type: self.item_type.clone(),
+            by: self.author.clone(),
+            created_at: self.created_at,
+            dead: None,
+            parent: self.parent_id,
+            poll: None,
+            kids: (!kids.is_empty()).then_some(kids),
+            url: self.url.clone(),
+            score: self.points,
+            title: self.title.clone(),
+            text: self.text.clone(),
+            parts: None,
+            descendants: None,
+        }
+    }
+
+    /// Assembles a thread from the item and its children, skipping children that are not comments, such as the
+    /// options of a poll.
+    pub fn into_thread(self) -> HackerNewsResult<HackerNewsThread> {
+        let mut root = self.to_item();
+        let mut comments = HashMap::new();
+        let mut pending = self.children;
+
+        while let Some(child) = pending.pop() {
+            if let Ok(comment) = HackerNewsComment::try_from(child.to_item()) {
+                comments.insert(comment.id, comment);
+                pending.extend(child.children);
+            }
+        }
+
+        root.kids = root
+            .kids
+            .map(|kids| {
+                kids.into_iter()
+                    .filter(|id| comments.contains_key(id))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|kids| !kids.is_empty());
+        root.descendants = Some(comments.len() as u32);
+
+        Ok(HackerNewsThread::assemble(root, comments))
+    }
+}
+
+/// A user profile returned by the search API, a subset of the Firebase API's user.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HackerNewsSearchUser {
+    /// Username of the account.
+    pub username: String,
+    /// The user's optional self-description. HTML.
+    pub about: Option<String>,
+    /// The user's karma.
+    pub karma: u32,
+}
@@ -0,0 +1,227 @@
+//! Typed query builders for the Algolia search endpoints.
+
+use std::fmt::Display;
+
+use time::OffsetDateTime;
+
+use crate::HackerNewsID;
+
+/// Default number of hits per page returned by the search API.
+const DEFAULT_HITS_PER_PAGE: u32 = 20;
+
+/// Tags for restricting search results, e.g. to stories by a single author.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HackerNewsSearchTag {
+    /// Stories.
+    Story,
+    /// Comments.
+    Comment,
+    /// Polls.
+    Poll,
+    /// Poll options.
+    PollOption,
+    /// Show HN stories.
+    ShowHackerNews,
+    /// Ask HN stories.
+    AskHackerNews,
+    /// Stories currently on the front page.
+    FrontPage,
+    /// Items by the author.
+    Author(String),
+    /// Comments on the story.
+    StoryId(HackerNewsID),
+}
+
+impl Display for HackerNewsSearchTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HackerNewsSearchTag::Story => write!(f, "story"),
+            HackerNewsSearchTag::Comment => write!(f, "comment"),
+            HackerNewsSearchTag::Poll => write!(f, "poll"),
+            HackerNewsSearchTag::PollOption => write!(f, "pollopt"),
+            HackerNewsSearchTag::ShowHackerNews => write!(f, "show_hn"),
+            HackerNewsSearchTag::AskHackerNews => write!(f, "ask_hn"),
+            HackerNewsSearchTag::FrontPage => write!(f, "front_page"),
+            HackerNewsSearchTag::Author(author) => write!(f, "author_{}", author),
+            HackerNewsSearchTag::StoryId(id) => write!(f, "story_{}", id),
+        }
+    }
+}
+
+/// Numeric fields available for filtering search results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HackerNewsSearchField {
+    /// Creation time of the item, in Unix time.
+    CreatedAt,
+    /// The item's points.
+    Points,
+    /// The story's comment count.
+    NumComments,
+}
+
+impl Display for HackerNewsSearchField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HackerNewsSearchField::CreatedAt => write!(f, "created_at_i"),
+            HackerNewsSearchField::Points => write!(f, "points"),
+            HackerNewsSearchField::NumComments => write!(f, "num_comments"),
+        }
+    }
+}
+
+/// Comparisons applied by numeric filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HackerNewsSearchComparison {
+    /// Strictly less than the value.
+    LessThan,
+    /// Less than or equal to the value.
+    LessThanOrEqual,
+    /// Equal to the value.
+    Equal,
+    /// Greater than or equal to the value.
+    GreaterThanOrEqual,
+    /// Strictly greater than the value.
+    GreaterThan,
+}
+
+impl Display for HackerNewsSearchComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HackerNewsSearchComparison::LessThan => write!(f, "<"),
+            HackerNewsSearchComparison::LessThanOrEqual => write!(f, "<="),
+            HackerNewsSearchComparison::Equal => write!(f, "="),
+            HackerNewsSearchComparison::GreaterThanOrEqual => write!(f, ">="),
+            HackerNewsSearchComparison::GreaterThan => write!(f, ">"),
+        }
+    }
+}
+
+/// A query for the search endpoints. Tags are combined with AND, except for tags added together
+/// as alternatives which are combined with OR, and all numeric filters must match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HackerNewsSearchQuery {
+    query: String,
+    tags: Vec<Vec<HackerNewsSearchTag>>,
+    numeric_filters: Vec<String>,
+    page: u32,
+    hits_per_page: u32,
+}
+
+impl HackerNewsSearchQuery {
+    /// Constructs a new query for the text, matching all items if the text is empty.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            tags: Vec::new(),
+            numeric_filters: Vec::new(),
+            page: 0,
+            hits_per_page: DEFAULT_HITS_PER_PAGE,
+        }
+    }
+
+    /// Restricts results to items with the tag.
+    pub fn tag(mut self, tag: HackerNewsSearchTag) -> Self {
+        self.tags.push(vec![tag]);
+        self
+    }
+
+    /// Restricts results to items with any of the tags, e.g. stories by any of several authors.
+    pub fn any_tag(mut self, tags: impl IntoIterator<Item = HackerNewsSearchTag>) -> Self {
+        let tags: Vec<HackerNewsSearchTag> = tags.into_iter().collect();
+
+        if !tags.is_empty() {
+            self.tags.push(tags);
+        }
+
+        self
+    }
+
+    /// Restricts results to items whose numeric field satisfies the comparison.
+    pub fn numeric_filter(
+        mut self,
+        field: HackerNewsSearchField,
+        comparison: HackerNewsSearchComparison,
+        value: i64,
+    ) -> Self {
+        self.numeric_filters
+            .push(format!("{}{}{}", field, comparison, value));
+        self
+    }
+
+    /// Restricts results to items with at least the minimum points.
+    pub fn min_points(self, points: u32) -> Self {
+        self.numeric_filter(
+            HackerNewsSearchField::Points,
+            HackerNewsSearchComparison::GreaterThanOrEqual,
+            points.into(),
+        )
+    }
+
+    /// Restricts results to stories with at least the minimum number of comments.
+    pub fn min_comments(self, comments: u32) -> Self {
+        self.numeric_filter(
+            HackerNewsSearchField::NumComments,
+            HackerNewsSearchComparison::GreaterThanOrEqual,
+            comments.into(),
+        )
+    }
+
+    /// Restricts results to items created after the time.
+    pub fn created_after(self, time: OffsetDateTime) -> Self {
+        self.numeric_filter(
+            HackerNewsSearchField::CreatedAt,
+            HackerNewsSearchComparison::GreaterThan,
+            time.unix_timestamp(),
+        )
+    }
+
+    /// Restricts results to items created before the time.
+    pub fn created_before(self, time: OffsetDateTime) -> Self {
+        self.numeric_filter(
+            HackerNewsSearchField::CreatedAt,
+            HackerNewsSearchComparison::LessThan,
+            time.unix_timestamp(),
+        )
+    }
+
+    /// Configures the zero-based page of results to retrieve.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Configures the number of hits per page.
+    pub fn hits_per_page(mut self, hits_per_page: u32) -> Self {
+        self.hits_per_page = hits_per_page;
+        self
+    }
+
+    /// Renders the query as URL query parameters.
+    pub(super) fn parameters(&self) -> Vec<(&'static str, String)> {
+        let mut parameters = vec![("query", self.query.clone())];
+
+        if !self.tags.is_empty() {
+            let tags: Vec<String> = self
+                .tags
+                .iter()
+                .map(|group| match group.as_slice() {
+                    [tag] => tag.to_string(),
+                    group => {
+                        let tags: Vec<String> = group.iter().map(|tag| tag.to_string()).collect();
+                        format!("({})", tags.join(","))
+                    }
+                })
+                .collect();
+
+            parameters.push(("tags", tags.join(",")));
+        }
+
+        if !self.numeric_filters.is_empty() {
+            parameters.push(("numericFilters", self.numeric_filters.join(",")));
+        }
+
+        parameters.push(("page", self.page.to_string()));
+        parameters.push(("hitsPerPage", self.hits_per_page.to_string()));
+        parameters
+    }
+}
@@ -0,0 +1,34 @@
+//! Computed statistics over threads, stories and users for reporting on discussion health and community activity.
+//! Story and user statistics over time are sampled by polling, driven by the caller.
+//!
+//! ```no_run
+//! use newswrap::{analytics::ThreadStats, client::HackerNewsClient};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!     let thread = client.items.get_thread(8863).await?;
+//!     let stats = ThreadStats::try_from(&thread)?;
+//!
+//!     println!("{} of {} comments visible", stats.visible_comments, stats.total_comments);
+//!
+//!     for participant in &stats.top_commenters {
+//!         println!("{} ({})", participant.by, participant.comments);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod stories;
+mod threads;
+mod users;
+
+pub use stories::{StorySample, StorySeries, StoryTracker};
+pub use threads::{RepliedComment, ReplyLatency, ThreadParticipant, ThreadStats};
+pub use users::{
+    ActivityHeatmap, KarmaHistory, KarmaSample, KarmaTracker, SubmissionMix, UserProfile,
+};
+
+/// Number of entries kept in rankings, e.g. the most active participants.
+const TOP_COUNT: usize = 10;
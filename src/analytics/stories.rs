@@ -0,0 +1,223 @@
+//! Time series of stories' score, comments and front page rank, sampled by polling the API.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    cancellation::Cancellation, client::HackerNewsClient, errors::HackerNewsResult,
+    items::HackerNewsItem, HackerNewsID,
+};
+
+/// Number of stories displayed on the front page.
+const FRONT_PAGE_SIZE: usize = 30;
+
+/// Gravity applied to a story's age in the ranking formula.
+const RANKING_GRAVITY: f64 = 1.8;
+
+/// Exponent applied to a story's points in the ranking formula.
+const RANKING_POINTS_EXPONENT: f64 = 0.8;
+
+/// A story's score, comment count and rank at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StorySample {
+    /// The time the story was sampled.
+    #[serde(with = "time::serde::timestamp")]
+    pub sampled_at: OffsetDateTime,
+    /// The story's score.
+    pub score: u32,
+    /// The story's total comment count.
+    pub comments: u32,
+    /// The story's one-based position within the top stories, if listed.
+    pub rank: Option<usize>,
+}
+
+/// Samples of a single story over time, oldest first.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StorySeries {
+    /// The story's unique id.
+    pub id: HackerNewsID,
+    /// Creation date of the story.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// The story's samples, oldest first.
+    pub samples: Vec<StorySample>,
+}
+
+impl StorySeries {
+    /// Retrieves the most recent sample.
+    pub fn latest(&self) -> Option<&StorySample> {
+        self.samples.last()
+    }
+
+    /// Computes the points gained per hour between the first and latest samples.
+    pub fn points_per_hour(&self) -> Option<f64> {
+        self.velocity(|sample| sample.score)
+    }
+
+    /// Computes the comments gained per hour between the first and latest samples.
+    pub fn comments_per_hour(&self) -> Option<f64> {
+        self.velocity(|sample| sample.comments)
+    }
+
+    /// The story's highest position within the top stories across all samples.
+    pub fn peak_rank(&self) -> Option<usize> {
+        self.samples.iter().filter_map(|sample| sample.rank).min()
+    }
+
+    /// Estimates the time the story spent on the front page, counting the interval following each sample
+    /// where the story ranked within the front page.
+    pub fn time_on_front_page(&self) -> Duration {
+        self.samples
+            .windows(2)
+            .filter(|window| window[0].rank.is_some_and(|rank| rank <= FRONT_PAGE_SIZE))
+            .map(|window| window[1].sampled_at - window[0].sampled_at)
+            .sum()
+    }
+
+    /// Estimates the story's score within the commonly cited Hacker News ranking formula as of the sample,
+    /// `(points - 1)^0.8 / (hours + 2)^1.8`, ignoring penalties applied by moderators and flags.
+    pub fn ranking_score(&self, sample: &StorySample) -> f64 {
+        let hours = (sample.sampled_at - self.created_at).as_seconds_f64() / 3600.0;
+        let points = f64::from(sample.score.saturating_sub(1));
+
+        points.powf(RANKING_POINTS_EXPONENT) / (hours.max(0.0) + 2.0).powf(RANKING_GRAVITY)
+    }
+
+    /// Computes the change in the sampled value per hour between the first and latest samples.
+    fn velocity(&self, value: impl Fn(&StorySample) -> u32) -> Option<f64> {
+        let (first, latest) = (self.samples.first()?, self.samples.last()?);
+        let hours = (latest.sampled_at - first.sampled_at).as_seconds_f64() / 3600.0;
+
+        (hours > 0.0).then(|| (f64::from(value(latest)) - f64::from(value(first))) / hours)
+    }
+}
+
+/// Tracks a set of stories, sampling their score, comment count and rank within the top stories each time
+/// the tracker is polled. Tracking is driven by the caller, e.g. on an interval of their async runtime.
+///
+/// ```no_run
+/// use newswrap::{analytics::StoryTracker, client::HackerNewsClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = HackerNewsClient::new();
+///     let mut tracker = StoryTracker::new([8863]);
+///     let mut interval = tokio::time::interval(std::time::Duration::from_secs(300));
+///
+///     for _ in 0..12 {
+///         interval.tick().await;
+///         tracker.poll(&client).await?;
+///     }
+///
+///     let series = tracker.series(8863).unwrap();
+///     println!("{:?} points per hour", series.points_per_hour());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StoryTracker {
+    ids: Vec<HackerNewsID>,
+    series: HashMap<HackerNewsID, StorySeries>,
+}
+
+impl StoryTracker {
+    /// Constructs a new tracker for the stories.
+    pub fn new(ids: impl IntoIterator<Item = HackerNewsID>) -> Self {
+        let mut tracker = Self::default();
+
+        for id in ids {
+            tracker.track(id);
+        }
+
+        tracker
+    }
+
+    /// Starts tracking the story on subsequent polls.
+    pub fn track(&mut self, id: HackerNewsID) {
+        if !self.ids.contains(&id) {
+            self.ids.push(id);
+        }
+    }
+
+    /// Stops tracking the story, returning its samples if any were recorded.
+    pub fn untrack(&mut self, id: HackerNewsID) -> Option<StorySeries> {
+        self.ids.retain(|tracked| *tracked != id);
+        self.series.remove(&id)
+    }
+
+    /// The IDs of tracked stories, in the order they were tracked.
+    pub fn ids(&self) -> &[HackerNewsID] {
+        &self.ids
+    }
+
+    /// Retrieves the samples recorded for the story.
+    pub fn series(&self, id: HackerNewsID) -> Option<&StorySeries> {
+        self.series.get(&id)
+    }
+
+    /// Samples all tracked stories and their rank within the current top stories, returning the new samples
+    /// in the order stories were tracked. Stories missing from the API are skipped until they reappear.
+    pub async fn poll(
+        &mut self,
+        client: &HackerNewsClient,
+    ) -> HackerNewsResult<Vec<(HackerNewsID, StorySample)>> {
+        let top_stories = client.realtime.get_top_stories().await?;
+        let items = client.items.get_existing_items(&self.ids).await?;
+        let sampled_at = OffsetDateTime::now_utc();
+
+        let samples = items
+            .iter()
+            .map(|item| {
+                let rank = top_stories
+                    .iter()
+                    .position(|id| *id == item.id)
+                    .map(|position| position + 1);
+
+                (item.id, self.record(item, rank, sampled_at))
+            })
+            .collect();
+
+        Ok(samples)
+    }
+
+    /// Samples all tracked stories like [`StoryTracker::poll`] until cancelled or past the deadline. Polls stopped
+    /// early record no samples and return the reason.
+    pub async fn poll_until(
+        &mut self,
+        client: &HackerNewsClient,
+        cancellation: &Cancellation,
+    ) -> HackerNewsResult<Vec<(HackerNewsID, StorySample)>> {
+        cancellation.run(self.poll(client)).await
+    }
+
+    /// Records a sample of the item at its rank within the top stories, tracking it if not already tracked.
+    pub fn record(
+        &mut self,
+        item: &HackerNewsItem,
+        rank: Option<usize>,
+        sampled_at: OffsetDateTime,
+    ) -> StorySample {
+        self.track(item.id);
+
+        let sample = StorySample {
+            sampled_at,
+            score: item.score.unwrap_or_default(),
+            comments: item.descendants.unwrap_or_default(),
+            rank,
+        };
+
+        self.series
+            .entry(item.id)
+            .or_insert_with(|| StorySeries {
+                id: item.id,
+                created_at: item.created_at,
+                samples: Vec::new(),
+            })
+            .samples
+            .push(sample);
+
+        sample
+    }
+}
@@ -0,0 +1,221 @@
+//! Statistics over a story's comment tree, e.g. depth, breadth and reply latency.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    errors::HackerNewsClientError,
+    items::{
+        stories::HackerNewsStory,
+        threads::{HackerNewsCommentNode, HackerNewsThread},
+    },
+    HackerNewsID,
+};
+
+use super::TOP_COUNT;
+
+/// A participant in a thread along with their activity.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ThreadParticipant {
+    /// Username of the participant.
+    pub by: String,
+    /// The number of visible comments the participant posted.
+    pub comments: usize,
+    /// The number of direct replies the participant's comments received.
+    pub replies_received: usize,
+}
+
+/// A comment ranked by the replies it received.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RepliedComment {
+    /// The comment's unique id.
+    pub id: HackerNewsID,
+    /// Username of the comment poster, empty for deleted comments.
+    pub by: String,
+    /// The number of direct replies to the comment.
+    pub replies: usize,
+    /// The number of nested replies to the comment.
+    pub total_replies: usize,
+}
+
+/// The distribution of time between comments and their parents being posted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReplyLatency {
+    /// The number of replies measured.
+    pub count: usize,
+    /// The quickest reply.
+    pub min: Duration,
+    /// The median reply time.
+    pub median: Duration,
+    /// The mean reply time.
+    pub mean: Duration,
+    /// The time within which 90% of replies were posted.
+    pub p90: Duration,
+    /// The slowest reply.
+    pub max: Duration,
+}
+
+impl ReplyLatency {
+    /// Computes the distribution of the latencies, if any were measured.
+    fn from_latencies(mut latencies: Vec<Duration>) -> Option<Self> {
+        if latencies.is_empty() {
+            return None;
+        }
+
+        latencies.sort();
+
+        let count = latencies.len();
+        let total: Duration = latencies.iter().sum();
+        let percentile = |percent: usize| latencies[((count * percent).div_ceil(100)).max(1) - 1];
+
+        Some(Self {
+            count,
+            min: latencies[0],
+            median: percentile(50),
+            mean: total / count as u32,
+            p90: percentile(90),
+            max: latencies[count - 1],
+        })
+    }
+}
+
+/// Statistics computed over a story's comment tree.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ThreadStats {
+    /// The story's unique id.
+    pub story: HackerNewsID,
+    /// The deepest level of nested replies, where top level comments have a depth of one.
+    pub max_depth: usize,
+    /// The number of comments at each level of the thread, top level comments first.
+    pub breadth: Vec<usize>,
+    /// The number of comments in the tree, including deleted and dead comments.
+    pub total_comments: usize,
+    /// The number of comments in the tree that are neither deleted nor dead.
+    pub visible_comments: usize,
+    /// The comment count reported on the story as its `descendants`.
+    pub reported_comments: u32,
+    /// The most active participants by visible comments posted.
+    pub top_commenters: Vec<ThreadParticipant>,
+    /// The distribution of time between visible comments and their parents being posted.
+    pub reply_latency: Option<ReplyLatency>,
+    /// The comments receiving the most direct replies.
+    pub most_replied: Vec<RepliedComment>,
+}
+
+impl ThreadStats {
+    /// Computes statistics over the story and its fetched comment tree.
+    pub fn new(story: &HackerNewsStory, comments: &[HackerNewsCommentNode]) -> Self {
+        let mut stats = Self {
+            story: story.id,
+            max_depth: 0,
+            breadth: Vec::new(),
+            total_comments: 0,
+            visible_comments: 0,
+            reported_comments: story.number_of_comments,
+            top_commenters: Vec::new(),
+            reply_latency: None,
+            most_replied: Vec::new(),
+        };
+
+        let mut participants: HashMap<&str, ThreadParticipant> = HashMap::new();
+        let mut latencies = Vec::new();
+        let mut replied = Vec::new();
+        let mut pending: Vec<(usize, OffsetDateTime, &HackerNewsCommentNode)> = comments
+            .iter()
+            .rev()
+            .map(|node| (0, story.created_at, node))
+            .collect();
+
+        while let Some((depth, parent_created_at, node)) = pending.pop() {
+            let comment = &node.comment;
+
+            stats.total_comments += 1;
+            stats.max_depth = stats.max_depth.max(depth + 1);
+
+            if stats.breadth.len() <= depth {
+                stats.breadth.push(0);
+            }
+
+            stats.breadth[depth] += 1;
+
+            if comment.is_visible() {
+                stats.visible_comments += 1;
+                latencies.push((comment.created_at - parent_created_at).max(Duration::ZERO));
+
+                let participant =
+                    participants
+                        .entry(comment.by.as_str())
+                        .or_insert_with(|| ThreadParticipant {
+                            by: comment.by.clone(),
+                            comments: 0,
+                            replies_received: 0,
+                        });
+
+                participant.comments += 1;
+                participant.replies_received += node.replies.len();
+            }
+
+            if !node.replies.is_empty() {
+                replied.push(RepliedComment {
+                    id: comment.id,
+                    by: comment.by.clone(),
+                    replies: node.replies.len(),
+                    total_replies: node.reply_count(),
+                });
+            }
+
+            pending.extend(
+                node.replies
+                    .iter()
+                    .rev()
+                    .map(|reply| (depth + 1, comment.created_at, reply)),
+            );
+        }
+
+        let mut top_commenters: Vec<ThreadParticipant> = participants.into_values().collect();
+        top_commenters.sort_by(|first, second| {
+            second
+                .comments
+                .cmp(&first.comments)
+                .then_with(|| second.replies_received.cmp(&first.replies_received))
+                .then_with(|| first.by.cmp(&second.by))
+        });
+        top_commenters.truncate(TOP_COUNT);
+
+        replied.sort_by(|first, second| {
+            second
+                .replies
+                .cmp(&first.replies)
+                .then_with(|| second.total_replies.cmp(&first.total_replies))
+                .then_with(|| first.id.cmp(&second.id))
+        });
+        replied.truncate(TOP_COUNT);
+
+        stats.top_commenters = top_commenters;
+        stats.reply_latency = ReplyLatency::from_latencies(latencies);
+        stats.most_replied = replied;
+        stats
+    }
+
+    /// Counts comments reported on the story that were not found within the tree as visible comments.
+    pub fn missing_comments(&self) -> u32 {
+        self.reported_comments
+            .saturating_sub(self.visible_comments as u32)
+    }
+
+    /// Computes the share of comments in the tree that are visible, or `None` for threads without comments.
+    pub fn visible_ratio(&self) -> Option<f64> {
+        (self.total_comments > 0).then(|| self.visible_comments as f64 / self.total_comments as f64)
+    }
+}
+
+impl TryFrom<&HackerNewsThread> for ThreadStats {
+    type Error = HackerNewsClientError;
+
+    fn try_from(thread: &HackerNewsThread) -> Result<Self, Self::Error> {
+        let story = HackerNewsStory::try_from(thread.root.clone())?;
+        Ok(Self::new(&story, &thread.comments))
+    }
+}
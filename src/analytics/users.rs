@@ -0,0 +1,311 @@
+//! Activity profiles of users derived from their submissions, along with karma history sampled by polling the API.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime, Weekday};
+
+use crate::{
+    client::HackerNewsClient,
+    errors::HackerNewsResult,
+    items::{HackerNewsItem, HackerNewsItemType},
+    users::HackerNewsUser,
+};
+
+/// Weekdays in the order they're counted within heatmaps.
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+/// Counts of a user's submissions by item type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct SubmissionMix {
+    /// The number of stories submitted.
+    pub stories: usize,
+    /// The number of comments posted.
+    pub comments: usize,
+    /// The number of jobs posted.
+    pub jobs: usize,
+    /// The number of polls submitted.
+    pub polls: usize,
+    /// The number of poll options submitted.
+    pub poll_options: usize,
+    /// The number of submissions of an unknown type.
+    pub unknown: usize,
+}
+
+impl SubmissionMix {
+    /// Counts submissions of the item type.
+    pub fn count(&self, item_type: HackerNewsItemType) -> usize {
+        match item_type {
+            HackerNewsItemType::Story => self.stories,
+            HackerNewsItemType::Comment => self.comments,
+            HackerNewsItemType::Job => self.jobs,
+            HackerNewsItemType::Poll => self.polls,
+            HackerNewsItemType::PollOption => self.poll_options,
+            HackerNewsItemType::Unknown => self.unknown,
+        }
+    }
+
+    /// Counts all submissions.
+    pub fn total(&self) -> usize {
+        self.stories + self.comments + self.jobs + self.polls + self.poll_options + self.unknown
+    }
+
+    fn add(&mut self, item_type: HackerNewsItemType) {
+        let count = match item_type {
+            HackerNewsItemType::Story => &mut self.stories,
+            HackerNewsItemType::Comment => &mut self.comments,
+            HackerNewsItemType::Job => &mut self.jobs,
+            HackerNewsItemType::Poll => &mut self.polls,
+            HackerNewsItemType::PollOption => &mut self.poll_options,
+            HackerNewsItemType::Unknown => &mut self.unknown,
+        };
+
+        *count += 1;
+    }
+}
+
+/// Counts of a user's submissions by weekday and hour of the day, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct ActivityHeatmap {
+    /// Submission counts indexed by weekday, starting with Monday, then by hour.
+    pub counts: [[u32; 24]; 7],
+}
+
+impl ActivityHeatmap {
+    /// Counts submissions made on the weekday within the hour, in UTC.
+    pub fn count(&self, weekday: Weekday, hour: u8) -> u32 {
+        self.counts[usize::from(weekday.number_days_from_monday())]
+            .get(usize::from(hour))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Finds the weekday and hour with the most submissions, preferring the earliest in the week on ties.
+    pub fn busiest(&self) -> Option<(Weekday, u8, u32)> {
+        let mut busiest: Option<(Weekday, u8, u32)> = None;
+
+        for (weekday, hours) in WEEKDAYS.iter().zip(self.counts.iter()) {
+            for (hour, count) in hours.iter().enumerate() {
+                if *count > busiest.map_or(0, |(_, _, busiest)| busiest) {
+                    busiest = Some((*weekday, hour as u8, *count));
+                }
+            }
+        }
+
+        busiest
+    }
+
+    fn add(&mut self, time: OffsetDateTime) {
+        let time = time.to_offset(time::UtcOffset::UTC);
+        let weekday = usize::from(time.weekday().number_days_from_monday());
+
+        self.counts[weekday][usize::from(time.hour())] += 1;
+    }
+}
+
+/// A user's activity profile, derived from their account and sampled submissions.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UserProfile {
+    /// Username of the account.
+    pub username: String,
+    /// The user's karma.
+    pub karma: u32,
+    /// The age of the account when the profile was computed.
+    pub account_age: Duration,
+    /// The number of submissions listed on the account.
+    pub total_submissions: usize,
+    /// Counts of the sampled submissions by type, excluding deleted submissions.
+    pub submission_mix: SubmissionMix,
+    /// Counts of the sampled submissions by weekday and hour, excluding deleted submissions.
+    pub activity: ActivityHeatmap,
+    /// The average score of the sampled stories.
+    pub average_story_score: Option<f64>,
+}
+
+impl UserProfile {
+    /// Computes a profile from the user and their submissions as of the time, typically now.
+    pub fn new(
+        user: &HackerNewsUser,
+        submissions: &[HackerNewsItem],
+        as_of: OffsetDateTime,
+    ) -> Self {
+        let mut submission_mix = SubmissionMix::default();
+        let mut activity = ActivityHeatmap::default();
+        let mut story_scores = Vec::new();
+
+        for item in submissions
+            .iter()
+            .filter(|item| !item.deleted.unwrap_or_default())
+        {
+            submission_mix.add(item.get_item_type());
+            activity.add(item.created_at);
+
+            if item.is_story() {
+                story_scores.push(f64::from(item.score.unwrap_or_default()));
+            }
+        }
+
+        let average_story_score = (!story_scores.is_empty())
+            .then(|| story_scores.iter().sum::<f64>() / story_scores.len() as f64);
+
+        Self {
+            username: user.id.clone(),
+            karma: user.karma,
+            account_age: as_of - user.created,
+            total_submissions: user.stories.as_ref().map_or(0, Vec::len),
+            submission_mix,
+            activity,
+            average_story_score,
+        }
+    }
+
+    /// Retrieves the user and up to the limit of their most recent submissions, computing their profile as of now.
+    /// Submissions missing from the API are left out of the profile.
+    pub async fn fetch(
+        client: &HackerNewsClient,
+        username: &str,
+        limit: usize,
+    ) -> HackerNewsResult<Self> {
+        let user = client.users.get_user(username).await?;
+        let ids = user.stories.as_deref().unwrap_or_default();
+        let submissions = client
+            .items
+            .get_existing_items(&ids[..ids.len().min(limit)])
+            .await?;
+
+        Ok(Self::new(&user, &submissions, OffsetDateTime::now_utc()))
+    }
+}
+
+/// A user's karma at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KarmaSample {
+    /// The time the user was sampled.
+    #[serde(with = "time::serde::timestamp")]
+    pub sampled_at: OffsetDateTime,
+    /// The user's karma.
+    pub karma: u32,
+}
+
+/// Samples of a single user's karma over time, oldest first.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KarmaHistory {
+    /// Username of the account.
+    pub username: String,
+    /// The user's samples, oldest first.
+    pub samples: Vec<KarmaSample>,
+}
+
+impl KarmaHistory {
+    /// Computes the change in karma between each consecutive pair of samples.
+    pub fn deltas(&self) -> Vec<i64> {
+        self.samples
+            .windows(2)
+            .map(|window| i64::from(window[1].karma) - i64::from(window[0].karma))
+            .collect()
+    }
+
+    /// Computes the change in karma between the first and latest samples.
+    pub fn net_change(&self) -> i64 {
+        self.deltas().iter().sum()
+    }
+
+    /// Computes the karma gained per day between the first and latest samples.
+    pub fn karma_per_day(&self) -> Option<f64> {
+        let (first, latest) = (self.samples.first()?, self.samples.last()?);
+        let days = (latest.sampled_at - first.sampled_at).as_seconds_f64() / 86_400.0;
+
+        (days > 0.0).then(|| self.net_change() as f64 / days)
+    }
+}
+
+/// Tracks the karma of a set of users each time the tracker is polled. Tracking is driven by the caller,
+/// e.g. on an interval of their async runtime.
+#[derive(Debug, Clone, Default)]
+pub struct KarmaTracker {
+    usernames: Vec<String>,
+    histories: HashMap<String, KarmaHistory>,
+}
+
+impl KarmaTracker {
+    /// Constructs a new tracker for the users.
+    pub fn new(usernames: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut tracker = Self::default();
+
+        for username in usernames {
+            tracker.track(username);
+        }
+
+        tracker
+    }
+
+    /// Starts tracking the user on subsequent polls.
+    pub fn track(&mut self, username: impl Into<String>) {
+        let username = username.into();
+
+        if !self.usernames.contains(&username) {
+            self.usernames.push(username);
+        }
+    }
+
+    /// Stops tracking the user, returning their history if any samples were recorded.
+    pub fn untrack(&mut self, username: &str) -> Option<KarmaHistory> {
+        self.usernames.retain(|tracked| tracked != username);
+        self.histories.remove(username)
+    }
+
+    /// The usernames of tracked users, in the order they were tracked.
+    pub fn usernames(&self) -> &[String] {
+        &self.usernames
+    }
+
+    /// Retrieves the samples recorded for the user.
+    pub fn history(&self, username: &str) -> Option<&KarmaHistory> {
+        self.histories.get(username)
+    }
+
+    /// Samples the karma of all tracked users, returning the new samples in the order users were tracked.
+    pub async fn poll(
+        &mut self,
+        client: &HackerNewsClient,
+    ) -> HackerNewsResult<Vec<(String, KarmaSample)>> {
+        let mut samples = Vec::with_capacity(self.usernames.len());
+
+        for username in self.usernames.clone() {
+            let user = client.users.get_user(&username).await?;
+            let sample = self.record(&user, OffsetDateTime::now_utc());
+            samples.push((user.id, sample));
+        }
+
+        Ok(samples)
+    }
+
+    /// Records a sample of the user's karma, tracking them if not already tracked.
+    pub fn record(&mut self, user: &HackerNewsUser, sampled_at: OffsetDateTime) -> KarmaSample {
+        self.track(user.id.as_str());
+
+        let sample = KarmaSample {
+            sampled_at,
+            karma: user.karma,
+        };
+
+        self.histories
+            .entry(user.id.clone())
+            .or_insert_with(|| KarmaHistory {
+                username: user.id.clone(),
+                samples: Vec::new(),
+            })
+            .samples
+            .push(sample);
+
+        sample
+    }
+}
@@ -0,0 +1,131 @@
+//! Self-contained HTML rendering, with all content sanitized and styles inlined.
+
+use time::format_description::well_known::Rfc3339;
+
+use crate::{
+    archive::{
+        comment_author, comment_placeholder, display_time, HackerNewsArchive, DELETED_PLACEHOLDER,
+    },
+    items::threads::HackerNewsCommentNode,
+    text::{escape_html, sanitize_href},
+    urls,
+};
+
+const STYLES: &str = "body{font-family:Verdana,Geneva,sans-serif;font-size:10pt;max-width:50rem;margin:2rem auto;padding:0 1rem;color:#222}\
+a{color:#222}\
+header{border-bottom:1px solid #ddd;padding-bottom:1rem;margin-bottom:1rem}\
+h1{font-size:14pt;margin:0 0 .25rem}\
+.meta{color:#828282;font-size:9pt}\
+.meta a{color:#828282}\
+.site{color:#828282;font-size:9pt;font-weight:normal}\
+.comment{margin:.75rem 0}\
+.replies{margin-left:1.5rem;border-left:1px solid #eee;padding-left:.75rem}\
+.placeholder{color:#828282;font-style:italic}\
+pre{white-space:pre-wrap}";
+
+pub(super) fn render(archive: &HackerNewsArchive) -> String {
+    let story = &archive.story;
+    let title = escape_html(&story.title_plain());
+    let permalink = escape_html(&story.permalink());
+
+    let mut output = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<header>\n",
+        title, STYLES
+    );
+
+    let link = story
+        .parsed_url()
+        .and_then(|url| sanitize_href(url.as_str()))
+        .unwrap_or_else(|| story.permalink());
+
+    output.push_str(&format!(
+        "<h1><a href=\"{}\">{}</a>",
+        escape_html(&link),
+        title
+    ));
+
+    if let Some(site) = story.site() {
+        output.push_str(&format!(
+            " <span class=\"site\">(<a href=\"{}\">{}</a>)</span>",
+            escape_html(&urls::site_permalink(&site)),
+            escape_html(&site)
+        ));
+    }
+
+    output.push_str("</h1>\n");
+    output.push_str(&format!(
+        "<div class=\"meta\">{} points by {} {} | <a href=\"{}\">{} comments</a></div>\n",
+        story.score,
+        user_link(&story.by),
+        time_link(story.created_at, &permalink),
+        permalink,
+        story.number_of_comments
+    ));
+
+    let text = story.text_sanitized();
+
+    if !text.as_str().is_empty() {
+        output.push_str(&format!("<div class=\"text\">{}</div>\n", text));
+    }
+
+    output.push_str("</header>\n<main>\n");
+    render_comments(&archive.comments, &mut output);
+    output.push_str("</main>\n</body>\n</html>\n");
+
+    output
+}
+
+fn render_comments(nodes: &[HackerNewsCommentNode], output: &mut String) {
+    for node in nodes {
+        let comment = &node.comment;
+        let permalink = escape_html(&comment.permalink());
+        let author = match comment_author(comment) {
+            Some(author) => user_link(author),
+            None => format!("<span class=\"placeholder\">{}</span>", DELETED_PLACEHOLDER),
+        };
+
+        output.push_str(&format!(
+            "<article class=\"comment\" id=\"{}\">\n<div class=\"meta\">{} {}</div>\n",
+            comment.id,
+            author,
+            time_link(comment.created_at, &permalink)
+        ));
+
+        match comment_placeholder(comment) {
+            Some(placeholder) => {
+                output.push_str(&format!("<p class=\"placeholder\">{}</p>\n", placeholder))
+            }
+            None => output.push_str(&format!(
+                "<div class=\"text\">{}</div>\n",
+                comment.text_sanitized()
+            )),
+        }
+
+        if !node.replies.is_empty() {
+            output.push_str("<div class=\"replies\">\n");
+            render_comments(&node.replies, output);
+            output.push_str("</div>\n");
+        }
+
+        output.push_str("</article>\n");
+    }
+}
+
+fn user_link(username: &str) -> String {
+    format!(
+        "<a href=\"{}\">{}</a>",
+        escape_html(&urls::user_permalink(username)),
+        escape_html(username)
+    )
+}
+
+/// Links the timestamp to the item's permalink, which must already be escaped.
+fn time_link(time: time::OffsetDateTime, permalink: &str) -> String {
+    format!(
+        "<a href=\"{}\"><time datetime=\"{}\">{}</time></a>",
+        permalink,
+        time.format(&Rfc3339).unwrap_or_default(),
+        display_time(time)
+    )
+}
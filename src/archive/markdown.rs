@@ -0,0 +1,98 @@
+//! Markdown rendering, nesting replies within one additional block quote per level.
+
+use crate::{
+    archive::{
+        comment_author, comment_placeholder, display_time, HackerNewsArchive, DELETED_PLACEHOLDER,
+    },
+    items::threads::HackerNewsCommentNode,
+    text::{escape_markdown, escape_markdown_href, sanitize_href},
+    urls,
+};
+
+pub(super) fn render(archive: &HackerNewsArchive) -> String {
+    let story = &archive.story;
+    let title = escape_markdown(&story.title_plain());
+
+    let link = story
+        .parsed_url()
+        .and_then(|url| sanitize_href(url.as_str()))
+        .map(|url| escape_markdown_href(&url));
+
+    let mut output = match link {
+        Some(url) => format!("# [{}]({})\n\n", title, url),
+        None => format!("# {}\n\n", title),
+    };
+
+    if let Some(site) = story.site() {
+        output.push_str(&format!(
+            "Site: [{}]({})\n\n",
+            escape_markdown(&site),
+            escape_markdown_href(&urls::site_permalink(&site))
+        ));
+    }
+
+    output.push_str(&format!(
+        "{} points by {} | [{}]({}) | [{} comments]({})\n",
+        story.score,
+        user_link(&story.by),
+        display_time(story.created_at),
+        story.permalink(),
+        story.number_of_comments,
+        story.permalink()
+    ));
+
+    let text = story.text_markdown();
+
+    if !text.is_empty() {
+        output.push_str(&format!("\n{}\n", text));
+    }
+
+    output.push_str("\n---\n");
+    render_comments(&archive.comments, 0, &mut output);
+
+    output
+}
+
+fn render_comments(nodes: &[HackerNewsCommentNode], depth: usize, output: &mut String) {
+    let prefix = "> ".repeat(depth);
+
+    for node in nodes {
+        let comment = &node.comment;
+        let author = comment_author(comment)
+            .map(user_link)
+            .unwrap_or_else(|| escape_markdown(DELETED_PLACEHOLDER));
+
+        output.push_str(&format!("{}\n", prefix.trim_end()));
+        output.push_str(&format!(
+            "{}**{}** | [{}]({})\n{}\n",
+            prefix,
+            author,
+            display_time(comment.created_at),
+            comment.permalink(),
+            prefix.trim_end()
+        ));
+
+        let text = match comment_placeholder(comment) {
+            Some(placeholder) => format!("*{}*", escape_markdown(placeholder)),
+            None => comment.text_markdown(),
+        };
+
+        for line in text.lines() {
+            if line.is_empty() {
+                output.push_str(&format!("{}\n", prefix.trim_end()));
+            } else {
+                output.push_str(&format!("{}{}\n", prefix, line));
+            }
+        }
+
+        render_comments(&node.replies, depth + 1, output);
+    }
+}
+
+fn user_link(username: &str) -> String {
+    format!(
+        "[{}]({})",
+        escape_markdown(username),
+        escape_markdown_href(&urls::user_permalink(username))
+    )
+}
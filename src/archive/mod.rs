@@ -0,0 +1,106 @@
+//! Static archives of a story and its comment tree, rendered as a self-contained HTML page or a Markdown document
+//! for preserving notable discussions outside of Hacker News.
+//!
+//! ```no_run
+//! use newswrap::{archive::HackerNewsArchive, client::HackerNewsClient};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!     let thread = client.items.get_thread(8863).await?;
+//!     let archive = HackerNewsArchive::try_from(thread)?;
+//!
+//!     std::fs::write("8863.html", archive.to_html())?;
+//!     std::fs::write("8863.md", archive.to_markdown())?;
+//!     Ok(())
+//! }
+//! ```
+
+mod html;
+mod markdown;
+
+use time::OffsetDateTime;
+
+use crate::{
+    errors::HackerNewsClientError,
+    items::{
+        comments::HackerNewsComment,
+        stories::HackerNewsStory,
+        threads::{HackerNewsCommentNode, HackerNewsThread},
+    },
+};
+
+/// Placeholder displayed in place of deleted comments' author and text.
+const DELETED_PLACEHOLDER: &str = "[deleted]";
+
+/// Placeholder displayed in place of dead comments' text.
+const DEAD_PLACEHOLDER: &str = "[dead]";
+
+/// A story and its comment tree, ready to be rendered as an archive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HackerNewsArchive {
+    /// The archived story.
+    pub story: HackerNewsStory,
+    /// Top level comments on the story, each with their nested replies.
+    pub comments: Vec<HackerNewsCommentNode>,
+}
+
+impl HackerNewsArchive {
+    /// Constructs a new archive from the story and its comment tree.
+    pub fn new(story: HackerNewsStory, comments: Vec<HackerNewsCommentNode>) -> Self {
+        Self { story, comments }
+    }
+
+    /// Renders the archive as a self-contained HTML page with inline styles, nesting replies beneath their parents.
+    pub fn to_html(&self) -> String {
+        html::render(self)
+    }
+
+    /// Renders the archive as a Markdown document, nesting replies within block quotes.
+    pub fn to_markdown(&self) -> String {
+        markdown::render(self)
+    }
+}
+
+impl TryFrom<HackerNewsThread> for HackerNewsArchive {
+    type Error = HackerNewsClientError;
+
+    fn try_from(thread: HackerNewsThread) -> Result<Self, Self::Error> {
+        let story = HackerNewsStory::try_from(thread.root)?;
+        Ok(Self::new(story, thread.comments))
+    }
+}
+
+/// The comment's author, or a placeholder for deleted comments.
+fn comment_author(comment: &HackerNewsComment) -> Option<&str> {
+    if comment.deleted || comment.by.is_empty() {
+        None
+    } else {
+        Some(&comment.by)
+    }
+}
+
+/// The placeholder displayed in place of the comment's text, if it should not be displayed.
+fn comment_placeholder(comment: &HackerNewsComment) -> Option<&'static str> {
+    if comment.deleted {
+        Some(DELETED_PLACEHOLDER)
+    } else if comment.dead {
+        Some(DEAD_PLACEHOLDER)
+    } else {
+        None
+    }
+}
+
+/// Formats timestamps for display, e.g. `2023-11-14 22:13 UTC`.
+fn display_time(time: OffsetDateTime) -> String {
+    let time = time.to_offset(time::UtcOffset::UTC);
+
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02} UTC",
+        time.year(),
+        u8::from(time.month()),
+        time.day(),
+        time.hour(),
+        time.minute()
+    )
+}
@@ -0,0 +1,30 @@
+//! The `hn-tui` interactive terminal reader for browsing Hacker News stories and comment threads,
+//! built on the newswrap client. Set `HN_BASE_URL` to read from a local mirror of the API.
+
+use std::process::ExitCode;
+
+use newswrap::{
+    client::{HackerNewsClient, API_BASE_URL},
+    tui,
+};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let base_url = std::env::var("HN_BASE_URL").unwrap_or_else(|_| API_BASE_URL.to_string());
+
+    let client = match HackerNewsClient::builder().base_url(base_url).build() {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match tui::run(client).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! The `hn` command line interface for browsing Hacker News items, users, and realtime lists,
+//! built on the newswrap client.
+
+mod output;
+
+use std::{error::Error, process::ExitCode, time::Duration};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use newswrap::{
+    client::{HackerNewsClient, API_BASE_URL},
+    HackerNewsID,
+};
+
+/// Default number of stories listed for realtime lists.
+const DEFAULT_LIST_LIMIT: usize = 30;
+
+/// Default request timeout in seconds.
+const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+
+/// Browse Hacker News items, users, and realtime lists from the command line.
+#[derive(Debug, Parser)]
+#[command(name = "hn", version, about)]
+struct Cli {
+    /// Base URL of the Hacker News API including the version path, e.g. for local mirrors.
+    #[arg(long, global = true, env = "HN_BASE_URL", default_value = API_BASE_URL)]
+    base_url: String,
+
+    /// Request timeout in seconds.
+    #[arg(long, global = true, default_value_t = DEFAULT_TIMEOUT_SECONDS)]
+    timeout: u64,
+
+    /// Output format.
+    #[arg(long, short, global = true, value_enum, default_value_t = OutputFormat::Plain)]
+    format: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Output formats supported by all commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Pretty printed JSON using the crate's serialized types.
+    Json,
+    /// Aligned columns.
+    Table,
+    /// Human readable text with HTML rendered as plain text.
+    Plain,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Retrieve an item by its ID.
+    Item {
+        /// The item's ID.
+        id: HackerNewsID,
+    },
+    /// Retrieve a user's profile.
+    User {
+        /// The user's username.
+        username: String,
+    },
+    /// List the current top stories and jobs.
+    Top(ListArgs),
+    /// List the newest stories.
+    New(ListArgs),
+    /// List the best stories.
+    Best(ListArgs),
+    /// List the latest Ask HN stories.
+    Ask(ListArgs),
+    /// List the latest Show HN stories.
+    Show(ListArgs),
+    /// List the latest job stories.
+    Jobs(ListArgs),
+    /// Retrieve an item along with its entire comment tree.
+    Thread {
+        /// The item's ID, typically a story.
+        id: HackerNewsID,
+
+        /// Maximum depth of replies to display, where top level comments have a depth of zero.
+        #[arg(long)]
+        depth: Option<usize>,
+    },
+    /// List recently updated items and profiles.
+    Updates,
+}
+
+#[derive(Debug, Args)]
+struct ListArgs {
+    /// Maximum number of stories to retrieve.
+    #[arg(long, short, default_value_t = DEFAULT_LIST_LIMIT)]
+    limit: usize,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(output) => {
+            println!("{}", output.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<String, Box<dyn Error>> {
+    let client = HackerNewsClient::builder()
+        .base_url(cli.base_url)
+        .timeout(Duration::from_secs(cli.timeout))
+        .build()?;
+
+    let format = cli.format;
+
+    let output = match cli.command {
+        Command::Item { id } => {
+            let item = client.items.get_item(id).await?;
+            output::item(&item, format)?
+        }
+        Command::User { username } => {
+            let user = client.users.get_user(&username).await?;
+            output::user(&user, format)?
+        }
+        Command::Top(args) => {
+            let ids = client.realtime.get_top_stories().await?;
+            list(&client, &ids, args, format).await?
+        }
+        Command::New(args) => {
+            let ids = client.realtime.get_latest_stories().await?;
+            list(&client, &ids, args, format).await?
+        }
+        Command::Best(args) => {
+            let ids = client.realtime.get_best_stories().await?;
+            list(&client, &ids, args, format).await?
+        }
+        Command::Ask(args) => {
+            let ids = client.realtime.get_ask_hacker_news_stories().await?;
+            list(&client, &ids, args, format).await?
+        }
+        Command::Show(args) => {
+            let ids = client.realtime.get_show_hacker_news_stories().await?;
+            list(&client, &ids, args, format).await?
+        }
+        Command::Jobs(args) => {
+            let ids = client.realtime.get_job_hacker_news_stories().await?;
+            list(&client, &ids, args, format).await?
+        }
+        Command::Thread { id, depth } => {
+            let mut thread = client.items.get_thread(id).await?;
+
+            if let Some(depth) = depth {
+                output::prune_thread(&mut thread, depth);
+            }
+
+            output::thread(&thread, format)?
+        }
+        Command::Updates => {
+            let updates = client.realtime.get_recent_updates().await?;
+            output::updates(&updates, format)?
+        }
+    };
+
+    Ok(output)
+}
+
+async fn list(
+    client: &HackerNewsClient,
+    ids: &[HackerNewsID],
+    args: ListArgs,
+    format: OutputFormat,
+) -> Result<String, Box<dyn Error>> {
+    let ids = &ids[..args.limit.min(ids.len())];
+    let items = client.items.get_existing_items(ids).await?;
+    Ok(output::items(&items, format)?)
+}
@@ -0,0 +1,349 @@
+//! Rendering of items, users, threads, and updates into each of the supported output formats.
+
+use newswrap::{
+    items::{
+        threads::{HackerNewsCommentNode, HackerNewsThread},
+        HackerNewsItem,
+    },
+    realtime::HackerNewsUpdatedItems,
+    users::HackerNewsUser,
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::OutputFormat;
+
+/// Maximum number of characters displayed for titles and text within table cells.
+const MAX_CELL_WIDTH: usize = 72;
+
+/// Indentation applied per level of replies when rendering threads as plain text.
+const THREAD_INDENT: &str = "  ";
+
+type OutputResult = Result<String, serde_json::Error>;
+
+/// Renders a single item.
+pub fn item(item: &HackerNewsItem, format: OutputFormat) -> OutputResult {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(item),
+        OutputFormat::Table => {
+            let mut rows = vec![
+                field("id", item.id.to_string()),
+                field("type", item.get_item_type().to_string()),
+                field("by", item.by.clone().unwrap_or_default()),
+                field("time", timestamp(item.created_at)),
+            ];
+
+            push_optional(&mut rows, "title", item.title_plain());
+            push_optional(&mut rows, "url", item.url.clone());
+            push_optional(&mut rows, "score", item.score);
+            push_optional(&mut rows, "comments", item.descendants);
+            push_optional(&mut rows, "parent", item.parent);
+            push_optional(&mut rows, "poll", item.poll);
+            push_optional(&mut rows, "kids", item.kids.as_ref().map(Vec::len));
+            push_optional(&mut rows, "parts", item.parts.as_ref().map(Vec::len));
+            push_optional(
+                &mut rows,
+                "text",
+                item.text_plain().map(|text| truncate(&text)),
+            );
+            rows.push(field("permalink", item.permalink()));
+
+            Ok(table(&["FIELD", "VALUE"], rows))
+        }
+        OutputFormat::Plain => Ok(plain_item(item)),
+    }
+}
+
+/// Renders a list of items, typically from one of the realtime lists.
+pub fn items(items: &[HackerNewsItem], format: OutputFormat) -> OutputResult {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(items),
+        OutputFormat::Table => {
+            let rows = items
+                .iter()
+                .map(|item| {
+                    vec![
+                        item.id.to_string(),
+                        item.get_item_type().to_string(),
+                        item.score.unwrap_or_default().to_string(),
+                        item.descendants.unwrap_or_default().to_string(),
+                        item.by.clone().unwrap_or_default(),
+                        truncate(&item.title_plain().unwrap_or_default()),
+                    ]
+                })
+                .collect();
+
+            Ok(table(
+                &["ID", "TYPE", "SCORE", "COMMENTS", "BY", "TITLE"],
+                rows,
+            ))
+        }
+        OutputFormat::Plain => {
+            let mut output = String::new();
+
+            for (rank, item) in items.iter().enumerate() {
+                let title = item.title_plain().unwrap_or_default();
+
+                match item.site() {
+                    Some(site) => {
+                        output.push_str(&format!("{:>3}. {} ({})\n", rank + 1, title, site))
+                    }
+                    None => output.push_str(&format!("{:>3}. {}\n", rank + 1, title)),
+                }
+
+                output.push_str(&format!(
+                    "     {} points by {} | {} comments | {}\n",
+                    item.score.unwrap_or_default(),
+                    item.by.as_deref().unwrap_or_default(),
+                    item.descendants.unwrap_or_default(),
+                    item.permalink()
+                ));
+            }
+
+            Ok(output)
+        }
+    }
+}
+
+/// Renders a user's profile.
+pub fn user(user: &HackerNewsUser, format: OutputFormat) -> OutputResult {
+    let submissions = user.stories.as_ref().map(Vec::len).unwrap_or_default();
+
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(user),
+        OutputFormat::Table => {
+            let mut rows = vec![
+                field("id", user.id.clone()),
+                field("created", timestamp(user.created)),
+                field("karma", user.karma),
+                field("submissions", submissions),
+            ];
+
+            push_optional(
+                &mut rows,
+                "about",
+                user.about_plain().map(|about| truncate(&about)),
+            );
+            rows.push(field("permalink", user.permalink()));
+
+            Ok(table(&["FIELD", "VALUE"], rows))
+        }
+        OutputFormat::Plain => {
+            let mut output = format!(
+                "{}\nkarma: {} | created: {} | submissions: {}\n{}\n",
+                user.id,
+                user.karma,
+                timestamp(user.created),
+                submissions,
+                user.permalink()
+            );
+
+            if let Some(about) = user.about_plain() {
+                output.push_str(&format!("\n{}\n", about));
+            }
+
+            Ok(output)
+        }
+    }
+}
+
+/// Renders an item along with its comment tree.
+pub fn thread(thread: &HackerNewsThread, format: OutputFormat) -> OutputResult {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(thread),
+        OutputFormat::Table => {
+            let rows = thread
+                .iter()
+                .map(|(depth, node)| {
+                    vec![
+                        node.comment.id.to_string(),
+                        depth.to_string(),
+                        comment_author(node),
+                        node.reply_count().to_string(),
+                        truncate(&comment_text(node)),
+                    ]
+                })
+                .collect();
+
+            Ok(table(&["ID", "DEPTH", "BY", "REPLIES", "TEXT"], rows))
+        }
+        OutputFormat::Plain => {
+            let mut output = plain_item(&thread.root);
+
+            for (depth, node) in thread.iter() {
+                let indent = THREAD_INDENT.repeat(depth + 1);
+
+                output.push_str(&format!(
+                    "\n{}{} | {}\n",
+                    indent,
+                    comment_author(node),
+                    timestamp(node.comment.created_at)
+                ));
+
+                for line in comment_text(node).lines() {
+                    output.push_str(&format!("{}{}\n", indent, line));
+                }
+            }
+
+            Ok(output)
+        }
+    }
+}
+
+/// Renders recently updated items and profiles.
+pub fn updates(updates: &HackerNewsUpdatedItems, format: OutputFormat) -> OutputResult {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(updates),
+        OutputFormat::Table => {
+            let rows = updates
+                .items
+                .iter()
+                .map(|id| vec!["item".to_string(), id.to_string()])
+                .chain(
+                    updates
+                        .profiles
+                        .iter()
+                        .map(|profile| vec!["profile".to_string(), profile.clone()]),
+                )
+                .collect();
+
+            Ok(table(&["KIND", "ID"], rows))
+        }
+        OutputFormat::Plain => {
+            let items: Vec<String> = updates.items.iter().map(|id| id.to_string()).collect();
+
+            Ok(format!(
+                "items: {}\nprofiles: {}\n",
+                items.join(", "),
+                updates.profiles.join(", ")
+            ))
+        }
+    }
+}
+
+/// Removes replies nested deeper than the maximum depth, where top level comments have a depth of zero.
+pub fn prune_thread(thread: &mut HackerNewsThread, max_depth: usize) {
+    fn prune(nodes: &mut [HackerNewsCommentNode], depth: usize, max_depth: usize) {
+        for node in nodes {
+            if depth >= max_depth {
+                node.replies.clear();
+            } else {
+                prune(&mut node.replies, depth + 1, max_depth);
+            }
+        }
+    }
+
+    prune(&mut thread.comments, 0, max_depth);
+}
+
+fn plain_item(item: &HackerNewsItem) -> String {
+    let mut output = String::new();
+
+    if let Some(title) = item.title_plain() {
+        output.push_str(&format!("{}\n", title));
+    }
+
+    let mut details = vec![
+        format!("{} {}", item.get_item_type(), item.id),
+        format!("by {}", item.by.as_deref().unwrap_or_default()),
+        timestamp(item.created_at),
+    ];
+
+    if let Some(score) = item.score {
+        details.push(format!("{} points", score));
+    }
+
+    if let Some(descendants) = item.descendants {
+        details.push(format!("{} comments", descendants));
+    }
+
+    output.push_str(&format!("{}\n", details.join(" | ")));
+
+    if let Some(url) = item.url.as_deref().filter(|url| !url.is_empty()) {
+        output.push_str(&format!("{}\n", url));
+    }
+
+    output.push_str(&format!("{}\n", item.permalink()));
+
+    if let Some(text) = item.text_plain().filter(|text| !text.is_empty()) {
+        output.push_str(&format!("\n{}\n", text));
+    }
+
+    output
+}
+
+fn comment_author(node: &HackerNewsCommentNode) -> String {
+    if node.comment.deleted {
+        "[deleted]".to_string()
+    } else {
+        node.comment.by.clone()
+    }
+}
+
+fn comment_text(node: &HackerNewsCommentNode) -> String {
+    if node.comment.deleted {
+        "[deleted]".to_string()
+    } else if node.comment.dead {
+        "[dead]".to_string()
+    } else {
+        node.comment.text_plain()
+    }
+}
+
+fn timestamp(time: OffsetDateTime) -> String {
+    time.format(&Rfc3339).unwrap_or_default()
+}
+
+fn field(name: &str, value: impl ToString) -> Vec<String> {
+    vec![name.to_string(), value.to_string()]
+}
+
+fn push_optional(rows: &mut Vec<Vec<String>>, name: &str, value: Option<impl ToString>) {
+    if let Some(value) = value {
+        rows.push(field(name, value));
+    }
+}
+
+/// Truncates text to fit within a table cell, collapsing newlines into spaces.
+fn truncate(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.chars().count() <= MAX_CELL_WIDTH {
+        return text;
+    }
+
+    let truncated: String = text.chars().take(MAX_CELL_WIDTH - 1).collect();
+    format!("{}…", truncated)
+}
+
+/// Renders rows as left-aligned columns separated by two spaces, with a header row.
+fn table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect();
+
+    for row in &rows {
+        for (index, cell) in row.iter().enumerate() {
+            widths[index] = widths[index].max(cell.chars().count());
+        }
+    }
+
+    let headers = headers.iter().map(|header| header.to_string()).collect();
+    let mut output = String::new();
+
+    for row in std::iter::once(headers).chain(rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let padding = widths[index] - cell.chars().count();
+                format!("{}{}", cell, " ".repeat(padding))
+            })
+            .collect();
+
+        output.push_str(cells.join("  ").trim_end());
+        output.push('\n');
+    }
+
+    output
+}
@@ -0,0 +1,149 @@
+//! Cancellation and deadlines for long-running operations, e.g. retrieving items in bulk or walking a comment tree.
+//! Operations stopped early return the results collected so far along with the reason they stopped, rather than
+//! discarding them.
+//!
+//! Futures are cancelled by dropping them, as axum does with handlers when clients disconnect. Tokens extend that to
+//! work spawned by a handler, cancelling it along with the handler with [`CancellationToken::drop_guard`]:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use newswrap::{
+//!     cancellation::{Cancellation, CancellationToken},
+//!     client::HackerNewsClient,
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = HackerNewsClient::new();
+//!     let token = CancellationToken::new();
+//!     let _guard = token.clone().drop_guard();
+//!
+//!     let cancellation = Cancellation::new()
+//!         .token(token)
+//!         .timeout(Duration::from_secs(5));
+//!     let thread = client.items.get_thread_until(8863, &cancellation).await.unwrap();
+//!
+//!     if let Some(error) = &thread.error {
+//!         println!("Showing a partial thread: {}", error);
+//!     }
+//! }
+//! ```
+
+use std::{
+    future::{pending, Future},
+    time::{Duration, Instant},
+};
+
+pub use tokio_util::sync::CancellationToken;
+
+use crate::errors::{HackerNewsClientError, HackerNewsResult};
+
+/// Bounds an operation by a cancellation token, a deadline or both. Operations run to completion by default.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+    token: Option<CancellationToken>,
+    deadline: Option<Instant>,
+}
+
+impl Cancellation {
+    /// Constructs a new cancellation with neither a token nor a deadline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the operation once the token is cancelled.
+    pub fn token(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Stops the operation once the deadline has passed.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stops the operation once the timeout has elapsed, starting from now.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.deadline(Instant::now() + timeout)
+    }
+
+    /// Checks whether the operation should stop, returning the reason if so.
+    pub fn check(&self) -> HackerNewsResult<()> {
+        if self
+            .token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Err(HackerNewsClientError::Cancelled);
+        }
+
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(HackerNewsClientError::DeadlineExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Runs the future until it completes or the operation should stop, whichever happens first.
+    pub async fn run<T>(
+        &self,
+        future: impl Future<Output = HackerNewsResult<T>>,
+    ) -> HackerNewsResult<T> {
+        self.check()?;
+
+        let cancelled = async {
+            match &self.token {
+                Some(token) => token.cancelled().await,
+                None => pending().await,
+            }
+        };
+
+        let deadline = async {
+            match self.deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                None => pending().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+            result = future => result,
+            _ = cancelled => Err(HackerNewsClientError::Cancelled),
+            _ = deadline => Err(HackerNewsClientError::DeadlineExceeded),
+        }
+    }
+}
+
+/// The results of an operation that may have stopped early, along with the error that stopped it.
+#[derive(Debug)]
+pub struct Partial<T> {
+    /// The results collected before the operation stopped, or every result if it ran to completion.
+    pub value: T,
+    /// The reason the operation stopped early, e.g. [`HackerNewsClientError::Cancelled`], if it did.
+    pub error: Option<HackerNewsClientError>,
+}
+
+impl<T> Partial<T> {
+    /// Constructs the results of an operation, stopped early if an error is given.
+    pub(crate) fn new(value: T, error: Option<HackerNewsClientError>) -> Self {
+        Self { value, error }
+    }
+
+    /// Determines if the operation ran to completion.
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns the results only if the operation ran to completion, otherwise the error that stopped it.
+    pub fn into_result(self) -> HackerNewsResult<T> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.value),
+        }
+    }
+}
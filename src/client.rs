@@ -5,31 +5,109 @@
 use std::time::Duration;
 
 use crate::{
-    http::InternalHttpClient, items::client::HackerNewsItemClient,
-    realtime::client::HackerNewsRealtimeClient, users::client::HackerNewsUserClient,
+    algolia::client::HackerNewsSearchClient, errors::HackerNewsResult, http::InternalHttpClient,
+    items::client::HackerNewsItemClient, realtime::client::HackerNewsRealtimeClient,
+    users::client::HackerNewsUserClient, versions::V0_BASE_URL,
 };
 
-/// Version information for the Hacker News API containing the base URLs.
-#[derive(Debug, Clone, Copy)]
-pub enum ApiVersion {
-    /// Represents version 0 of the Hacker News API.
-    V0,
-}
+pub use crate::versions::ApiVersion;
+
+/// The transport requests to the API are sent through when middleware is configured, a type-erased
+/// [`tower::Service`] wrapping the underlying HTTP client.
+#[cfg(feature = "tower")]
+pub type HackerNewsTransport =
+    tower::util::BoxCloneService<reqwest::Request, reqwest::Response, tower::BoxError>;
+
+/// A layer wrapping the transport, type-erased so layers of differing types can be composed by the builder.
+#[cfg(feature = "tower")]
+type TransportLayer =
+    std::sync::Arc<dyn Fn(HackerNewsTransport) -> HackerNewsTransport + Send + Sync + 'static>;
 
 /// All outgoing requests will have a user-agent associated to newswrap for request visibility.
 const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
 /// Current URL of the API.
-const API_BASE_URL: &str = "https://hacker-news.firebaseio.com/v0";
+pub const API_BASE_URL: &str = V0_BASE_URL;
+
+/// Current URL of the Algolia-backed search API.
+pub const SEARCH_API_BASE_URL: &str = "https://hn.algolia.com/api/v1";
 
 /// Default timeout for requests the API.
 const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
 
+/// Options applied to an individual request, overriding the client's configuration for latency-sensitive callers,
+/// e.g. a request handler with a tighter budget than background jobs sharing the same client.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use newswrap::client::RequestOptions;
+///
+/// let options = RequestOptions::new()
+///     .timeout(Duration::from_millis(500))
+///     .retries(1);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestOptions {
+    /// The timeout for each attempt at the request, defaulting to the timeout the client was configured with.
+    pub timeout: Option<Duration>,
+    /// Requests a fresh response rather than one served from an HTTP cache, e.g. a caching proxy in front of a mirror.
+    pub bypass_cache: bool,
+    /// Number of times the request is retried after timeouts, connection failures or server errors, defaulting to none.
+    pub retries: u32,
+}
+
+impl RequestOptions {
+    /// Constructs new options, applying the client's configuration without retries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configures the timeout for each attempt at the request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Requests a fresh response rather than one served from an HTTP cache.
+    pub fn bypass_cache(mut self, bypass_cache: bool) -> Self {
+        self.bypass_cache = bypass_cache;
+        self
+    }
+
+    /// Configures the number of times the request is retried after transient failures.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Applies the timeout and cache directives to the request.
+    pub(crate) fn apply(&self, mut request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        if self.bypass_cache {
+            request = request.header(reqwest::header::CACHE_CONTROL, "no-cache");
+        }
+
+        request
+    }
+}
+
 /// A wrapping HTTP client for Hacker News Firebase API and real-time data.
 /// A client instance should only be instantiated once in an application's
 /// lifecycle, seeking to reuse it where possible. Clients can be configured
 /// with requests timeouts in seconds, defaulting to 10 seconds.
 ///
+/// Clients and their sub-clients are cheap to clone, sharing the connection
+/// pool and any middleware, so clones can be handed to spawned tasks or
+/// used as application state in place of wrapping the client in an `Arc`.
+/// Clients keep no response cache, rate limiter or metrics of their own;
+/// middleware layers added with the `tower` feature provide these, and are
+/// shared by every clone.
+///
 /// ```
 /// use newswrap::client::HackerNewsClient;
 ///
@@ -39,8 +117,14 @@ const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
 /// // Or, with an optional timeout
 /// let custom_client = HackerNewsClient::new_with_timeout_secs(10);
 /// let custom_client_with_duration = HackerNewsClient::new_with_timeout_duration(std::time::Duration::from_millis(400));
+///
+/// // Or, pointing to a local mirror of the API
+/// let mirror_client = HackerNewsClient::builder()
+///     .base_url("http://localhost:8080/v0")
+///     .build()
+///     .unwrap();
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HackerNewsClient {
     /// An internal item client for interacting with items.
     pub items: HackerNewsItemClient,
@@ -48,6 +132,8 @@ pub struct HackerNewsClient {
     pub users: HackerNewsUserClient,
     /// An internal realtime client for interacting with live data.
     pub realtime: HackerNewsRealtimeClient,
+    /// An internal search client for interacting with the Algolia-backed search API.
+    pub search: HackerNewsSearchClient,
     /// The internal version of the Hacker News API your client will target.
     pub version: ApiVersion,
 }
@@ -61,24 +147,11 @@ impl Default for HackerNewsClient {
 impl HackerNewsClient {
     /// Internally constructs the client allowing for flexibility in configuring the timeout.
     fn new_client(timeout: std::time::Duration) -> Self {
-        let client = reqwest::ClientBuilder::new()
+        HackerNewsClientBuilder::new()
             .timeout(timeout)
-            .user_agent(USER_AGENT)
             .build()
-            // TODO: probably move this to a builder of sorts, if this panics we have much bigger problems
-            .unwrap();
-
-        let internal_client = InternalHttpClient::new(client, API_BASE_URL);
-        let item_client = HackerNewsItemClient::new(internal_client.clone());
-        let user_client: HackerNewsUserClient = HackerNewsUserClient::new(internal_client.clone());
-        let realtime_client = HackerNewsRealtimeClient::new(internal_client);
-
-        Self {
-            items: item_client,
-            users: user_client,
-            realtime: realtime_client,
-            version: ApiVersion::V0,
-        }
+            // If building the default HTTP client panics, we have much bigger problems
+            .unwrap()
     }
 
     /// Constructs a new client pointing to the latest Hacker News API version.
@@ -97,4 +170,159 @@ impl HackerNewsClient {
     pub fn new_with_timeout_duration(duration: Duration) -> Self {
         Self::new_client(duration)
     }
+
+    /// Creates a builder for configuring the client's request timeout and API base URLs.
+    pub fn builder() -> HackerNewsClientBuilder {
+        HackerNewsClientBuilder::new()
+    }
+}
+
+/// A builder for configuring clients beyond the default request timeout, e.g. to target a local mirror of the API.
+#[derive(Clone)]
+pub struct HackerNewsClientBuilder {
+    timeout: Duration,
+    version: ApiVersion,
+    base_url: Option<String>,
+    search_base_url: String,
+    #[cfg(feature = "tower")]
+    layers: Vec<TransportLayer>,
+}
+
+impl std::fmt::Debug for HackerNewsClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut builder = f.debug_struct("HackerNewsClientBuilder");
+        builder
+            .field("timeout", &self.timeout)
+            .field("version", &self.version)
+            .field("base_url", &self.base_url)
+            .field("search_base_url", &self.search_base_url);
+
+        #[cfg(feature = "tower")]
+        builder.field("layers", &self.layers.len());
+
+        builder.finish()
+    }
+}
+
+impl Default for HackerNewsClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HackerNewsClientBuilder {
+    /// Constructs a new builder with the default request timeout, pointing to the latest Hacker News API version.
+    pub fn new() -> Self {
+        Self {
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
+            version: ApiVersion::default(),
+            base_url: None,
+            search_base_url: SEARCH_API_BASE_URL.to_string(),
+            #[cfg(feature = "tower")]
+            layers: Vec::new(),
+        }
+    }
+
+    /// Configures the request timeout for all requests made by the client.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Configures the version of the API requests are made against, determining its URLs and response formats. Version 0
+    /// is currently the only version, so this only needs setting to pin it explicitly.
+    pub fn version(mut self, version: ApiVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Configures the base URL requests are made against, including the API version path, e.g. `http://localhost:8080/v0`.
+    /// Defaults to the URL of the configured API version.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Configures the base URL search requests are made against, including the API version path, e.g. `http://localhost:8080/api/v1`.
+    pub fn search_base_url(mut self, search_base_url: impl Into<String>) -> Self {
+        self.search_base_url = search_base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Wraps the transport requests are sent through with the layer, e.g. a timeout, retry or concurrency limit from
+    /// [tower](https://docs.rs/tower) or a layer of your own. Layers are applied in the order given with the first
+    /// being outermost, as with `tower::ServiceBuilder`. Layered services must be `Clone`, so layers producing
+    /// services that aren't, e.g. rate limits, should be wrapped with a `BufferLayer`.
+    #[cfg(feature = "tower")]
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: tower::Layer<HackerNewsTransport> + Send + Sync + 'static,
+        L::Service:
+            tower::Service<reqwest::Request, Response = reqwest::Response> + Clone + Send + 'static,
+        <L::Service as tower::Service<reqwest::Request>>::Error: Into<tower::BoxError>,
+        <L::Service as tower::Service<reqwest::Request>>::Future: Send + 'static,
+    {
+        use tower::ServiceExt;
+
+        self.layers.push(std::sync::Arc::new(move |transport| {
+            HackerNewsTransport::new(layer.layer(transport).map_err(Into::into))
+        }));
+        self
+    }
+
+    /// Builds the client, returning an error if the underlying HTTP client could not be initialized.
+    pub fn build(self) -> HackerNewsResult<HackerNewsClient> {
+        let client = reqwest::ClientBuilder::new()
+            .timeout(self.timeout)
+            .user_agent(USER_AGENT)
+            .build()?;
+
+        let search_client = InternalHttpClient::new(client.clone(), self.search_base_url);
+        let base_url = self
+            .base_url
+            .unwrap_or_else(|| self.version.base_url().to_string());
+        let internal_client =
+            InternalHttpClient::new(client.clone(), base_url).with_version(self.version);
+
+        #[cfg(feature = "tower")]
+        let (search_client, internal_client) = if self.layers.is_empty() {
+            (search_client, internal_client)
+        } else {
+            let transport = Self::layered_transport(client, &self.layers);
+            (
+                search_client.with_transport(transport.clone()),
+                internal_client.with_transport(transport),
+            )
+        };
+
+        let search_client = HackerNewsSearchClient::new(search_client);
+        let item_client = HackerNewsItemClient::new(internal_client.clone());
+        let user_client: HackerNewsUserClient = HackerNewsUserClient::new(internal_client.clone());
+        let realtime_client = HackerNewsRealtimeClient::new(internal_client);
+
+        Ok(HackerNewsClient {
+            items: item_client,
+            users: user_client,
+            realtime: realtime_client,
+            search: search_client,
+            version: self.version,
+        })
+    }
+
+    /// Builds the transport wrapping the HTTP client with the layers, the first layer being outermost.
+    #[cfg(feature = "tower")]
+    fn layered_transport(
+        client: reqwest::Client,
+        layers: &[TransportLayer],
+    ) -> HackerNewsTransport {
+        let transport = HackerNewsTransport::new(tower::service_fn(move |request| {
+            let client = client.clone();
+            async move { client.execute(request).await.map_err(tower::BoxError::from) }
+        }));
+
+        layers
+            .iter()
+            .rev()
+            .fold(transport, |transport, layer| layer(transport))
+    }
 }
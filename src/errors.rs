@@ -14,6 +14,7 @@ pub type HackerNewsResult<T> = Result<T, HackerNewsClientError>;
 
 /// Exported types for handling internal errors with the client.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum HackerNewsClientError {
     /// Reports errors that occur when making HTTP requests to Hacker News.
     #[error("{0}")]
@@ -33,6 +34,21 @@ pub enum HackerNewsClientError {
     /// Represents missing item or user data returned from the API,
     #[error("Item or user {0} was not found.")]
     ItemOrUserNotFound(String),
+    /// Represents a failure to write exported items or users to the underlying writer.
+    #[error("Failed to export records: {0}")]
+    ExportError(#[source] Box<dyn std::error::Error + Send + Sync>),
+    /// Represents a failure to serialize webhook events or record undeliverable ones to the dead letter log.
+    #[error("Failed to process webhook event: {0}")]
+    WebhookError(#[source] Box<dyn std::error::Error + Send + Sync>),
+    /// Represents an error raised by middleware wrapping the transport, e.g. a timeout or load shedding layer.
+    #[error("{0}")]
+    TransportError(Box<dyn std::error::Error + Send + Sync>),
+    /// Represents an operation stopped early by its cancellation token.
+    #[error("The operation was cancelled.")]
+    Cancelled,
+    /// Represents an operation stopped early after running past its deadline.
+    #[error("The operation did not complete before its deadline.")]
+    DeadlineExceeded,
     /// Represents a seemingly infallible operation that has occurred.
     #[error(
         "An infallible operation has occurred. If you're seeing this, please report an issue!"
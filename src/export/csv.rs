@@ -0,0 +1,79 @@
+//! CSV export with a header row of the record's columns.
+
+use std::{io::Write, marker::PhantomData};
+
+use time::format_description::well_known::Rfc3339;
+
+use crate::{
+    errors::HackerNewsResult,
+    export::{export_error, ExportRecord, ExportValue, RecordExporter},
+};
+
+/// Writes records as CSV, starting with a header row of the record's columns. Missing values are written as empty
+/// fields, timestamps as RFC 3339 (failing the write if a timestamp can't be formatted), and ID lists as space separated IDs.
+#[derive(Debug)]
+pub struct CsvExporter<W: Write, R: ExportRecord> {
+    writer: csv::Writer<W>,
+    header_written: bool,
+    record: PhantomData<R>,
+}
+
+impl<W: Write, R: ExportRecord> CsvExporter<W, R> {
+    /// Constructs a new exporter writing to the writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: csv::Writer::from_writer(writer),
+            header_written: false,
+            record: PhantomData,
+        }
+    }
+
+    /// Flushes all written records, returning the underlying writer. The header row is written
+    /// even if no records were exported.
+    pub fn finish(mut self) -> HackerNewsResult<W> {
+        self.write_header()?;
+        self.writer
+            .into_inner()
+            .map_err(|error| export_error(error.into_error()))
+    }
+
+    fn write_header(&mut self) -> HackerNewsResult<()> {
+        if !self.header_written {
+            let names = R::columns().iter().map(|column| column.name);
+            self.writer.write_record(names).map_err(export_error)?;
+            self.header_written = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write, R: ExportRecord> RecordExporter<R> for CsvExporter<W, R> {
+    fn write(&mut self, record: &R) -> HackerNewsResult<()> {
+        self.write_header()?;
+
+        let fields = record
+            .values()
+            .into_iter()
+            .map(field)
+            .collect::<HackerNewsResult<Vec<_>>>()?;
+        self.writer.write_record(fields).map_err(export_error)
+    }
+}
+
+fn field(value: ExportValue) -> HackerNewsResult<String> {
+    let field = match value {
+        ExportValue::Null => String::new(),
+        ExportValue::Integer(value) => value.to_string(),
+        ExportValue::Boolean(value) => value.to_string(),
+        ExportValue::Text(value) => value,
+        ExportValue::Timestamp(value) => value.format(&Rfc3339).map_err(export_error)?,
+        ExportValue::Identifiers(ids) => ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(" "),
+    };
+
+    Ok(field)
+}
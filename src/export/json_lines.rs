@@ -0,0 +1,38 @@
+//! JSON Lines export, writing each record as it serializes on its own line.
+
+use std::{io::Write, marker::PhantomData};
+
+use crate::{
+    errors::HackerNewsResult,
+    export::{export_error, ExportRecord, RecordExporter},
+};
+
+/// Writes records as JSON Lines, one serialized record per line.
+#[derive(Debug)]
+pub struct JsonLinesExporter<W: Write, R: ExportRecord> {
+    writer: W,
+    record: PhantomData<R>,
+}
+
+impl<W: Write, R: ExportRecord> JsonLinesExporter<W, R> {
+    /// Constructs a new exporter writing to the writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            record: PhantomData,
+        }
+    }
+
+    /// Flushes all written records, returning the underlying writer.
+    pub fn finish(mut self) -> HackerNewsResult<W> {
+        self.writer.flush().map_err(export_error)?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write, R: ExportRecord> RecordExporter<R> for JsonLinesExporter<W, R> {
+    fn write(&mut self, record: &R) -> HackerNewsResult<()> {
+        serde_json::to_writer(&mut self.writer, record).map_err(export_error)?;
+        self.writer.write_all(b"\n").map_err(export_error)
+    }
+}
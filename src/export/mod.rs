@@ -0,0 +1,166 @@
+//! Exporters for writing items and users to JSON Lines, to CSV behind the `csv` feature, and to Parquet and Arrow
+//! behind the `parquet` feature, for feeding Hacker News data into analytics pipelines. Exporters accept any
+//! iterator of records, including the results of batch fetches, or a stream of fallible records.
+//!
+//! Records are flattened into a stable set of columns. Item lists such as `kids` and `parts` are written as
+//! space separated IDs in CSV and as lists in Parquet, while JSON Lines writes each record as it serializes.
+//!
+//! ```no_run
+//! use newswrap::{
+//!     client::HackerNewsClient,
+//!     export::{JsonLinesExporter, RecordExporter},
+//!     items::HackerNewsItem,
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!     let ids = client.realtime.get_top_stories().await?;
+//!     let items = client.items.get_items(&ids).await?;
+//!
+//!     let mut exporter = JsonLinesExporter::<_, HackerNewsItem>::new(std::io::stdout());
+//!     exporter.write_all(&items)?;
+//!     exporter.finish()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+#[cfg(feature = "csv")]
+mod csv;
+mod json_lines;
+#[cfg(feature = "parquet")]
+mod parquet;
+mod records;
+
+use std::{borrow::Borrow, future::Future};
+
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{
+    errors::{HackerNewsClientError, HackerNewsResult},
+    HackerNewsID,
+};
+
+#[cfg(feature = "csv")]
+pub use self::csv::CsvExporter;
+pub use json_lines::JsonLinesExporter;
+#[cfg(feature = "parquet")]
+pub use parquet::{to_record_batch, ParquetExporter};
+
+/// The kinds of values written to each column, determining their CSV representation and Arrow data type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportColumnKind {
+    /// Whole numbers such as IDs, scores, and karma.
+    Integer,
+    /// Flags such as `deleted` and `dead`.
+    Boolean,
+    /// Text such as titles, usernames, and HTML content.
+    Text,
+    /// Points in time, written as RFC 3339 in CSV and as UTC second timestamps in Arrow.
+    Timestamp,
+    /// Lists of item IDs such as `kids` and `parts`.
+    Identifiers,
+}
+
+/// Describes a single exported column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportColumn {
+    /// The column's name, used for CSV headers and Arrow fields.
+    pub name: &'static str,
+    /// The kind of values written to the column.
+    pub kind: ExportColumnKind,
+}
+
+impl ExportColumn {
+    const fn new(name: &'static str, kind: ExportColumnKind) -> Self {
+        Self { name, kind }
+    }
+}
+
+/// A single value within an exported row, matching its column's kind or missing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportValue {
+    /// A missing value.
+    Null,
+    /// A whole number.
+    Integer(i64),
+    /// A flag.
+    Boolean(bool),
+    /// Text.
+    Text(String),
+    /// A point in time.
+    Timestamp(OffsetDateTime),
+    /// A list of item IDs.
+    Identifiers(Vec<HackerNewsID>),
+}
+
+impl ExportValue {
+    fn integer(value: impl Into<i64>) -> Self {
+        Self::Integer(value.into())
+    }
+
+    fn optional<T>(value: Option<T>, variant: impl FnOnce(T) -> Self) -> Self {
+        value.map(variant).unwrap_or(Self::Null)
+    }
+}
+
+/// Records that can be exported, flattening into a stable set of columns.
+pub trait ExportRecord: Serialize {
+    /// The record's columns, in the order they are written.
+    fn columns() -> &'static [ExportColumn];
+
+    /// The record's values, in the same order as its columns.
+    fn values(&self) -> Vec<ExportValue>;
+}
+
+/// Writers of a single record type to an export format.
+pub trait RecordExporter<R: ExportRecord> {
+    /// Writes a single record.
+    fn write(&mut self, record: &R) -> HackerNewsResult<()>;
+
+    /// Writes all records from the iterator, returning the number of records written.
+    fn write_all<I>(&mut self, records: I) -> HackerNewsResult<usize>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<R>,
+    {
+        let mut written = 0;
+
+        for record in records {
+            self.write(record.borrow())?;
+            written += 1;
+        }
+
+        Ok(written)
+    }
+
+    /// Writes all records from the stream, stopping at the first failed record and returning
+    /// the number of records written.
+    fn write_stream<S, T>(&mut self, stream: S) -> impl Future<Output = HackerNewsResult<usize>>
+    where
+        S: Stream<Item = HackerNewsResult<T>>,
+        T: Borrow<R>,
+    {
+        async move {
+            let mut stream = std::pin::pin!(stream);
+            let mut written = 0;
+
+            while let Some(record) = stream.next().await {
+                self.write(record?.borrow())?;
+                written += 1;
+            }
+
+            Ok(written)
+        }
+    }
+}
+
+/// Maps errors from the underlying writers and encoders to their library equivalent.
+fn export_error(
+    error: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+) -> HackerNewsClientError {
+    HackerNewsClientError::ExportError(error.into())
+}
@@ -0,0 +1,187 @@
+//! Arrow record batches and Parquet export, available behind the `parquet` feature.
+
+use std::{borrow::Borrow, io::Write, marker::PhantomData, sync::Arc};
+
+use arrow_array::{
+    builder::{
+        ArrayBuilder, BooleanBuilder, Int64Builder, ListBuilder, StringBuilder,
+        TimestampSecondBuilder, UInt32Builder,
+    },
+    ArrayRef, RecordBatch,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use parquet::arrow::ArrowWriter;
+
+use crate::{
+    errors::HackerNewsResult,
+    export::{export_error, ExportColumnKind, ExportRecord, ExportValue, RecordExporter},
+};
+
+/// Number of records buffered before being written as a row group.
+const BATCH_SIZE: usize = 1024;
+
+/// Time zone applied to exported timestamps.
+const TIMESTAMP_TIME_ZONE: &str = "UTC";
+
+/// Writes records as Parquet, buffering records into row groups with a schema derived from the record's columns.
+/// ID lists are written as lists of unsigned integers and timestamps as UTC seconds.
+pub struct ParquetExporter<W: Write + Send, R: ExportRecord> {
+    writer: ArrowWriter<W>,
+    buffer: Vec<Vec<ExportValue>>,
+    record: PhantomData<R>,
+}
+
+impl<W: Write + Send, R: ExportRecord> ParquetExporter<W, R> {
+    /// Constructs a new exporter writing to the writer.
+    pub fn new(writer: W) -> HackerNewsResult<Self> {
+        let writer = ArrowWriter::try_new(writer, schema::<R>(), None).map_err(export_error)?;
+
+        Ok(Self {
+            writer,
+            buffer: Vec::with_capacity(BATCH_SIZE),
+            record: PhantomData,
+        })
+    }
+
+    /// Writes all buffered records and the Parquet footer, returning the underlying writer.
+    pub fn finish(mut self) -> HackerNewsResult<W> {
+        self.flush()?;
+        self.writer.into_inner().map_err(export_error)
+    }
+
+    fn flush(&mut self) -> HackerNewsResult<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let rows = std::mem::take(&mut self.buffer);
+        let batch = record_batch::<R>(rows)?;
+
+        self.writer.write(&batch).map_err(export_error)
+    }
+}
+
+impl<W: Write + Send, R: ExportRecord> RecordExporter<R> for ParquetExporter<W, R> {
+    fn write(&mut self, record: &R) -> HackerNewsResult<()> {
+        self.buffer.push(record.values());
+
+        if self.buffer.len() >= BATCH_SIZE {
+            self.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write + Send, R: ExportRecord> std::fmt::Debug for ParquetExporter<W, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParquetExporter")
+            .field("buffered", &self.buffer.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Converts records into an Arrow record batch with a schema derived from the record's columns.
+pub fn to_record_batch<R, I>(records: I) -> HackerNewsResult<RecordBatch>
+where
+    R: ExportRecord,
+    I: IntoIterator,
+    I::Item: Borrow<R>,
+{
+    let rows = records
+        .into_iter()
+        .map(|record| record.borrow().values())
+        .collect();
+
+    record_batch::<R>(rows)
+}
+
+fn schema<R: ExportRecord>() -> SchemaRef {
+    let fields: Vec<Field> = R::columns()
+        .iter()
+        .map(|column| Field::new(column.name, data_type(column.kind), true))
+        .collect();
+
+    Arc::new(Schema::new(fields))
+}
+
+fn data_type(kind: ExportColumnKind) -> DataType {
+    match kind {
+        ExportColumnKind::Integer => DataType::Int64,
+        ExportColumnKind::Boolean => DataType::Boolean,
+        ExportColumnKind::Text => DataType::Utf8,
+        ExportColumnKind::Timestamp => {
+            DataType::Timestamp(TimeUnit::Second, Some(TIMESTAMP_TIME_ZONE.into()))
+        }
+        ExportColumnKind::Identifiers => {
+            DataType::List(Arc::new(Field::new_list_field(DataType::UInt32, true)))
+        }
+    }
+}
+
+fn record_batch<R: ExportRecord>(rows: Vec<Vec<ExportValue>>) -> HackerNewsResult<RecordBatch> {
+    let mut builders: Vec<Box<dyn ArrayBuilder>> = R::columns()
+        .iter()
+        .map(|column| builder(column.kind, rows.len()))
+        .collect();
+
+    for row in rows {
+        for (builder, value) in builders.iter_mut().zip(row) {
+            append(builder.as_mut(), value);
+        }
+    }
+
+    let columns: Vec<ArrayRef> = builders
+        .iter_mut()
+        .map(|builder| builder.finish())
+        .collect();
+
+    RecordBatch::try_new(schema::<R>(), columns).map_err(export_error)
+}
+
+fn builder(kind: ExportColumnKind, capacity: usize) -> Box<dyn ArrayBuilder> {
+    match kind {
+        ExportColumnKind::Integer => Box::new(Int64Builder::with_capacity(capacity)),
+        ExportColumnKind::Boolean => Box::new(BooleanBuilder::with_capacity(capacity)),
+        ExportColumnKind::Text => Box::new(StringBuilder::new()),
+        ExportColumnKind::Timestamp => Box::new(
+            TimestampSecondBuilder::with_capacity(capacity).with_timezone(TIMESTAMP_TIME_ZONE),
+        ),
+        ExportColumnKind::Identifiers => Box::new(ListBuilder::new(UInt32Builder::new())),
+    }
+}
+
+/// Appends the value to the column's builder. Values not matching the column's kind are appended as nulls.
+fn append(builder: &mut dyn ArrayBuilder, value: ExportValue) {
+    let any = builder.as_any_mut();
+
+    if let Some(builder) = any.downcast_mut::<Int64Builder>() {
+        match value {
+            ExportValue::Integer(value) => builder.append_value(value),
+            _ => builder.append_null(),
+        }
+    } else if let Some(builder) = any.downcast_mut::<BooleanBuilder>() {
+        match value {
+            ExportValue::Boolean(value) => builder.append_value(value),
+            _ => builder.append_null(),
+        }
+    } else if let Some(builder) = any.downcast_mut::<StringBuilder>() {
+        match value {
+            ExportValue::Text(value) => builder.append_value(value),
+            _ => builder.append_null(),
+        }
+    } else if let Some(builder) = any.downcast_mut::<TimestampSecondBuilder>() {
+        match value {
+            ExportValue::Timestamp(value) => builder.append_value(value.unix_timestamp()),
+            _ => builder.append_null(),
+        }
+    } else if let Some(builder) = any.downcast_mut::<ListBuilder<UInt32Builder>>() {
+        match value {
+            ExportValue::Identifiers(ids) => {
+                builder.values().append_slice(&ids);
+                builder.append(true);
+            }
+            _ => builder.append_null(),
+        }
+    }
+}
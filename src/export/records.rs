@@ -0,0 +1,129 @@
+//! Column layouts for items, typed items, and users.
+
+use crate::{
+    export::{ExportColumn, ExportColumnKind, ExportRecord, ExportValue},
+    items::{
+        comments::HackerNewsComment, jobs::HackerNewsJob, poll_options::HackerNewsPollOption,
+        polls::HackerNewsPoll, stories::HackerNewsStory, HackerNewsItem,
+    },
+    users::HackerNewsUser,
+};
+
+/// Columns shared by items and all typed items, which export as their generic item representation.
+const ITEM_COLUMNS: &[ExportColumn] = &[
+    ExportColumn::new("id", ExportColumnKind::Integer),
+    ExportColumn::new("type", ExportColumnKind::Text),
+    ExportColumn::new("by", ExportColumnKind::Text),
+    ExportColumn::new("time", ExportColumnKind::Timestamp),
+    ExportColumn::new("title", ExportColumnKind::Text),
+    ExportColumn::new("url", ExportColumnKind::Text),
+    ExportColumn::new("text", ExportColumnKind::Text),
+    ExportColumn::new("score", ExportColumnKind::Integer),
+    ExportColumn::new("descendants", ExportColumnKind::Integer),
+    ExportColumn::new("parent", ExportColumnKind::Integer),
+    ExportColumn::new("poll", ExportColumnKind::Integer),
+    ExportColumn::new("kids", ExportColumnKind::Identifiers),
+    ExportColumn::new("parts", ExportColumnKind::Identifiers),
+    ExportColumn::new("deleted", ExportColumnKind::Boolean),
+    ExportColumn::new("dead", ExportColumnKind::Boolean),
+];
+
+const USER_COLUMNS: &[ExportColumn] = &[
+    ExportColumn::new("id", ExportColumnKind::Text),
+    ExportColumn::new("created", ExportColumnKind::Timestamp),
+    ExportColumn::new("karma", ExportColumnKind::Integer),
+    ExportColumn::new("about", ExportColumnKind::Text),
+    ExportColumn::new("submitted", ExportColumnKind::Identifiers),
+];
+
+impl ExportRecord for HackerNewsItem {
+    fn columns() -> &'static [ExportColumn] {
+        ITEM_COLUMNS
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        vec![
+            ExportValue::integer(self.id),
+            ExportValue::optional(self.response_type.clone(), ExportValue::Text),
+            ExportValue::optional(self.by.clone(), ExportValue::Text),
+            ExportValue::Timestamp(self.created_at),
+            ExportValue::optional(self.title.clone(), ExportValue::Text),
+            ExportValue::optional(self.url.clone(), ExportValue::Text),
+            ExportValue::optional(self.text.clone(), ExportValue::Text),
+            ExportValue::optional(self.score, ExportValue::integer),
+            ExportValue::optional(self.descendants, ExportValue::integer),
+            ExportValue::optional(self.parent, ExportValue::integer),
+            ExportValue::optional(self.poll, ExportValue::integer),
+            ExportValue::optional(self.kids.clone(), ExportValue::Identifiers),
+            ExportValue::optional(self.parts.clone(), ExportValue::Identifiers),
+            ExportValue::Boolean(self.deleted.unwrap_or_default()),
+            ExportValue::Boolean(self.dead.unwrap_or_default()),
+        ]
+    }
+}
+
+impl ExportRecord for HackerNewsStory {
+    fn columns() -> &'static [ExportColumn] {
+        ITEM_COLUMNS
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        HackerNewsItem::from(self.clone()).values()
+    }
+}
+
+impl ExportRecord for HackerNewsComment {
+    fn columns() -> &'static [ExportColumn] {
+        ITEM_COLUMNS
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        HackerNewsItem::from(self.clone()).values()
+    }
+}
+
+impl ExportRecord for HackerNewsJob {
+    fn columns() -> &'static [ExportColumn] {
+        ITEM_COLUMNS
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        HackerNewsItem::from(self.clone()).values()
+    }
+}
+
+impl ExportRecord for HackerNewsPoll {
+    fn columns() -> &'static [ExportColumn] {
+        ITEM_COLUMNS
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        HackerNewsItem::from(self.clone()).values()
+    }
+}
+
+impl ExportRecord for HackerNewsPollOption {
+    fn columns() -> &'static [ExportColumn] {
+        ITEM_COLUMNS
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        HackerNewsItem::from(self.clone()).values()
+    }
+}
+
+impl ExportRecord for HackerNewsUser {
+    fn columns() -> &'static [ExportColumn] {
+        USER_COLUMNS
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        vec![
+            ExportValue::Text(self.id.clone()),
+            ExportValue::Timestamp(self.created),
+            ExportValue::integer(self.karma),
+            ExportValue::optional(self.about.clone(), ExportValue::Text),
+            ExportValue::optional(self.stories.clone(), ExportValue::Identifiers),
+        ]
+    }
+}
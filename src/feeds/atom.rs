@@ -0,0 +1,63 @@
+//! Atom rendering, identifying entries by their Hacker News permalink.
+
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{feeds::HackerNewsFeed, text::escape_html};
+
+pub(super) fn render(feed: &HackerNewsFeed) -> String {
+    let mut output = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <feed xmlns=\"http://www.w3.org/2005/Atom\">\n",
+    );
+
+    output.push_str(&format!("<title>{}</title>\n", escape_html(&feed.title)));
+    output.push_str(&format!(
+        "<subtitle>{}</subtitle>\n",
+        escape_html(&feed.description)
+    ));
+    output.push_str(&format!("<id>{}</id>\n", escape_html(&feed.link)));
+    output.push_str(&format!("<link href=\"{}\"/>\n", escape_html(&feed.link)));
+    output.push_str(&format!("<updated>{}</updated>\n", date(feed.updated())));
+
+    for entry in feed.filtered_entries() {
+        output.push_str("<entry>\n");
+        output.push_str(&format!("<title>{}</title>\n", escape_html(&entry.title)));
+        output.push_str(&format!("<id>{}</id>\n", escape_html(&entry.comments)));
+        output.push_str(&format!(
+            "<link rel=\"alternate\" href=\"{}\"/>\n",
+            escape_html(&entry.link)
+        ));
+        output.push_str(&format!(
+            "<link rel=\"replies\" type=\"text/html\" href=\"{}\"/>\n",
+            escape_html(&entry.comments)
+        ));
+        if !entry.author.is_empty() {
+            output.push_str(&format!(
+                "<author><name>{}</name></author>\n",
+                escape_html(&entry.author)
+            ));
+        }
+        output.push_str(&format!(
+            "<published>{}</published>\n",
+            date(entry.published)
+        ));
+        output.push_str(&format!("<updated>{}</updated>\n", date(entry.published)));
+
+        if let Some(kind) = entry.kind {
+            output.push_str(&format!("<category term=\"{}\"/>\n", kind));
+        }
+
+        output.push_str(&format!(
+            "<summary type=\"html\">{}</summary>\n",
+            escape_html(&entry.summary())
+        ));
+        output.push_str("</entry>\n");
+    }
+
+    output.push_str("</feed>\n");
+    output
+}
+
+fn date(date: OffsetDateTime) -> String {
+    date.format(&Rfc3339).unwrap_or_default()
+}
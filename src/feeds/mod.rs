@@ -0,0 +1,298 @@
+//! RSS 2.0 and Atom feed generation from the realtime lists or any set of stories, with filters for publishing
+//! curated Hacker News feeds.
+//!
+//! ```no_run
+//! use newswrap::{
+//!     client::HackerNewsClient, feeds::HackerNewsFeed, items::stories::StoryKind,
+//!     realtime::HackerNewsStoryList,
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!     let feed = HackerNewsFeed::new("Popular Show HN")
+//!         .min_score(100)
+//!         .kinds([StoryKind::Show])
+//!         .load_list(&client, HackerNewsStoryList::Best)
+//!         .await?;
+//!
+//!     println!("{}", feed.to_rss());
+//!     Ok(())
+//! }
+//! ```
+
+mod atom;
+mod rss;
+
+use time::OffsetDateTime;
+
+use crate::{
+    client::HackerNewsClient,
+    errors::HackerNewsResult,
+    items::{jobs::HackerNewsJob, stories::HackerNewsStory, stories::StoryKind, HackerNewsItem},
+    realtime::HackerNewsStoryList,
+    text,
+    urls::HACKER_NEWS_WEB_URL,
+    HackerNewsID,
+};
+
+/// Multiple of the limit fetched per page from realtime lists when filters are configured, so filtered out
+/// entries rarely need another page.
+const FILTER_HEADROOM: usize = 4;
+
+/// A single story or job within a feed.
+#[derive(Debug, Clone, PartialEq)]
+struct FeedEntry {
+    title: String,
+    link: String,
+    comments: String,
+    author: String,
+    score: u32,
+    descendants: u32,
+    published: OffsetDateTime,
+    kind: Option<StoryKind>,
+    text: Option<String>,
+}
+
+impl FeedEntry {
+    fn from_story(story: &HackerNewsStory, kind: StoryKind) -> Self {
+        let comments = story.permalink();
+
+        Self {
+            title: story.title_plain(),
+            link: story
+                .parsed_url()
+                .map(String::from)
+                .unwrap_or_else(|| comments.clone()),
+            comments,
+            author: story.by.clone(),
+            score: story.score,
+            descendants: story.number_of_comments,
+            published: story.created_at,
+            kind: Some(kind),
+            text: Some(story.text_sanitized().into_string()).filter(|text| !text.is_empty()),
+        }
+    }
+
+    fn from_job(job: &HackerNewsJob) -> Self {
+        let comments = job.permalink();
+
+        Self {
+            title: job.title_plain(),
+            link: job
+                .parsed_url()
+                .map(String::from)
+                .unwrap_or_else(|| comments.clone()),
+            comments,
+            author: job.by.clone(),
+            score: job.score,
+            descendants: 0,
+            published: job.created_at,
+            kind: None,
+            text: Some(job.text_sanitized().into_string()).filter(|text| !text.is_empty()),
+        }
+    }
+
+    /// Renders the entry's score, author, comment link and text as HTML for feed descriptions and summaries.
+    fn summary(&self) -> String {
+        let mut summary = format!(
+            "<p>{} points by {} | <a href=\"{}\">{} comments</a></p>",
+            self.score,
+            text::escape_html(&self.author),
+            text::escape_html(&self.comments),
+            self.descendants
+        );
+
+        if let Some(text) = &self.text {
+            summary.push_str(text);
+        }
+
+        summary
+    }
+}
+
+/// A feed of stories and jobs, rendered as RSS 2.0 or Atom. Filters are applied when rendering,
+/// keeping entries in the order they were added.
+#[derive(Debug, Clone)]
+pub struct HackerNewsFeed {
+    title: String,
+    link: String,
+    description: String,
+    min_score: u32,
+    min_comments: u32,
+    kinds: Option<Vec<StoryKind>>,
+    limit: Option<usize>,
+    entries: Vec<FeedEntry>,
+}
+
+impl HackerNewsFeed {
+    /// Constructs a new, empty feed linking to the Hacker News homepage.
+    pub fn new(title: impl Into<String>) -> Self {
+        let title = title.into();
+
+        Self {
+            description: title.clone(),
+            title,
+            link: HACKER_NEWS_WEB_URL.to_string(),
+            min_score: 0,
+            min_comments: 0,
+            kinds: None,
+            limit: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Configures the link to the feed's website, also used as the Atom feed's ID.
+    pub fn link(mut self, link: impl Into<String>) -> Self {
+        self.link = link.into();
+        self
+    }
+
+    /// Configures the feed's description, defaulting to its title.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Excludes entries scoring below the minimum.
+    pub fn min_score(mut self, min_score: u32) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Excludes entries with fewer comments than the minimum.
+    pub fn min_comments(mut self, min_comments: u32) -> Self {
+        self.min_comments = min_comments;
+        self
+    }
+
+    /// Includes only stories of the given kinds, excluding jobs.
+    pub fn kinds(mut self, kinds: impl IntoIterator<Item = StoryKind>) -> Self {
+        self.kinds = Some(kinds.into_iter().collect());
+        self
+    }
+
+    /// Limits the number of entries rendered after filtering.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Adds stories to the feed, classifying them by their title prefix and URL.
+    pub fn with_stories<'a>(
+        mut self,
+        stories: impl IntoIterator<Item = &'a HackerNewsStory>,
+    ) -> Self {
+        self.entries.extend(
+            stories
+                .into_iter()
+                .map(|story| FeedEntry::from_story(story, story.kind())),
+        );
+        self
+    }
+
+    /// Adds jobs to the feed.
+    pub fn with_jobs<'a>(mut self, jobs: impl IntoIterator<Item = &'a HackerNewsJob>) -> Self {
+        self.entries
+            .extend(jobs.into_iter().map(FeedEntry::from_job));
+        self
+    }
+
+    /// Adds the stories and jobs of a realtime list to the feed, in ranked order. Stories in the Ask HN and
+    /// Show HN lists are classified by their listing, and items that are neither stories nor jobs are skipped.
+    /// Configure the limit and filters first, as a limited list is fetched a page at a time until the limit
+    /// is filled or the list runs out, and items missing from the API are skipped.
+    pub async fn load_list(
+        mut self,
+        client: &HackerNewsClient,
+        list: HackerNewsStoryList,
+    ) -> HackerNewsResult<Self> {
+        let ids = client.realtime.get_story_list(list).await?;
+        let (ask_stories, show_stories) = match list {
+            HackerNewsStoryList::Ask => (ids.as_slice(), [].as_slice()),
+            HackerNewsStoryList::Show => ([].as_slice(), ids.as_slice()),
+            _ => ([].as_slice(), [].as_slice()),
+        };
+
+        let mut fetched = 0;
+
+        while fetched < ids.len() && !self.is_full() {
+            let page = &ids[fetched..fetched + self.fetch_limit(ids.len() - fetched)];
+            let items = client.items.get_existing_items(page).await?;
+            fetched += page.len();
+
+            for item in items {
+                if let Some(entry) = item_entry(item, ask_stories, show_stories) {
+                    self.entries.push(entry);
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Renders the feed as an RSS 2.0 document.
+    pub fn to_rss(&self) -> String {
+        rss::render(self)
+    }
+
+    /// Renders the feed as an Atom document.
+    pub fn to_atom(&self) -> String {
+        atom::render(self)
+    }
+
+    /// The number of list items worth fetching, leaving headroom for entries dropped by the filters.
+    fn fetch_limit(&self, available: usize) -> usize {
+        let filtered = self.min_score > 0 || self.min_comments > 0 || self.kinds.is_some();
+
+        match self.limit {
+            Some(limit) if filtered => limit.saturating_mul(FILTER_HEADROOM).min(available),
+            Some(limit) => limit.min(available),
+            None => available,
+        }
+    }
+
+    /// Whether the feed already renders as many entries as its limit allows.
+    fn is_full(&self) -> bool {
+        self.limit
+            .is_some_and(|limit| self.filtered_entries().count() >= limit)
+    }
+
+    /// The entries remaining after filters and limits are applied.
+    fn filtered_entries(&self) -> impl Iterator<Item = &FeedEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.score >= self.min_score)
+            .filter(|entry| entry.descendants >= self.min_comments)
+            .filter(|entry| match (&self.kinds, entry.kind) {
+                (None, _) => true,
+                (Some(kinds), Some(kind)) => kinds.contains(&kind),
+                (Some(_), None) => false,
+            })
+            .take(self.limit.unwrap_or(usize::MAX))
+    }
+
+    /// The most recent publication date among rendered entries, or the current time for empty feeds.
+    fn updated(&self) -> OffsetDateTime {
+        self.filtered_entries()
+            .map(|entry| entry.published)
+            .max()
+            .unwrap_or_else(OffsetDateTime::now_utc)
+    }
+}
+
+fn item_entry(
+    item: HackerNewsItem,
+    ask_stories: &[HackerNewsID],
+    show_stories: &[HackerNewsID],
+) -> Option<FeedEntry> {
+    if item.is_job() {
+        let job = HackerNewsJob::try_from(item).ok()?;
+        return Some(FeedEntry::from_job(&job));
+    }
+
+    let story = HackerNewsStory::try_from(item).ok()?;
+    let kind = story.kind_with_listings(ask_stories, show_stories);
+
+    Some(FeedEntry::from_story(&story, kind))
+}
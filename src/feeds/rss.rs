@@ -0,0 +1,62 @@
+//! RSS 2.0 rendering, using the Dublin Core `creator` element for authors as RSS expects email addresses.
+
+use time::{format_description::well_known::Rfc2822, OffsetDateTime};
+
+use crate::{feeds::HackerNewsFeed, text::escape_html};
+
+pub(super) fn render(feed: &HackerNewsFeed) -> String {
+    let mut output = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <rss version=\"2.0\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n\
+         <channel>\n",
+    );
+
+    output.push_str(&format!("<title>{}</title>\n", escape_html(&feed.title)));
+    output.push_str(&format!("<link>{}</link>\n", escape_html(&feed.link)));
+    output.push_str(&format!(
+        "<description>{}</description>\n",
+        escape_html(&feed.description)
+    ));
+    output.push_str(&format!(
+        "<lastBuildDate>{}</lastBuildDate>\n",
+        date(feed.updated())
+    ));
+
+    for entry in feed.filtered_entries() {
+        output.push_str("<item>\n");
+        output.push_str(&format!("<title>{}</title>\n", escape_html(&entry.title)));
+        output.push_str(&format!("<link>{}</link>\n", escape_html(&entry.link)));
+        output.push_str(&format!(
+            "<comments>{}</comments>\n",
+            escape_html(&entry.comments)
+        ));
+        output.push_str(&format!(
+            "<guid isPermaLink=\"true\">{}</guid>\n",
+            escape_html(&entry.comments)
+        ));
+        if !entry.author.is_empty() {
+            output.push_str(&format!(
+                "<dc:creator>{}</dc:creator>\n",
+                escape_html(&entry.author)
+            ));
+        }
+        output.push_str(&format!("<pubDate>{}</pubDate>\n", date(entry.published)));
+
+        if let Some(kind) = entry.kind {
+            output.push_str(&format!("<category>{}</category>\n", kind));
+        }
+
+        output.push_str(&format!(
+            "<description>{}</description>\n",
+            escape_html(&entry.summary())
+        ));
+        output.push_str("</item>\n");
+    }
+
+    output.push_str("</channel>\n</rss>\n");
+    output
+}
+
+fn date(date: OffsetDateTime) -> String {
+    date.format(&Rfc2822).unwrap_or_default()
+}
@@ -0,0 +1,139 @@
+//! Data loaders batching and deduplicating item and user requests made while resolving a query.
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::{
+    async_trait::async_trait,
+    dataloader::{DataLoader, HashMapCache, Loader},
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest},
+    Request, ServerResult,
+};
+use futures::{stream, StreamExt};
+
+use crate::{
+    client::HackerNewsClient, errors::HackerNewsClientError, items::HackerNewsItem,
+    users::HackerNewsUser, HackerNewsID,
+};
+
+/// Number of users retrieved concurrently within a batch.
+const CONCURRENT_USER_REQUESTS: usize = 10;
+
+/// Item loader caching items for the duration of a request.
+pub(super) type ItemDataLoader = DataLoader<ItemLoader, HashMapCache>;
+
+/// User loader caching users for the duration of a request.
+pub(super) type UserDataLoader = DataLoader<UserLoader, HashMapCache>;
+
+/// Loads items requested while resolving a query in a single concurrent batch, omitting missing items.
+#[derive(Debug)]
+pub(super) struct ItemLoader {
+    client: HackerNewsClient,
+}
+
+impl ItemLoader {
+    fn new(client: HackerNewsClient) -> Self {
+        Self { client }
+    }
+}
+
+impl Loader<HackerNewsID> for ItemLoader {
+    type Value = HackerNewsItem;
+    type Error = Arc<HackerNewsClientError>;
+
+    async fn load(
+        &self,
+        keys: &[HackerNewsID],
+    ) -> Result<HashMap<HackerNewsID, Self::Value>, Self::Error> {
+        let items = self.client.items.get_existing_items(keys).await?;
+        Ok(items.into_iter().map(|item| (item.id, item)).collect())
+    }
+}
+
+/// Loads users requested while resolving a query in a single concurrent batch, omitting missing users.
+#[derive(Debug)]
+pub(super) struct UserLoader {
+    client: HackerNewsClient,
+}
+
+impl UserLoader {
+    fn new(client: HackerNewsClient) -> Self {
+        Self { client }
+    }
+}
+
+impl Loader<String> for UserLoader {
+    type Value = HackerNewsUser;
+    type Error = Arc<HackerNewsClientError>;
+
+    async fn load(&self, keys: &[String]) -> Result<HashMap<String, Self::Value>, Self::Error> {
+        let results: Vec<_> = stream::iter(keys.iter().cloned())
+            .map(|username| async move { self.client.users.get_user(&username).await })
+            .buffered(CONCURRENT_USER_REQUESTS)
+            .collect()
+            .await;
+
+        let mut users = HashMap::with_capacity(keys.len());
+
+        for (username, result) in keys.iter().zip(results) {
+            match result {
+                Ok(user) => {
+                    users.insert(username.clone(), user);
+                }
+                Err(HackerNewsClientError::ItemOrUserNotFound(_)) => {}
+                Err(error) => return Err(Arc::new(error)),
+            }
+        }
+
+        Ok(users)
+    }
+}
+
+/// Provides each request with its own item and user loaders, caching items and users for the duration of the
+/// request so related items and users referenced throughout a query are retrieved once, without serving stale data
+/// to later requests.
+#[derive(Debug)]
+pub(super) struct RequestLoaders {
+    client: HackerNewsClient,
+}
+
+impl RequestLoaders {
+    pub(super) fn new(client: HackerNewsClient) -> Self {
+        Self { client }
+    }
+}
+
+impl ExtensionFactory for RequestLoaders {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(RequestLoadersExtension {
+            client: self.client.clone(),
+        })
+    }
+}
+
+struct RequestLoadersExtension {
+    client: HackerNewsClient,
+}
+
+#[async_trait]
+impl Extension for RequestLoadersExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let item_loader: ItemDataLoader = DataLoader::with_cache(
+            ItemLoader::new(self.client.clone()),
+            tokio::spawn,
+            HashMapCache::default(),
+        );
+        let user_loader: UserDataLoader = DataLoader::with_cache(
+            UserLoader::new(self.client.clone()),
+            tokio::spawn,
+            HashMapCache::default(),
+        );
+
+        next.run(ctx, request.data(item_loader).data(user_loader))
+            .await
+    }
+}
@@ -0,0 +1,144 @@
+//! A GraphQL schema over the Hacker News data model, available behind the `graphql` feature. Items and users resolve
+//! their related items and users on demand, e.g. stories to their comments, comments to their authors and authors to
+//! their submissions, with requests made while resolving a query batched and deduplicated by data loaders to avoid
+//! fetching the same item or user more than once.
+//!
+//! ```no_run
+//! use newswrap::{client::HackerNewsClient, graphql};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let schema = graphql::schema(HackerNewsClient::new());
+//!     let response = schema
+//!         .execute("{ stories(list: TOP, first: 5) { title author { karma } } }")
+//!         .await;
+//!
+//!     println!("{}", serde_json::to_string_pretty(&response).unwrap());
+//! }
+//! ```
+
+mod loaders;
+mod types;
+
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Result, Schema};
+
+use crate::{client::HackerNewsClient, HackerNewsID};
+
+use loaders::RequestLoaders;
+use types::{list_complexity, load_item, load_items, load_typed_item, load_user, load_users};
+
+pub use types::{Comment, Item, ItemType, Job, Poll, PollOption, Story, StoryList, User};
+
+/// Default number of items resolved from lists such as the realtime lists and comments, matching the size of the
+/// front page.
+const DEFAULT_LIST_SIZE: usize = 30;
+
+/// Maximum depth of queries, allowing threads to be resolved several replies deep.
+const MAX_QUERY_DEPTH: usize = 16;
+
+/// Maximum complexity of queries, with fields counted once for each item of the lists they're nested within. Allows
+/// threads to be resolved three levels of replies deep at the default list size.
+const MAX_QUERY_COMPLEXITY: usize = 200_000;
+
+/// The schema type exposed by [`schema`], for passing to GraphQL server integrations.
+pub type HackerNewsSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Builds the schema, resolving queries with the client. Queries nested too deeply or requesting too many items are
+/// rejected before any requests are made.
+pub fn schema(client: HackerNewsClient) -> HackerNewsSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .extension(RequestLoaders::new(client.clone()))
+        .data(client)
+        .finish()
+}
+
+/// The root of all queries, looking up items, users and the realtime lists.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Looks up any item by its ID.
+    async fn item(&self, ctx: &Context<'_>, id: HackerNewsID) -> Result<Option<Item>> {
+        Ok(load_item(ctx, id).await?.map(Item))
+    }
+
+    /// Looks up a story by its ID, erroring if the item is not a story.
+    async fn story(&self, ctx: &Context<'_>, id: HackerNewsID) -> Result<Option<Story>> {
+        Ok(load_typed_item(ctx, id).await?.map(Story))
+    }
+
+    /// Looks up a comment by its ID, erroring if the item is not a comment.
+    async fn comment(&self, ctx: &Context<'_>, id: HackerNewsID) -> Result<Option<Comment>> {
+        Ok(load_typed_item(ctx, id).await?.map(Comment))
+    }
+
+    /// Looks up a job by its ID, erroring if the item is not a job.
+    async fn job(&self, ctx: &Context<'_>, id: HackerNewsID) -> Result<Option<Job>> {
+        Ok(load_typed_item(ctx, id).await?.map(Job))
+    }
+
+    /// Looks up a poll by its ID, erroring if the item is not a poll.
+    async fn poll(&self, ctx: &Context<'_>, id: HackerNewsID) -> Result<Option<Poll>> {
+        Ok(load_typed_item(ctx, id).await?.map(Poll))
+    }
+
+    /// Looks up a poll option by its ID, erroring if the item is not a poll option.
+    async fn poll_option(&self, ctx: &Context<'_>, id: HackerNewsID) -> Result<Option<PollOption>> {
+        Ok(load_typed_item(ctx, id).await?.map(PollOption))
+    }
+
+    /// Looks up a user by their username.
+    async fn user(&self, ctx: &Context<'_>, username: String) -> Result<Option<User>> {
+        Ok(load_user(ctx, &username).await?.map(User))
+    }
+
+    /// The IDs of the items within one of the realtime lists.
+    async fn story_ids(&self, ctx: &Context<'_>, list: StoryList) -> Result<Vec<HackerNewsID>> {
+        let client = ctx.data::<HackerNewsClient>()?;
+        Ok(client.realtime.get_story_list(list.into()).await?)
+    }
+
+    /// The items within one of the realtime lists, defaulting to the first 30.
+    #[graphql(complexity = "list_complexity(first, child_complexity)")]
+    async fn stories(
+        &self,
+        ctx: &Context<'_>,
+        list: StoryList,
+        first: Option<usize>,
+    ) -> Result<Vec<Item>> {
+        let client = ctx.data::<HackerNewsClient>()?;
+        let mut ids = client.realtime.get_story_list(list.into()).await?;
+        ids.truncate(first.unwrap_or(DEFAULT_LIST_SIZE));
+
+        Ok(load_items(ctx, &ids).await?.into_iter().map(Item).collect())
+    }
+
+    /// The ID of the latest item.
+    async fn max_item(&self, ctx: &Context<'_>) -> Result<HackerNewsID> {
+        let client = ctx.data::<HackerNewsClient>()?;
+        Ok(client.realtime.get_latest_item_id().await?)
+    }
+
+    /// The most recently updated items.
+    async fn updated_items(&self, ctx: &Context<'_>) -> Result<Vec<Item>> {
+        let client = ctx.data::<HackerNewsClient>()?;
+        let ids = client.realtime.get_recently_updated_items().await?;
+
+        Ok(load_items(ctx, &ids).await?.into_iter().map(Item).collect())
+    }
+
+    /// The most recently updated users.
+    async fn updated_users(&self, ctx: &Context<'_>) -> Result<Vec<User>> {
+        let client = ctx.data::<HackerNewsClient>()?;
+        let usernames = client.realtime.get_recently_updated_profiles().await?;
+
+        Ok(load_users(ctx, &usernames)
+            .await?
+            .into_iter()
+            .map(User)
+            .collect())
+    }
+}
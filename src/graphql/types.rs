@@ -0,0 +1,547 @@
+//! GraphQL objects wrapping the item and user models, resolving related items and users through the data loaders.
+
+use async_graphql::{Context, Enum, Object, Result};
+
+use crate::{
+    errors::HackerNewsClientError,
+    items::{
+        comments::HackerNewsComment, jobs::HackerNewsJob, poll_options::HackerNewsPollOption,
+        polls::HackerNewsPoll, stories::HackerNewsStory, HackerNewsItem,
+    },
+    users::HackerNewsUser,
+    HackerNewsID,
+};
+
+use super::{
+    loaders::{ItemDataLoader, UserDataLoader},
+    DEFAULT_LIST_SIZE, MAX_QUERY_COMPLEXITY,
+};
+
+/// The type of an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+#[graphql(remote = "crate::items::HackerNewsItemType")]
+pub enum ItemType {
+    /// Comments on stories, polls and other comments.
+    Comment,
+    /// Job listings.
+    Job,
+    /// Story polls.
+    Poll,
+    /// Choices on a poll.
+    PollOption,
+    /// Homepage story posts.
+    Story,
+    /// Items of an unrecognized type.
+    Unknown,
+}
+
+/// The realtime story lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+#[graphql(remote = "crate::realtime::HackerNewsStoryList")]
+pub enum StoryList {
+    /// The current top stories and jobs.
+    Top,
+    /// The newest stories.
+    New,
+    /// The best stories.
+    Best,
+    /// The latest Ask HN stories.
+    Ask,
+    /// The latest Show HN stories.
+    Show,
+    /// The latest job stories.
+    Jobs,
+}
+
+/// Loads the item, returning `None` if it does not exist.
+pub(super) async fn load_item(
+    ctx: &Context<'_>,
+    id: HackerNewsID,
+) -> Result<Option<HackerNewsItem>> {
+    Ok(ctx.data::<ItemDataLoader>()?.load_one(id).await?)
+}
+
+/// Loads the items in a single batch, preserving their order and omitting those that do not exist.
+pub(super) async fn load_items(
+    ctx: &Context<'_>,
+    ids: &[HackerNewsID],
+) -> Result<Vec<HackerNewsItem>> {
+    let mut items = ctx
+        .data::<ItemDataLoader>()?
+        .load_many(ids.iter().copied())
+        .await?;
+
+    Ok(ids.iter().filter_map(|id| items.remove(id)).collect())
+}
+
+/// Loads the user, returning `None` if they do not exist.
+pub(super) async fn load_user(ctx: &Context<'_>, username: &str) -> Result<Option<HackerNewsUser>> {
+    if username.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(ctx
+        .data::<UserDataLoader>()?
+        .load_one(username.to_string())
+        .await?)
+}
+
+/// Loads the users in a single batch, preserving their order and omitting those that do not exist.
+pub(super) async fn load_users(
+    ctx: &Context<'_>,
+    usernames: &[String],
+) -> Result<Vec<HackerNewsUser>> {
+    let mut users = ctx
+        .data::<UserDataLoader>()?
+        .load_many(usernames.iter().cloned())
+        .await?;
+
+    Ok(usernames
+        .iter()
+        .filter_map(|username| users.remove(username))
+        .collect())
+}
+
+/// The complexity of resolving the first items of a list, counting the fields selected on each item. Capped just
+/// past the maximum complexity, so sums of deeply nested lists can't overflow.
+pub(super) fn list_complexity(first: Option<usize>, child_complexity: usize) -> usize {
+    first
+        .unwrap_or(DEFAULT_LIST_SIZE)
+        .saturating_mul(child_complexity)
+        .min(MAX_QUERY_COMPLEXITY + 1)
+}
+
+/// Takes the first IDs, defaulting to the first 30.
+fn take_first(ids: &[HackerNewsID], first: Option<usize>) -> &[HackerNewsID] {
+    &ids[..first.unwrap_or(DEFAULT_LIST_SIZE).min(ids.len())]
+}
+
+/// Loads the items converted to the typed item, omitting those of other types.
+async fn load_typed<T: TryFrom<HackerNewsItem>>(
+    ctx: &Context<'_>,
+    ids: &[HackerNewsID],
+    first: Option<usize>,
+) -> Result<Vec<T>> {
+    let items = load_items(ctx, take_first(ids, first)).await?;
+
+    Ok(items
+        .into_iter()
+        .filter_map(|item| T::try_from(item).ok())
+        .collect())
+}
+
+/// Any Hacker News item, exposing the fields of every item type.
+#[derive(Debug, Clone)]
+pub struct Item(pub(super) HackerNewsItem);
+
+#[Object]
+impl Item {
+    /// The item's unique ID.
+    async fn id(&self) -> HackerNewsID {
+        self.0.id
+    }
+
+    /// The type of the item.
+    #[graphql(name = "type")]
+    async fn item_type(&self) -> ItemType {
+        self.0.get_item_type().into()
+    }
+
+    /// The username of the item's author.
+    async fn by(&self) -> Option<&str> {
+        self.0.by.as_deref()
+    }
+
+    /// The item's author.
+    async fn author(&self, ctx: &Context<'_>) -> Result<Option<User>> {
+        match &self.0.by {
+            Some(by) => Ok(load_user(ctx, by).await?.map(User)),
+            None => Ok(None),
+        }
+    }
+
+    /// Creation date of the item, in Unix Time.
+    async fn time(&self) -> i64 {
+        self.0.created_at.unix_timestamp()
+    }
+
+    /// True if the item is deleted.
+    async fn deleted(&self) -> bool {
+        self.0.deleted.unwrap_or_default()
+    }
+
+    /// True if the item is dead.
+    async fn dead(&self) -> bool {
+        self.0.dead.unwrap_or_default()
+    }
+
+    /// The title of the story, poll or job. HTML.
+    async fn title(&self) -> Option<&str> {
+        self.0.title.as_deref()
+    }
+
+    /// The comment, story or poll text. HTML.
+    async fn text(&self) -> Option<&str> {
+        self.0.text.as_deref()
+    }
+
+    /// The URL of the story.
+    async fn url(&self) -> Option<&str> {
+        self.0.url.as_deref()
+    }
+
+    /// The story's score, or the votes for a poll option.
+    async fn score(&self) -> Option<u32> {
+        self.0.score
+    }
+
+    /// In the case of stories or polls, the total comment count.
+    async fn descendants(&self) -> Option<u32> {
+        self.0.descendants
+    }
+
+    /// The comment's parent: either another comment or the relevant story.
+    async fn parent(&self, ctx: &Context<'_>) -> Result<Option<Item>> {
+        match self.0.parent {
+            Some(parent) => Ok(load_item(ctx, parent).await?.map(Item)),
+            None => Ok(None),
+        }
+    }
+
+    /// The item's comments, in ranked display order, defaulting to the first 30.
+    #[graphql(complexity = "list_complexity(first, child_complexity)")]
+    async fn kids(&self, ctx: &Context<'_>, first: Option<usize>) -> Result<Vec<Item>> {
+        let kids = take_first(self.0.kids.as_deref().unwrap_or_default(), first);
+        Ok(load_items(ctx, kids).await?.into_iter().map(Item).collect())
+    }
+
+    /// The poll option's associated poll.
+    async fn poll(&self, ctx: &Context<'_>) -> Result<Option<Item>> {
+        match self.0.poll {
+            Some(poll) => Ok(load_item(ctx, poll).await?.map(Item)),
+            None => Ok(None),
+        }
+    }
+
+    /// The poll's options, in display order.
+    async fn parts(&self, ctx: &Context<'_>) -> Result<Vec<Item>> {
+        let parts = self.0.parts.as_deref().unwrap_or_default();
+        Ok(load_items(ctx, parts)
+            .await?
+            .into_iter()
+            .map(Item)
+            .collect())
+    }
+
+    /// The Hacker News permalink for the item.
+    async fn permalink(&self) -> String {
+        self.0.permalink()
+    }
+}
+
+/// A homepage story post.
+#[derive(Debug, Clone)]
+pub struct Story(pub(super) HackerNewsStory);
+
+#[Object]
+impl Story {
+    /// The story's unique ID.
+    async fn id(&self) -> HackerNewsID {
+        self.0.id
+    }
+
+    /// The username of the story's author.
+    async fn by(&self) -> &str {
+        &self.0.by
+    }
+
+    /// The story's author.
+    async fn author(&self, ctx: &Context<'_>) -> Result<Option<User>> {
+        Ok(load_user(ctx, &self.0.by).await?.map(User))
+    }
+
+    /// Creation date of the story, in Unix Time.
+    async fn time(&self) -> i64 {
+        self.0.created_at.unix_timestamp()
+    }
+
+    /// The title of the story. HTML.
+    async fn title(&self) -> &str {
+        &self.0.title
+    }
+
+    /// The URL of the story.
+    async fn url(&self) -> &str {
+        &self.0.url
+    }
+
+    /// The story text. HTML.
+    async fn text(&self) -> &str {
+        &self.0.text
+    }
+
+    /// The story's score.
+    async fn score(&self) -> u32 {
+        self.0.score
+    }
+
+    /// The total comment count.
+    async fn comment_count(&self) -> u32 {
+        self.0.number_of_comments
+    }
+
+    /// The story's top level comments, in ranked display order, defaulting to the first 30.
+    #[graphql(complexity = "list_complexity(first, child_complexity)")]
+    async fn comments(&self, ctx: &Context<'_>, first: Option<usize>) -> Result<Vec<Comment>> {
+        let comments = load_typed(ctx, &self.0.comments, first).await?;
+        Ok(comments.into_iter().map(Comment).collect())
+    }
+}
+
+/// A comment on a story, poll or another comment.
+#[derive(Debug, Clone)]
+pub struct Comment(pub(super) HackerNewsComment);
+
+#[Object]
+impl Comment {
+    /// The comment's unique ID.
+    async fn id(&self) -> HackerNewsID {
+        self.0.id
+    }
+
+    /// The username of the comment's author.
+    async fn by(&self) -> &str {
+        &self.0.by
+    }
+
+    /// The comment's author.
+    async fn author(&self, ctx: &Context<'_>) -> Result<Option<User>> {
+        Ok(load_user(ctx, &self.0.by).await?.map(User))
+    }
+
+    /// Creation date of the comment, in Unix Time.
+    async fn time(&self) -> i64 {
+        self.0.created_at.unix_timestamp()
+    }
+
+    /// The comment text. HTML.
+    async fn text(&self) -> &str {
+        &self.0.text
+    }
+
+    /// True if the comment is deleted.
+    async fn deleted(&self) -> bool {
+        self.0.deleted
+    }
+
+    /// True if the comment is dead.
+    async fn dead(&self) -> bool {
+        self.0.dead
+    }
+
+    /// The comment's parent: either another comment or the relevant story.
+    async fn parent(&self, ctx: &Context<'_>) -> Result<Option<Item>> {
+        Ok(load_item(ctx, self.0.parent_story).await?.map(Item))
+    }
+
+    /// Direct replies to the comment, in ranked display order, defaulting to the first 30.
+    #[graphql(complexity = "list_complexity(first, child_complexity)")]
+    async fn replies(&self, ctx: &Context<'_>, first: Option<usize>) -> Result<Vec<Comment>> {
+        let replies = load_typed(ctx, &self.0.sub_comments, first).await?;
+        Ok(replies.into_iter().map(Comment).collect())
+    }
+}
+
+/// A job listing.
+#[derive(Debug, Clone)]
+pub struct Job(pub(super) HackerNewsJob);
+
+#[Object]
+impl Job {
+    /// The job's unique ID.
+    async fn id(&self) -> HackerNewsID {
+        self.0.id
+    }
+
+    /// The username of the job's author.
+    async fn by(&self) -> &str {
+        &self.0.by
+    }
+
+    /// Creation date of the job, in Unix Time.
+    async fn time(&self) -> i64 {
+        self.0.created_at.unix_timestamp()
+    }
+
+    /// The title of the job. HTML.
+    async fn title(&self) -> &str {
+        &self.0.title
+    }
+
+    /// The URL of the job listing.
+    async fn url(&self) -> &str {
+        &self.0.url
+    }
+
+    /// The job text. HTML.
+    async fn text(&self) -> &str {
+        &self.0.text
+    }
+
+    /// The job's score.
+    async fn score(&self) -> u32 {
+        self.0.score
+    }
+}
+
+/// A story poll.
+#[derive(Debug, Clone)]
+pub struct Poll(pub(super) HackerNewsPoll);
+
+#[Object]
+impl Poll {
+    /// The poll's unique ID.
+    async fn id(&self) -> HackerNewsID {
+        self.0.id
+    }
+
+    /// The username of the poll's author.
+    async fn by(&self) -> &str {
+        &self.0.by
+    }
+
+    /// The poll's author.
+    async fn author(&self, ctx: &Context<'_>) -> Result<Option<User>> {
+        Ok(load_user(ctx, &self.0.by).await?.map(User))
+    }
+
+    /// Creation date of the poll, in Unix Time.
+    async fn time(&self) -> i64 {
+        self.0.created_at.unix_timestamp()
+    }
+
+    /// The title of the poll. HTML.
+    async fn title(&self) -> &str {
+        &self.0.title
+    }
+
+    /// The poll text. HTML.
+    async fn text(&self) -> &str {
+        &self.0.text
+    }
+
+    /// The poll's score.
+    async fn score(&self) -> u32 {
+        self.0.score
+    }
+
+    /// The total comment count.
+    async fn comment_count(&self) -> u32 {
+        self.0.participants
+    }
+
+    /// The poll's options, in display order.
+    async fn options(&self, ctx: &Context<'_>) -> Result<Vec<PollOption>> {
+        let options = load_typed(ctx, &self.0.poll_options, None).await?;
+        Ok(options.into_iter().map(PollOption).collect())
+    }
+
+    /// The poll's top level comments, in ranked display order, defaulting to the first 30.
+    #[graphql(complexity = "list_complexity(first, child_complexity)")]
+    async fn comments(&self, ctx: &Context<'_>, first: Option<usize>) -> Result<Vec<Comment>> {
+        let comments = load_typed(ctx, &self.0.comments, first).await?;
+        Ok(comments.into_iter().map(Comment).collect())
+    }
+}
+
+/// A choice on a poll.
+#[derive(Debug, Clone)]
+pub struct PollOption(pub(super) HackerNewsPollOption);
+
+#[Object]
+impl PollOption {
+    /// The poll option's unique ID.
+    async fn id(&self) -> HackerNewsID {
+        self.0.id
+    }
+
+    /// The username of the poll option's author.
+    async fn by(&self) -> &str {
+        &self.0.by
+    }
+
+    /// Creation date of the poll option, in Unix Time.
+    async fn time(&self) -> i64 {
+        self.0.created_at.unix_timestamp()
+    }
+
+    /// The poll option text. HTML.
+    async fn text(&self) -> &str {
+        &self.0.text
+    }
+
+    /// The votes for the poll option.
+    async fn score(&self) -> u32 {
+        self.0.score
+    }
+
+    /// The poll option's associated poll.
+    async fn poll(&self, ctx: &Context<'_>) -> Result<Option<Poll>> {
+        let polls = load_typed(ctx, &[self.0.poll], None).await?;
+        Ok(polls.into_iter().next().map(Poll))
+    }
+}
+
+/// A Hacker News user.
+#[derive(Debug, Clone)]
+pub struct User(pub(super) HackerNewsUser);
+
+#[Object]
+impl User {
+    /// The user's unique username.
+    async fn id(&self) -> &str {
+        &self.0.id
+    }
+
+    /// Creation date of the user, in Unix Time.
+    async fn created(&self) -> i64 {
+        self.0.created.unix_timestamp()
+    }
+
+    /// The user's karma.
+    async fn karma(&self) -> u32 {
+        self.0.karma
+    }
+
+    /// The user's optional self-description. HTML.
+    async fn about(&self) -> Option<&str> {
+        self.0.about.as_deref()
+    }
+
+    /// The user's stories, polls and comments, newest first, defaulting to the first 30.
+    #[graphql(complexity = "list_complexity(first, child_complexity)")]
+    async fn submissions(&self, ctx: &Context<'_>, first: Option<usize>) -> Result<Vec<Item>> {
+        let submitted = take_first(self.0.stories.as_deref().unwrap_or_default(), first);
+        Ok(load_items(ctx, submitted)
+            .await?
+            .into_iter()
+            .map(Item)
+            .collect())
+    }
+
+    /// The Hacker News profile permalink for the user.
+    async fn permalink(&self) -> String {
+        self.0.permalink()
+    }
+}
+
+/// Maps the typed item's lookup to its GraphQL object, reporting items of other types as errors.
+pub(super) async fn load_typed_item<T: TryFrom<HackerNewsItem, Error = HackerNewsClientError>>(
+    ctx: &Context<'_>,
+    id: HackerNewsID,
+) -> Result<Option<T>> {
+    match load_item(ctx, id).await? {
+        Some(item) => Ok(Some(T::try_from(item)?)),
+        None => Ok(None),
+    }
+}
@@ -1,64 +1,198 @@
-use std::fmt::Display;
+#[cfg(feature = "tower")]
+use std::sync::{Mutex, PoisonError};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use serde::Deserialize;
 
-use crate::errors::HackerNewsResult;
+#[cfg(feature = "tower")]
+use crate::client::HackerNewsTransport;
+use crate::{
+    client::RequestOptions,
+    errors::{HackerNewsClientError, HackerNewsResult},
+    versions::{ApiResource, ApiResponse, ApiVersion},
+};
 
-/// An internal reqwest-based HTTP client for interacting with Hacker News.
+/// Delay before the first retry of a failed request, doubling on each subsequent retry.
+const RETRY_DELAY_MILLIS: u64 = 100;
+
+/// Number of times the retry delay doubles at most, capping the delay between retries.
+const MAX_BACKOFF_DOUBLINGS: u32 = 6;
+
+/// An internal reqwest-based HTTP client for interacting with Hacker News. Clones share the connection pool and
+/// middleware stack, as the HTTP client and transport are reference counted.
 #[derive(Debug, Clone)]
 pub struct InternalHttpClient {
     http: reqwest::Client,
-    base_url: &'static str,
+    base_url: Arc<str>,
+    version: ApiVersion,
+    /// The middleware stack requests are sent through in place of the HTTP client, if configured.
+    #[cfg(feature = "tower")]
+    transport: Option<Arc<Mutex<HackerNewsTransport>>>,
 }
 
 impl InternalHttpClient {
     /// Constructs a new internal client with the base URL of the Hacker News API and the configured HTTP client.
-    pub fn new(http: reqwest::Client, base_url: &'static str) -> Self {
-        Self { http, base_url }
+    pub fn new(http: reqwest::Client, base_url: String) -> Self {
+        Self {
+            http,
+            base_url: base_url.into(),
+            version: ApiVersion::default(),
+            #[cfg(feature = "tower")]
+            transport: None,
+        }
+    }
+
+    /// Builds URLs and parses responses according to the API version, rather than the default version.
+    pub(crate) fn with_version(mut self, version: ApiVersion) -> Self {
+        self.version = version;
+        self
     }
 
-    /// Retrieves an item from Hacker News generic over the endpoint being called.
-    pub async fn get_item<T: for<'de> Deserialize<'de>>(
+    /// Sends requests through the transport rather than directly with the HTTP client.
+    #[cfg(feature = "tower")]
+    pub fn with_transport(mut self, transport: HackerNewsTransport) -> Self {
+        self.transport = Some(Arc::new(Mutex::new(transport)));
+        self
+    }
+
+    /// Retrieves a resource from the Hacker News API, with its URL and parsing determined by the API version.
+    pub(crate) async fn get<T: ApiResponse>(
         &self,
-        endpoint: &str,
+        resource: ApiResource<'_>,
+        options: &RequestOptions,
     ) -> HackerNewsResult<T> {
-        let item_result = self
-            .http
-            .get(format!("{}/{}.json", self.base_url, endpoint))
-            .send()
-            .await;
-
-        match item_result {
-            Ok(item) => {
-                let parsed_item = item.json::<T>().await?;
-                Ok(parsed_item)
-            }
-            Err(_) => Err(crate::errors::HackerNewsClientError::ItemOrUserNotFound(
-                endpoint.to_string(),
-            )),
-        }
+        let name = resource.to_string();
+        let url = self
+            .version
+            .url(&self.base_url, &resource)
+            .ok_or_else(|| HackerNewsClientError::ItemOrUserNotFound(name.clone()))?;
+        let response = self.respond(|| self.http.get(&url), &name, options).await?;
+
+        self.version
+            .parse(response)
+            .await?
+            .ok_or(HackerNewsClientError::ItemOrUserNotFound(name))
     }
 
-    /// Retrieves an item from Hacker News generic over the endpoint being called.
-    pub async fn get_item_with_id<T: for<'de> Deserialize<'de>>(
+    /// Retrieves a resource from APIs without the Firebase `.json` suffix, e.g. the Algolia search API,
+    /// along with the query parameters and request options.
+    pub async fn get_resource<T: for<'de> Deserialize<'de>>(
         &self,
-        endpoint: &str,
-        id: impl Display,
+        path: &str,
+        query: &[(&str, String)],
+        options: &RequestOptions,
     ) -> HackerNewsResult<T> {
-        let item_result = self
-            .http
-            .get(format!("{}/{}/{}.json", self.base_url, endpoint, id))
-            .send()
-            .await;
-
-        match item_result {
-            Ok(item) => {
-                let parsed_item = item.json::<T>().await?;
-                Ok(parsed_item)
+        let url = format!("{}/{}", self.base_url, path);
+        let request = || self.http.get(&url).query(query);
+        let response = self.respond(request, path, options).await?;
+
+        response
+            .json::<Option<T>>()
+            .await?
+            .ok_or_else(|| HackerNewsClientError::ItemOrUserNotFound(path.to_string()))
+    }
+
+    /// Sends the request with the options applied, retrying transient failures as many times as the options allow
+    /// with exponential backoff and jitter between attempts.
+    async fn respond(
+        &self,
+        request: impl Fn() -> reqwest::RequestBuilder,
+        name: &str,
+        options: &RequestOptions,
+    ) -> HackerNewsResult<reqwest::Response> {
+        let mut attempt = 0;
+
+        loop {
+            match self.respond_once(options.apply(request()), name).await {
+                Err(error) if attempt < options.retries && is_transient(&error) => {
+                    tokio::time::sleep(retry_delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
             }
-            Err(_) => Err(crate::errors::HackerNewsClientError::ItemOrUserNotFound(
-                id.to_string(),
-            )),
         }
     }
+
+    /// Sends the request, reporting missing resources by the name given and unsuccessful responses as request errors.
+    /// Failures to reach the API are reported as request errors rather than missing resources.
+    async fn respond_once(
+        &self,
+        request: reqwest::RequestBuilder,
+        name: &str,
+    ) -> HackerNewsResult<reqwest::Response> {
+        let response = self.send(request).await?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(HackerNewsClientError::ItemOrUserNotFound(name.to_string()));
+        }
+
+        Ok(response.error_for_status()?)
+    }
+
+    /// Sends the request through the transport if configured, otherwise directly with the HTTP client.
+    #[cfg(feature = "tower")]
+    async fn send(&self, request: reqwest::RequestBuilder) -> HackerNewsResult<reqwest::Response> {
+        use tower::{Service, ServiceExt};
+
+        let Some(transport) = &self.transport else {
+            return Ok(request.send().await?);
+        };
+
+        let request = request.build()?;
+
+        // Services are cloned for each request, as they are driven to readiness independently
+        let mut transport = transport
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        let response = match transport.ready().await {
+            Ok(service) => service.call(request).await,
+            Err(error) => Err(error),
+        };
+
+        response.map_err(|error| match error.downcast::<reqwest::Error>() {
+            Ok(error) => HackerNewsClientError::RequestError(*error),
+            Err(error) => HackerNewsClientError::TransportError(error),
+        })
+    }
+
+    /// Sends the request directly with the HTTP client.
+    #[cfg(not(feature = "tower"))]
+    async fn send(&self, request: reqwest::RequestBuilder) -> HackerNewsResult<reqwest::Response> {
+        Ok(request.send().await?)
+    }
+}
+
+/// The delay before the retry following the attempt, doubling from the base delay on each attempt with up to half
+/// of the delay added as jitter so clients failing together don't retry in lockstep.
+pub(crate) fn retry_delay(attempt: u32) -> Duration {
+    let delay = Duration::from_millis(RETRY_DELAY_MILLIS)
+        * 2_u32.saturating_pow(attempt.min(MAX_BACKOFF_DOUBLINGS));
+    let jitter_nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| u64::from(now.subsec_nanos()))
+        .unwrap_or_default();
+    let max_jitter_nanos = u64::try_from(delay.as_nanos() / 2).unwrap_or(u64::MAX);
+
+    delay + Duration::from_nanos(jitter_nanos % (max_jitter_nanos + 1))
+}
+
+/// Determines whether the request may succeed if retried, i.e. it timed out, failed to connect, the API responded with
+/// a server error or the transport failed. Missing items and malformed responses are never retried.
+fn is_transient(error: &HackerNewsClientError) -> bool {
+    match error {
+        HackerNewsClientError::RequestError(error) => {
+            error.is_timeout()
+                || error.is_connect()
+                || error
+                    .status()
+                    .is_some_and(|status| status.is_server_error())
+        }
+        HackerNewsClientError::TransportError(_) => true,
+        _ => false,
+    }
 }
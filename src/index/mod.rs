@@ -0,0 +1,298 @@
+//! A local full-text search index over items, available behind the `index` feature. Items are indexed by their
+//! title, text, author and linked domain as they're fetched or loaded from storage, and queried with BM25 ranking
+//! alongside filters on author, domain, type, score and creation time.
+//!
+//! ```no_run
+//! use newswrap::{
+//!     client::HackerNewsClient,
+//!     index::{HackerNewsIndex, SearchQuery},
+//!     items::HackerNewsItemType,
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!     let mut index = HackerNewsIndex::new();
+//!
+//!     let ids = client.realtime.get_top_stories().await?;
+//!     index.fetch(&client, &ids).await?;
+//!
+//!     let query = SearchQuery::new("rust async")
+//!         .item_type(HackerNewsItemType::Story)
+//!         .min_score(50);
+//!
+//!     for hit in index.search(&query) {
+//!         println!("{:.2} {}", hit.relevance, hit.item.title_plain().unwrap_or_default());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod query;
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::HackerNewsClient, errors::HackerNewsResult, items::HackerNewsItem, HackerNewsID,
+};
+
+pub use query::{SearchOrder, SearchQuery};
+
+/// BM25 term frequency saturation.
+const BM25_K1: f64 = 1.2;
+
+/// BM25 document length normalization.
+const BM25_B: f64 = 0.75;
+
+/// Weights applied to term frequencies within each indexed field.
+const TITLE_WEIGHT: f64 = 3.0;
+const AUTHOR_WEIGHT: f64 = 2.0;
+const DOMAIN_WEIGHT: f64 = 1.5;
+const TEXT_WEIGHT: f64 = 1.0;
+
+/// An item matching a search query along with its relevance to the query's text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit<'a> {
+    /// The matching item.
+    pub item: &'a HackerNewsItem,
+    /// The item's BM25 relevance to the query's text, or zero for queries without text.
+    pub relevance: f64,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedItem {
+    item: HackerNewsItem,
+    domain: Option<String>,
+    terms: HashSet<String>,
+    length: f64,
+}
+
+/// An in-memory inverted index of items. Indexes serialize as the list of indexed items, allowing them
+/// to be persisted and loaded from storage.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<HackerNewsItem>", into = "Vec<HackerNewsItem>")]
+pub struct HackerNewsIndex {
+    items: HashMap<HackerNewsID, IndexedItem>,
+    postings: HashMap<String, HashMap<HackerNewsID, f64>>,
+    total_length: f64,
+}
+
+impl HackerNewsIndex {
+    /// Constructs a new, empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts the indexed items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Determines if no items have been indexed.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Retrieves an indexed item by its ID.
+    pub fn get(&self, id: HackerNewsID) -> Option<&HackerNewsItem> {
+        self.items.get(&id).map(|indexed| &indexed.item)
+    }
+
+    /// Iterates all indexed items in no particular order.
+    pub fn items(&self) -> impl Iterator<Item = &HackerNewsItem> {
+        self.items.values().map(|indexed| &indexed.item)
+    }
+
+    /// Indexes the item, replacing any previously indexed version of it.
+    pub fn insert(&mut self, item: HackerNewsItem) {
+        self.remove(item.id);
+
+        let mut frequencies: HashMap<String, f64> = HashMap::new();
+        let domain = item
+            .parsed_url()
+            .and_then(|url| url.host_str().map(str::to_lowercase));
+
+        let fields = [
+            (item.title_plain(), TITLE_WEIGHT),
+            (item.by.clone(), AUTHOR_WEIGHT),
+            (domain.clone(), DOMAIN_WEIGHT),
+            (item.text_plain(), TEXT_WEIGHT),
+        ];
+
+        for (field, weight) in fields {
+            for term in tokenize(field.as_deref().unwrap_or_default()) {
+                *frequencies.entry(term).or_default() += weight;
+            }
+        }
+
+        let length = frequencies.values().sum();
+
+        for (term, frequency) in &frequencies {
+            self.postings
+                .entry(term.clone())
+                .or_default()
+                .insert(item.id, *frequency);
+        }
+
+        self.total_length += length;
+        self.items.insert(
+            item.id,
+            IndexedItem {
+                item,
+                domain,
+                terms: frequencies.into_keys().collect(),
+                length,
+            },
+        );
+    }
+
+    /// Removes an item from the index, returning it if it was indexed.
+    pub fn remove(&mut self, id: HackerNewsID) -> Option<HackerNewsItem> {
+        let indexed = self.items.remove(&id)?;
+
+        for term in &indexed.terms {
+            if let Some(postings) = self.postings.get_mut(term) {
+                postings.remove(&id);
+
+                if postings.is_empty() {
+                    self.postings.remove(term);
+                }
+            }
+        }
+
+        self.total_length -= indexed.length;
+        Some(indexed.item)
+    }
+
+    /// Retrieves the items by their IDs and indexes them, returning the number of items indexed. Items missing from
+    /// the API are skipped.
+    pub async fn fetch(
+        &mut self,
+        client: &HackerNewsClient,
+        ids: &[HackerNewsID],
+    ) -> HackerNewsResult<usize> {
+        let items = client.items.get_existing_items(ids).await?;
+        let count = items.len();
+
+        self.extend(items);
+        Ok(count)
+    }
+
+    /// Searches the index, returning matching items in the query's order up to its limit.
+    pub fn search(&self, query: &SearchQuery) -> Vec<SearchHit<'_>> {
+        let terms: HashSet<String> = tokenize(&query.text).collect();
+
+        let mut hits: Vec<SearchHit> = if terms.is_empty() {
+            self.items
+                .values()
+                .filter(|indexed| query.matches(&indexed.item, indexed.domain.as_deref()))
+                .map(|indexed| SearchHit {
+                    item: &indexed.item,
+                    relevance: 0.0,
+                })
+                .collect()
+        } else {
+            self.rank(&terms)
+                .into_iter()
+                .filter_map(|(id, relevance)| {
+                    let indexed = self.items.get(&id)?;
+
+                    query
+                        .matches(&indexed.item, indexed.domain.as_deref())
+                        .then_some(SearchHit {
+                            item: &indexed.item,
+                            relevance,
+                        })
+                })
+                .collect()
+        };
+
+        hits.sort_by(|first, second| {
+            let order = match query.order {
+                SearchOrder::Relevance => second.relevance.total_cmp(&first.relevance),
+                SearchOrder::Newest => std::cmp::Ordering::Equal,
+                SearchOrder::Score => second.item.score.cmp(&first.item.score),
+            };
+
+            // break ties with the newest items first for a stable order
+            order
+                .then_with(|| second.item.created_at.cmp(&first.item.created_at))
+                .then_with(|| second.item.id.cmp(&first.item.id))
+        });
+
+        hits.truncate(query.limit);
+        hits
+    }
+
+    /// Scores all items containing any of the terms using BM25.
+    fn rank(&self, terms: &HashSet<String>) -> HashMap<HackerNewsID, f64> {
+        let count = self.items.len() as f64;
+        let average_length = self.total_length / count.max(1.0);
+        let mut scores: HashMap<HackerNewsID, f64> = HashMap::new();
+
+        for term in terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+
+            let frequency = postings.len() as f64;
+            let idf = (1.0 + (count - frequency + 0.5) / (frequency + 0.5)).ln();
+
+            for (id, term_frequency) in postings {
+                let length = self.items.get(id).map(|indexed| indexed.length);
+                let normalization =
+                    1.0 - BM25_B + BM25_B * length.unwrap_or(average_length) / average_length;
+
+                *scores.entry(*id).or_default() += idf * term_frequency * (BM25_K1 + 1.0)
+                    / (term_frequency + BM25_K1 * normalization);
+            }
+        }
+
+        scores
+    }
+}
+
+impl Extend<HackerNewsItem> for HackerNewsIndex {
+    fn extend<T: IntoIterator<Item = HackerNewsItem>>(&mut self, items: T) {
+        for item in items {
+            self.insert(item);
+        }
+    }
+}
+
+impl FromIterator<HackerNewsItem> for HackerNewsIndex {
+    fn from_iter<T: IntoIterator<Item = HackerNewsItem>>(items: T) -> Self {
+        let mut index = Self::new();
+        index.extend(items);
+        index
+    }
+}
+
+impl From<Vec<HackerNewsItem>> for HackerNewsIndex {
+    fn from(items: Vec<HackerNewsItem>) -> Self {
+        items.into_iter().collect()
+    }
+}
+
+impl From<HackerNewsIndex> for Vec<HackerNewsItem> {
+    fn from(index: HackerNewsIndex) -> Self {
+        let mut items: Vec<HackerNewsItem> = index
+            .items
+            .into_values()
+            .map(|indexed| indexed.item)
+            .collect();
+
+        items.sort_by_key(|item| item.id);
+        items
+    }
+}
+
+/// Splits text into lowercase alphanumeric terms.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+}
@@ -0,0 +1,126 @@
+//! Search queries combining free text with filters on indexed items.
+
+use time::OffsetDateTime;
+
+use crate::items::{HackerNewsItem, HackerNewsItemType};
+
+/// Default number of hits returned for a query.
+const DEFAULT_LIMIT: usize = 20;
+
+/// The order search hits are returned in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchOrder {
+    /// Most relevant hits first, falling back to the newest for queries without text.
+    #[default]
+    Relevance,
+    /// Newest hits first.
+    Newest,
+    /// Highest scoring hits first.
+    Score,
+}
+
+/// A query against the local index, matching items containing any of the query's terms
+/// and satisfying all of its filters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery {
+    pub(super) text: String,
+    author: Option<String>,
+    domain: Option<String>,
+    item_type: Option<HackerNewsItemType>,
+    min_score: Option<u32>,
+    after: Option<OffsetDateTime>,
+    before: Option<OffsetDateTime>,
+    pub(super) order: SearchOrder,
+    pub(super) limit: usize,
+}
+
+impl SearchQuery {
+    /// Constructs a new query for the text, matching all items if the text is empty.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            author: None,
+            domain: None,
+            item_type: None,
+            min_score: None,
+            after: None,
+            before: None,
+            order: SearchOrder::default(),
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// Matches only items by the author, ignoring case.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into().to_lowercase());
+        self
+    }
+
+    /// Matches only stories linking to the domain or its subdomains, e.g. `github.com`.
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into().to_lowercase());
+        self
+    }
+
+    /// Matches only items of the type.
+    pub fn item_type(mut self, item_type: HackerNewsItemType) -> Self {
+        self.item_type = Some(item_type);
+        self
+    }
+
+    /// Matches only items scoring at least the minimum.
+    pub fn min_score(mut self, min_score: u32) -> Self {
+        self.min_score = Some(min_score);
+        self
+    }
+
+    /// Matches only items created at or after the time.
+    pub fn after(mut self, after: OffsetDateTime) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Matches only items created before the time.
+    pub fn before(mut self, before: OffsetDateTime) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Configures the order hits are returned in.
+    pub fn order(mut self, order: SearchOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Configures the maximum number of hits returned.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Determines if the indexed item satisfies all of the query's filters.
+    pub(super) fn matches(&self, item: &HackerNewsItem, domain: Option<&str>) -> bool {
+        let author = self.author.as_deref().is_none_or(|author| {
+            item.by
+                .as_deref()
+                .is_some_and(|by| by.eq_ignore_ascii_case(author))
+        });
+
+        let domain = self.domain.as_deref().is_none_or(|expected| {
+            domain.is_some_and(|domain| {
+                domain == expected || domain.ends_with(&format!(".{}", expected))
+            })
+        });
+
+        author
+            && domain
+            && self
+                .item_type
+                .is_none_or(|item_type| item.get_item_type() == item_type)
+            && self
+                .min_score
+                .is_none_or(|min_score| item.score.unwrap_or_default() >= min_score)
+            && self.after.is_none_or(|after| item.created_at >= after)
+            && self.before.is_none_or(|before| item.created_at < before)
+    }
+}
@@ -1,20 +1,28 @@
 //! A client for interacting with item types and endpoints.
 
+use std::{collections::HashMap, future::Future};
+
+use futures::{stream, StreamExt, TryStreamExt};
+
 use crate::{
+    cancellation::{Cancellation, Partial},
+    client::RequestOptions,
     errors::{HackerNewsClientError, HackerNewsResult},
     http::InternalHttpClient,
+    versions::ApiResource,
     HackerNewsID,
 };
 
 use super::{
     comments::HackerNewsComment, jobs::HackerNewsJob, poll_options::HackerNewsPollOption,
-    polls::HackerNewsPoll, stories::HackerNewsStory, HackerNewsItem,
+    polls::HackerNewsPoll, stories::HackerNewsStory, threads::HackerNewsThread, HackerNewsItem,
 };
 
-const ITEM_ENDPOINT: &str = "item";
+/// Maximum number of concurrent requests made when retrieving items in bulk.
+const MAX_CONCURRENT_REQUESTS: usize = 16;
 
 /// An internal items client for interacting with item endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HackerNewsItemClient {
     internal_client: InternalHttpClient,
 }
@@ -27,42 +35,96 @@ impl HackerNewsItemClient {
 
     /// Retrieves item information based on the given ID.
     pub async fn get_item(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsItem> {
+        self.get_item_with(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves item information based on the given ID with the request options applied.
+    pub async fn get_item_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsItem> {
         let item = self
             .internal_client
-            .get_item_with_id(ITEM_ENDPOINT, id)
+            .get(ApiResource::Item(id), &options)
             .await?;
         Ok(item)
     }
 
-    /// Generic retrieval of various hacker news items assuming they are validly convertible to a subtype.
-    async fn get_typed_item<T>(&self, id: HackerNewsID) -> HackerNewsResult<T>
+    /// Generic retrieval of various hacker news items with the request options applied, assuming they are validly
+    /// convertible to a subtype.
+    async fn get_typed_item<T>(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<T>
     where
         HackerNewsClientError: From<<T as TryFrom<HackerNewsItem>>::Error>,
         T: TryFrom<HackerNewsItem>,
     {
-        let item = self.get_item(id).await?;
+        let item = self.get_item_with(id, options).await?;
         let typed_item = item.try_into()?;
         Ok(typed_item)
     }
 
     /// Retrieves a story from Hacker News, returning errors if the item was not a valid story type.
     pub async fn get_story(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsStory> {
-        self.get_typed_item(id).await
+        self.get_typed_item(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves a story from Hacker News with the request options applied, returning errors if the item was not a
+    /// valid story type.
+    pub async fn get_story_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsStory> {
+        self.get_typed_item(id, options).await
     }
 
     /// Retrieves a story comment from Hacker News, returning errors if the item was not a valid comment type.
     pub async fn get_comment(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsComment> {
-        self.get_typed_item(id).await
+        self.get_typed_item(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves a story comment from Hacker News with the request options applied, returning errors if the item was not a
+    /// valid comment type.
+    pub async fn get_comment_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsComment> {
+        self.get_typed_item(id, options).await
     }
 
     /// Retrieves a job posting from Hacker News, returning errors if the item was not a valid job posting type.
     pub async fn get_job(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsJob> {
-        self.get_typed_item(id).await
+        self.get_typed_item(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves a job posting from Hacker News with the request options applied, returning errors if the item was not a
+    /// valid job posting type.
+    pub async fn get_job_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsJob> {
+        self.get_typed_item(id, options).await
     }
 
     /// Retrieves a poll from Hacker News, returning errors if the item was not a valid poll type.
     pub async fn get_poll(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsPoll> {
-        self.get_typed_item(id).await
+        self.get_typed_item(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves a poll from Hacker News with the request options applied, returning errors if the item was not a
+    /// valid poll type.
+    pub async fn get_poll_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsPoll> {
+        self.get_typed_item(id, options).await
     }
 
     /// Retrieves a poll option from Hacker News, returning errors if the item was not a valid poll option type.
@@ -70,6 +132,231 @@ impl HackerNewsItemClient {
         &self,
         id: HackerNewsID,
     ) -> HackerNewsResult<HackerNewsPollOption> {
-        self.get_typed_item(id).await
+        self.get_typed_item(id, RequestOptions::default()).await
     }
+
+    /// Retrieves a poll option from Hacker News with the request options applied, returning errors if the item was not a
+    /// valid poll option type.
+    pub async fn get_poll_option_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsPollOption> {
+        self.get_typed_item(id, options).await
+    }
+
+    /// Retrieves multiple items concurrently, returning them in the order of the given IDs.
+    /// Fails if any of the items could not be retrieved.
+    pub async fn get_items(&self, ids: &[HackerNewsID]) -> HackerNewsResult<Vec<HackerNewsItem>> {
+        self.get_items_with(ids, RequestOptions::default()).await
+    }
+
+    /// Retrieves multiple items concurrently with the request options applied to each, returning them in the order of
+    /// the given IDs. Fails if any of the items could not be retrieved.
+    pub async fn get_items_with(
+        &self,
+        ids: &[HackerNewsID],
+        options: RequestOptions,
+    ) -> HackerNewsResult<Vec<HackerNewsItem>> {
+        stream::iter(
+            ids.iter()
+                .copied()
+                .map(|id| self.get_item_with(id, options)),
+        )
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .try_collect()
+        .await
+    }
+
+    /// Retrieves multiple items concurrently in the order of the given IDs until cancelled or past the deadline,
+    /// returning the items retrieved before the first that could not be along with the reason.
+    pub async fn get_items_until(
+        &self,
+        ids: &[HackerNewsID],
+        cancellation: &Cancellation,
+    ) -> Partial<Vec<HackerNewsItem>> {
+        self.get_items_until_with(ids, cancellation, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves multiple items concurrently with the request options applied to each, in the order of the given IDs
+    /// until cancelled or past the deadline, returning the items retrieved before the first that could not be along
+    /// with the reason.
+    pub async fn get_items_until_with(
+        &self,
+        ids: &[HackerNewsID],
+        cancellation: &Cancellation,
+        options: RequestOptions,
+    ) -> Partial<Vec<HackerNewsItem>> {
+        collect_until(ids, cancellation, |id| self.get_item_with(id, options)).await
+    }
+
+    /// Retrieves multiple items concurrently with the request options applied to each, in the order of the given IDs
+    /// until cancelled or past the deadline, with `None` in place of items that do not exist so the results line up
+    /// with the IDs retrieved.
+    pub(crate) async fn get_existing_items_until(
+        &self,
+        ids: &[HackerNewsID],
+        cancellation: &Cancellation,
+        options: RequestOptions,
+    ) -> Partial<Vec<Option<HackerNewsItem>>> {
+        collect_until(ids, cancellation, |id| self.get_existing_item(id, options)).await
+    }
+
+    /// Retrieves multiple items concurrently in the order of the given IDs, skipping items that do not exist, e.g.
+    /// deleted items still listed within a realtime list.
+    pub async fn get_existing_items(
+        &self,
+        ids: &[HackerNewsID],
+    ) -> HackerNewsResult<Vec<HackerNewsItem>> {
+        self.get_existing_items_with(ids, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves multiple items concurrently with the request options applied to each, in the order of the given IDs,
+    /// skipping items that do not exist.
+    pub async fn get_existing_items_with(
+        &self,
+        ids: &[HackerNewsID],
+        options: RequestOptions,
+    ) -> HackerNewsResult<Vec<HackerNewsItem>> {
+        let items: Vec<Option<HackerNewsItem>> = stream::iter(
+            ids.iter()
+                .copied()
+                .map(|id| self.get_existing_item(id, options)),
+        )
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .try_collect()
+        .await?;
+
+        Ok(items.into_iter().flatten().collect())
+    }
+
+    /// Retrieves an item along with its entire comment tree, walking the tree one level at a time
+    /// with each level's comments retrieved concurrently. Comments Hacker News no longer returns are skipped.
+    pub async fn get_thread(&self, id: HackerNewsID) -> HackerNewsResult<HackerNewsThread> {
+        self.get_thread_with(id, RequestOptions::default()).await
+    }
+
+    /// Retrieves an item along with its entire comment tree with the request options applied to each request made
+    /// while walking the tree.
+    pub async fn get_thread_with(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsThread> {
+        let root = self.get_item_with(id, options).await?;
+        let mut comments = HashMap::new();
+        let mut pending = root.kids.clone().unwrap_or_default();
+
+        while !pending.is_empty() {
+            let level = self.get_existing_items_with(&pending, options).await?;
+
+            pending = Vec::new();
+
+            for item in level {
+                let comment: HackerNewsComment = item.try_into()?;
+                pending.extend(comment.sub_comments.iter().copied());
+                comments.insert(comment.id, comment);
+            }
+        }
+
+        Ok(HackerNewsThread::assemble(root, comments))
+    }
+
+    /// Retrieves an item along with its comment tree until cancelled or past the deadline, returning the comments
+    /// retrieved so far if the walk stops early. Fails only if the item itself could not be retrieved.
+    pub async fn get_thread_until(
+        &self,
+        id: HackerNewsID,
+        cancellation: &Cancellation,
+    ) -> HackerNewsResult<Partial<HackerNewsThread>> {
+        self.get_thread_until_with(id, cancellation, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves an item along with its comment tree with the request options applied to each request until cancelled
+    /// or past the deadline, returning the comments retrieved so far if the walk stops early. Fails only if the item
+    /// itself could not be retrieved.
+    pub async fn get_thread_until_with(
+        &self,
+        id: HackerNewsID,
+        cancellation: &Cancellation,
+        options: RequestOptions,
+    ) -> HackerNewsResult<Partial<HackerNewsThread>> {
+        let root = cancellation.run(self.get_item_with(id, options)).await?;
+        let mut comments = HashMap::new();
+        let mut pending = root.kids.clone().unwrap_or_default();
+        let mut error = None;
+
+        'walk: while !pending.is_empty() {
+            let level = self
+                .get_existing_items_until(&pending, cancellation, options)
+                .await;
+
+            pending = Vec::new();
+
+            for item in level.value.into_iter().flatten() {
+                let comment = match HackerNewsComment::try_from(item) {
+                    Ok(comment) => comment,
+                    Err(conversion_error) => {
+                        error = Some(conversion_error);
+                        break 'walk;
+                    }
+                };
+
+                pending.extend(comment.sub_comments.iter().copied());
+                comments.insert(comment.id, comment);
+            }
+
+            if level.error.is_some() {
+                error = level.error;
+                break;
+            }
+        }
+
+        Ok(Partial::new(
+            HackerNewsThread::assemble(root, comments),
+            error,
+        ))
+    }
+
+    /// Retrieves an item, returning `None` rather than an error when the item does not exist.
+    async fn get_existing_item(
+        &self,
+        id: HackerNewsID,
+        options: RequestOptions,
+    ) -> HackerNewsResult<Option<HackerNewsItem>> {
+        match self.get_item_with(id, options).await {
+            Ok(item) => Ok(Some(item)),
+            Err(HackerNewsClientError::ItemOrUserNotFound(_)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Retrieves values for each ID concurrently in order until one fails or the operation should stop, returning the
+/// values retrieved before then along with the error.
+async fn collect_until<T, F, Fut>(
+    ids: &[HackerNewsID],
+    cancellation: &Cancellation,
+    retrieve: F,
+) -> Partial<Vec<T>>
+where
+    F: Fn(HackerNewsID) -> Fut,
+    Fut: Future<Output = HackerNewsResult<T>>,
+{
+    let mut results = stream::iter(ids.iter().copied())
+        .map(|id| cancellation.run(retrieve(id)))
+        .buffered(MAX_CONCURRENT_REQUESTS);
+    let mut values = Vec::with_capacity(ids.len());
+
+    while let Some(result) = results.next().await {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => return Partial::new(values, Some(error)),
+        }
+    }
+
+    Partial::new(values, None)
 }
@@ -1,16 +1,17 @@
 //! Story comments associated to Hacker News stories  and all associated data.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::{
     errors::HackerNewsClientError,
-    items::{HackerNewsItem, HackerNewsItemType},
-    HackerNewsID,
+    items::{non_empty, HackerNewsItem, HackerNewsItemType, ITEM_TYPE_COMMENT},
+    text::{self, HackerNewsLink, SanitizedHtml},
+    urls, HackerNewsID,
 };
 
 /// Represents a Hacker News story comment and all associated data to it including author and child comments.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct HackerNewsComment {
     /// The item's unique id.
     pub id: HackerNewsID,
@@ -24,6 +25,44 @@ pub struct HackerNewsComment {
     pub text: String,
     /// Username of the comment poster.
     pub by: String,
+    /// Flag representing the comment has been deleted, leaving no author or text.
+    #[serde(default)]
+    pub deleted: bool,
+    /// Flag representing the comment has been killed by moderators or flags.
+    #[serde(default)]
+    pub dead: bool,
+}
+
+impl HackerNewsComment {
+    /// Determines if the comment is visible, i.e. it has been neither deleted nor killed.
+    pub fn is_visible(&self) -> bool {
+        !self.deleted && !self.dead
+    }
+
+    /// Renders the comment's text as plain text.
+    pub fn text_plain(&self) -> String {
+        text::to_plain_text(&self.text)
+    }
+
+    /// Renders the comment's text as Markdown.
+    pub fn text_markdown(&self) -> String {
+        text::to_markdown(&self.text)
+    }
+
+    /// Sanitizes the comment's text for embedding within other web pages.
+    pub fn text_sanitized(&self) -> SanitizedHtml {
+        text::sanitize(&self.text)
+    }
+
+    /// Extracts all links found within the comment's text.
+    pub fn links(&self) -> Vec<HackerNewsLink> {
+        text::extract_links(&self.text)
+    }
+
+    /// Generates the Hacker News permalink for the comment.
+    pub fn permalink(&self) -> String {
+        urls::item_permalink(self.id)
+    }
 }
 
 impl TryFrom<HackerNewsItem> for HackerNewsComment {
@@ -43,6 +82,30 @@ impl TryFrom<HackerNewsItem> for HackerNewsComment {
             parent_story: item.parent.unwrap_or_default(),
             text: item.text.unwrap_or_default(),
             by: item.by.unwrap_or_default(),
+            deleted: item.deleted.unwrap_or_default(),
+            dead: item.dead.unwrap_or_default(),
         })
     }
 }
+
+impl From<HackerNewsComment> for HackerNewsItem {
+    fn from(comment: HackerNewsComment) -> Self {
+        Self {
+            id: comment.id,
+            deleted: comment.deleted.then_some(true),
+            response_type: Some(ITEM_TYPE_COMMENT.to_string()),
+            by: non_empty(comment.by),
+            created_at: comment.created_at,
+            dead: comment.dead.then_some(true),
+            parent: non_empty(comment.parent_story),
+            poll: None,
+            kids: non_empty(comment.sub_comments),
+            url: None,
+            score: None,
+            title: None,
+            text: non_empty(comment.text),
+            parts: None,
+            descendants: None,
+        }
+    }
+}
@@ -1,16 +1,18 @@
 //! Jobs listed on the Hacker News and all associated data.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::{
     errors::HackerNewsClientError,
-    items::{HackerNewsItem, HackerNewsItemType},
+    items::{non_empty, HackerNewsItem, HackerNewsItemType, ITEM_TYPE_JOB},
+    text::{self, HackerNewsLink, SanitizedHtml},
+    urls::{self, Url},
     HackerNewsID,
 };
 
-/// Represents a Hacker News story and all associated data to it including author, text, and child comments.
-#[derive(Debug, Serialize)]
+/// Represents a Hacker News job posting and all associated data to it including author, title, and listing URL.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct HackerNewsJob {
     /// The item's unique id.
     pub id: HackerNewsID,
@@ -28,11 +30,53 @@ pub struct HackerNewsJob {
     pub url: String,
 }
 
+impl HackerNewsJob {
+    /// Renders the job listing description as plain text.
+    pub fn text_plain(&self) -> String {
+        text::to_plain_text(&self.text)
+    }
+
+    /// Renders the job listing description as Markdown.
+    pub fn text_markdown(&self) -> String {
+        text::to_markdown(&self.text)
+    }
+
+    /// Sanitizes the job listing description for embedding within other web pages.
+    pub fn text_sanitized(&self) -> SanitizedHtml {
+        text::sanitize(&self.text)
+    }
+
+    /// Decodes any encoded entities in the title.
+    pub fn title_plain(&self) -> String {
+        text::decode_entities(&self.title)
+    }
+
+    /// Extracts all links found within the job listing description.
+    pub fn links(&self) -> Vec<HackerNewsLink> {
+        text::extract_links(&self.text)
+    }
+
+    /// Parses the job listing's URL, returning `None` if the job listing has no URL or it is malformed.
+    pub fn parsed_url(&self) -> Option<Url> {
+        urls::parse_url(&self.url)
+    }
+
+    /// Computes the site Hacker News displays for the job listing's URL, e.g. `github.com/rust-lang`.
+    pub fn site(&self) -> Option<String> {
+        self.parsed_url().and_then(|url| urls::site(&url))
+    }
+
+    /// Generates the Hacker News permalink for the job listing.
+    pub fn permalink(&self) -> String {
+        urls::item_permalink(self.id)
+    }
+}
+
 impl TryFrom<HackerNewsItem> for HackerNewsJob {
     type Error = HackerNewsClientError;
 
     fn try_from(item: HackerNewsItem) -> Result<Self, Self::Error> {
-        if item.get_item_type() != HackerNewsItemType::Comment {
+        if item.get_item_type() != HackerNewsItemType::Job {
             return Err(HackerNewsClientError::InvalidTypeMapping(
                 item.get_item_type(),
             ));
@@ -49,3 +93,25 @@ impl TryFrom<HackerNewsItem> for HackerNewsJob {
         })
     }
 }
+
+impl From<HackerNewsJob> for HackerNewsItem {
+    fn from(job: HackerNewsJob) -> Self {
+        Self {
+            id: job.id,
+            deleted: None,
+            response_type: Some(ITEM_TYPE_JOB.to_string()),
+            by: non_empty(job.by),
+            created_at: job.created_at,
+            dead: None,
+            parent: None,
+            poll: None,
+            kids: None,
+            url: non_empty(job.url),
+            score: Some(job.score),
+            title: non_empty(job.title),
+            text: non_empty(job.text),
+            parts: None,
+            descendants: None,
+        }
+    }
+}
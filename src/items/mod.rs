@@ -8,13 +8,18 @@ pub mod jobs;
 pub mod poll_options;
 pub mod polls;
 pub mod stories;
+pub mod threads;
 
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
-use crate::HackerNewsID;
+use crate::{
+    text::{self, HackerNewsLink, SanitizedHtml},
+    urls::{self, Url},
+    HackerNewsID,
+};
 
 const ITEM_TYPE_COMMENT: &str = "comment";
 const ITEM_TYPE_JOB: &str = "job";
@@ -46,7 +51,7 @@ impl Display for HackerNewsItemType {
 }
 
 /// Represents a Hacker News item returned from the item endpoint.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct HackerNewsItem {
     /// The item's unique id.
     pub id: HackerNewsID,
@@ -82,6 +87,15 @@ pub struct HackerNewsItem {
     pub descendants: Option<u32>,
 }
 
+/// Maps a defaulted value on a typed item back to its optional representation on the generic item.
+fn non_empty<T: Default + PartialEq>(value: T) -> Option<T> {
+    if value == T::default() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 impl HackerNewsItem {
     fn parse_item_type(&self, item_type: &str) -> HackerNewsItemType {
         match item_type {
@@ -130,4 +144,47 @@ impl HackerNewsItem {
     pub fn is_story(&self) -> bool {
         self.is_item_type(HackerNewsItemType::Story)
     }
+
+    /// Renders the item's HTML text as plain text, if the item has text.
+    pub fn text_plain(&self) -> Option<String> {
+        self.text.as_deref().map(text::to_plain_text)
+    }
+
+    /// Renders the item's HTML text as Markdown, if the item has text.
+    pub fn text_markdown(&self) -> Option<String> {
+        self.text.as_deref().map(text::to_markdown)
+    }
+
+    /// Sanitizes the item's HTML text for embedding within other web pages, if the item has text.
+    pub fn text_sanitized(&self) -> Option<SanitizedHtml> {
+        self.text.as_deref().map(text::sanitize)
+    }
+
+    /// Decodes any encoded entities in the item's title, if the item has a title.
+    pub fn title_plain(&self) -> Option<String> {
+        self.title.as_deref().map(text::decode_entities)
+    }
+
+    /// Extracts all links found within the item's text.
+    pub fn links(&self) -> Vec<HackerNewsLink> {
+        self.text
+            .as_deref()
+            .map(text::extract_links)
+            .unwrap_or_default()
+    }
+
+    /// Parses the item's URL, returning `None` if the item has no URL or it is malformed.
+    pub fn parsed_url(&self) -> Option<Url> {
+        self.url.as_deref().and_then(urls::parse_url)
+    }
+
+    /// Computes the site Hacker News displays for the item's URL, e.g. `github.com/rust-lang`.
+    pub fn site(&self) -> Option<String> {
+        self.parsed_url().and_then(|url| urls::site(&url))
+    }
+
+    /// Generates the Hacker News permalink for the item.
+    pub fn permalink(&self) -> String {
+        urls::item_permalink(self.id)
+    }
 }
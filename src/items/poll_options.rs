@@ -1,16 +1,17 @@
 //! Poll options associated to a poll.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::{
     errors::HackerNewsClientError,
-    items::{HackerNewsItem, HackerNewsItemType},
-    HackerNewsID,
+    items::{non_empty, HackerNewsItem, HackerNewsItemType, ITEM_TYPE_POLL_OPTIONS},
+    text::{self, SanitizedHtml},
+    urls, HackerNewsID,
 };
 
 /// Represents a Hacker News poll option and all associated data to it including the parent poll and author.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct HackerNewsPollOption {
     /// The item's unique id.
     pub id: HackerNewsID,
@@ -26,6 +27,28 @@ pub struct HackerNewsPollOption {
     pub by: String,
 }
 
+impl HackerNewsPollOption {
+    /// Renders the poll option's text as plain text.
+    pub fn text_plain(&self) -> String {
+        text::to_plain_text(&self.text)
+    }
+
+    /// Renders the poll option's text as Markdown.
+    pub fn text_markdown(&self) -> String {
+        text::to_markdown(&self.text)
+    }
+
+    /// Sanitizes the poll option's text for embedding within other web pages.
+    pub fn text_sanitized(&self) -> SanitizedHtml {
+        text::sanitize(&self.text)
+    }
+
+    /// Generates the Hacker News permalink for the poll option.
+    pub fn permalink(&self) -> String {
+        urls::item_permalink(self.id)
+    }
+}
+
 impl TryFrom<HackerNewsItem> for HackerNewsPollOption {
     type Error = HackerNewsClientError;
 
@@ -50,3 +73,25 @@ impl TryFrom<HackerNewsItem> for HackerNewsPollOption {
         })
     }
 }
+
+impl From<HackerNewsPollOption> for HackerNewsItem {
+    fn from(poll_option: HackerNewsPollOption) -> Self {
+        Self {
+            id: poll_option.id,
+            deleted: None,
+            response_type: Some(ITEM_TYPE_POLL_OPTIONS.to_string()),
+            by: non_empty(poll_option.by),
+            created_at: poll_option.created_at,
+            dead: None,
+            parent: None,
+            poll: Some(poll_option.poll),
+            kids: None,
+            url: None,
+            score: Some(poll_option.score),
+            title: None,
+            text: non_empty(poll_option.text),
+            parts: None,
+            descendants: None,
+        }
+    }
+}
@@ -1,16 +1,17 @@
 //! Polls listed on Hacker News and all associated data.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::{
     errors::HackerNewsClientError,
-    items::{HackerNewsItem, HackerNewsItemType},
-    HackerNewsID,
+    items::{non_empty, HackerNewsItem, HackerNewsItemType, ITEM_TYPE_POLL},
+    text::{self, SanitizedHtml},
+    urls, HackerNewsID,
 };
 
 /// Represents a Hacker News poll and all associated data including comments on the poll, poll options, etc.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct HackerNewsPoll {
     /// The item's unique id.
     pub id: HackerNewsID,
@@ -32,6 +33,33 @@ pub struct HackerNewsPoll {
     pub by: String,
 }
 
+impl HackerNewsPoll {
+    /// Renders the poll's text as plain text.
+    pub fn text_plain(&self) -> String {
+        text::to_plain_text(&self.text)
+    }
+
+    /// Renders the poll's text as Markdown.
+    pub fn text_markdown(&self) -> String {
+        text::to_markdown(&self.text)
+    }
+
+    /// Sanitizes the poll's text for embedding within other web pages.
+    pub fn text_sanitized(&self) -> SanitizedHtml {
+        text::sanitize(&self.text)
+    }
+
+    /// Decodes any encoded entities in the title.
+    pub fn title_plain(&self) -> String {
+        text::decode_entities(&self.title)
+    }
+
+    /// Generates the Hacker News permalink for the poll.
+    pub fn permalink(&self) -> String {
+        urls::item_permalink(self.id)
+    }
+}
+
 impl TryFrom<HackerNewsItem> for HackerNewsPoll {
     type Error = HackerNewsClientError;
 
@@ -55,3 +83,25 @@ impl TryFrom<HackerNewsItem> for HackerNewsPoll {
         })
     }
 }
+
+impl From<HackerNewsPoll> for HackerNewsItem {
+    fn from(poll: HackerNewsPoll) -> Self {
+        Self {
+            id: poll.id,
+            deleted: None,
+            response_type: Some(ITEM_TYPE_POLL.to_string()),
+            by: non_empty(poll.by),
+            created_at: poll.created_at,
+            dead: None,
+            parent: None,
+            poll: None,
+            kids: non_empty(poll.comments),
+            url: None,
+            score: Some(poll.score),
+            title: non_empty(poll.title),
+            text: non_empty(poll.text),
+            parts: non_empty(poll.poll_options),
+            descendants: Some(poll.participants),
+        }
+    }
+}
@@ -1,16 +1,84 @@
 //! Stories listed on the Hacker News homepage and all associated data.
 
-use serde::Serialize;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::{
     errors::HackerNewsClientError,
-    items::{HackerNewsItem, HackerNewsItemType},
+    items::{non_empty, HackerNewsItem, HackerNewsItemType, ITEM_TYPE_STORY},
+    text::{self, HackerNewsLink, SanitizedHtml},
+    urls::{self, Url},
     HackerNewsID,
 };
 
+/// Title prefixes Hacker News uses to mark story kinds, matched case-insensitively.
+const ASK_PREFIX: &str = "ask hn";
+const SHOW_PREFIX: &str = "show hn";
+const LAUNCH_PREFIX: &str = "launch hn";
+const TELL_PREFIX: &str = "tell hn";
+
+/// Title fragments identifying the monthly hiring threads.
+const WHO_IS_HIRING: &str = "who is hiring";
+const WHO_WANTS_TO_BE_HIRED: &str = "who wants to be hired";
+const SEEKING_FREELANCER: &str = "seeking freelancer";
+
+/// The kind of story, as determined by its title prefix and whether it links to an external URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum StoryKind {
+    /// An "Ask HN" question posed to the community.
+    Ask,
+    /// A "Show HN" post sharing something the author made.
+    Show,
+    /// A "Launch HN" post announcing a Y Combinator company launch.
+    Launch,
+    /// A "Tell HN" announcement to the community.
+    Tell,
+    /// A text post without an external URL and without a recognized title prefix.
+    SelfPost,
+    /// A story linking to an external URL.
+    Link,
+}
+
+impl Display for StoryKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The monthly hiring threads posted to Hacker News.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum HiringThreadKind {
+    /// The "Ask HN: Who is hiring?" thread where companies post job openings.
+    WhoIsHiring,
+    /// The "Ask HN: Who wants to be hired?" thread where candidates post their availability.
+    WhoWantsToBeHired,
+    /// The "Ask HN: Freelancer? Seeking freelancer?" thread for freelance work.
+    Freelancer,
+}
+
+impl Display for HiringThreadKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Determines if the title starts with the prefix, followed by a separator rather than another word.
+fn has_title_prefix(title: &str, prefix: &str) -> bool {
+    let title = title.trim_start().to_lowercase();
+
+    match title.strip_prefix(prefix) {
+        Some(remaining) => remaining
+            .chars()
+            .next()
+            .is_none_or(|next| next == ':' || next.is_whitespace() || next == '-'),
+        None => false,
+    }
+}
+
 /// Represents a Hacker News story and all associated data to it including author, text, and child comments.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct HackerNewsStory {
     /// The item's unique id.
     pub id: HackerNewsID,
@@ -32,6 +100,112 @@ pub struct HackerNewsStory {
     pub text: String,
 }
 
+impl HackerNewsStory {
+    /// Renders the story's text as plain text.
+    pub fn text_plain(&self) -> String {
+        text::to_plain_text(&self.text)
+    }
+
+    /// Renders the story's text as Markdown.
+    pub fn text_markdown(&self) -> String {
+        text::to_markdown(&self.text)
+    }
+
+    /// Sanitizes the story's text for embedding within other web pages.
+    pub fn text_sanitized(&self) -> SanitizedHtml {
+        text::sanitize(&self.text)
+    }
+
+    /// Classifies the story based on its title prefix and the presence of a URL or text.
+    pub fn kind(&self) -> StoryKind {
+        let title = text::decode_entities(&self.title);
+
+        if has_title_prefix(&title, ASK_PREFIX) {
+            StoryKind::Ask
+        } else if has_title_prefix(&title, SHOW_PREFIX) {
+            StoryKind::Show
+        } else if has_title_prefix(&title, LAUNCH_PREFIX) {
+            StoryKind::Launch
+        } else if has_title_prefix(&title, TELL_PREFIX) {
+            StoryKind::Tell
+        } else if self.url.trim().is_empty() {
+            StoryKind::SelfPost
+        } else {
+            StoryKind::Link
+        }
+    }
+
+    /// Classifies the story, preferring its membership in the Ask HN and Show HN lists retrieved from
+    /// the realtime client over its title prefix, as Hacker News includes posts in those lists regardless of title.
+    pub fn kind_with_listings(
+        &self,
+        ask_stories: &[HackerNewsID],
+        show_stories: &[HackerNewsID],
+    ) -> StoryKind {
+        if ask_stories.contains(&self.id) {
+            StoryKind::Ask
+        } else if show_stories.contains(&self.id) {
+            StoryKind::Show
+        } else {
+            self.kind()
+        }
+    }
+
+    /// Detects the monthly "Who is hiring?", "Who wants to be hired?", and freelancer threads.
+    pub fn hiring_thread(&self) -> Option<HiringThreadKind> {
+        let title = text::decode_entities(&self.title).to_lowercase();
+
+        if !has_title_prefix(&title, ASK_PREFIX) {
+            return None;
+        }
+
+        if title.contains(WHO_IS_HIRING) {
+            Some(HiringThreadKind::WhoIsHiring)
+        } else if title.contains(WHO_WANTS_TO_BE_HIRED) {
+            Some(HiringThreadKind::WhoWantsToBeHired)
+        } else if title.contains(SEEKING_FREELANCER) {
+            Some(HiringThreadKind::Freelancer)
+        } else {
+            None
+        }
+    }
+
+    /// Decodes any encoded entities in the title.
+    pub fn title_plain(&self) -> String {
+        text::decode_entities(&self.title)
+    }
+
+    /// Extracts all links found within the story's text.
+    pub fn links(&self) -> Vec<HackerNewsLink> {
+        text::extract_links(&self.text)
+    }
+
+    /// Parses the story's URL, returning `None` if the story has no URL or it is malformed.
+    pub fn parsed_url(&self) -> Option<Url> {
+        urls::parse_url(&self.url)
+    }
+
+    /// Computes the site Hacker News displays for the story's URL, e.g. `github.com/rust-lang`.
+    pub fn site(&self) -> Option<String> {
+        self.parsed_url().and_then(|url| urls::site(&url))
+    }
+
+    /// Canonicalizes the story's URL for deduplicating links, removing tracking parameters and fragments.
+    pub fn canonical_url(&self) -> Option<Url> {
+        self.parsed_url().map(|url| urls::canonicalize(&url))
+    }
+
+    /// Generates the Hacker News permalink for the story.
+    pub fn permalink(&self) -> String {
+        urls::item_permalink(self.id)
+    }
+
+    /// Generates the Hacker News permalink listing all submissions from the story's site.
+    pub fn site_permalink(&self) -> Option<String> {
+        self.site().map(|site| urls::site_permalink(&site))
+    }
+}
+
 impl TryFrom<HackerNewsItem> for HackerNewsStory {
     type Error = HackerNewsClientError;
 
@@ -55,3 +229,25 @@ impl TryFrom<HackerNewsItem> for HackerNewsStory {
         })
     }
 }
+
+impl From<HackerNewsStory> for HackerNewsItem {
+    fn from(story: HackerNewsStory) -> Self {
+        Self {
+            id: story.id,
+            deleted: None,
+            response_type: Some(ITEM_TYPE_STORY.to_string()),
+            by: non_empty(story.by),
+            created_at: story.created_at,
+            dead: None,
+            parent: None,
+            poll: None,
+            kids: non_empty(story.comments),
+            url: non_empty(story.url),
+            score: Some(story.score),
+            title: non_empty(story.title),
+            text: non_empty(story.text),
+            parts: None,
+            descendants: Some(story.number_of_comments),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Comment threads associated to stories, polls, and comments, assembled from the item tree.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    items::{comments::HackerNewsComment, HackerNewsItem},
+    HackerNewsID,
+};
+
+/// Represents a comment within a thread along with its replies, in ranked display order.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HackerNewsCommentNode {
+    /// The comment at this position in the thread.
+    pub comment: HackerNewsComment,
+    /// Direct replies to the comment, each with their own replies.
+    pub replies: Vec<HackerNewsCommentNode>,
+}
+
+impl HackerNewsCommentNode {
+    /// Counts all nested replies to the comment.
+    pub fn reply_count(&self) -> usize {
+        self.replies
+            .iter()
+            .map(|reply| 1 + reply.reply_count())
+            .sum()
+    }
+}
+
+/// Represents an item and its full comment tree.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HackerNewsThread {
+    /// The item the thread was requested for, typically a story.
+    pub root: HackerNewsItem,
+    /// Top level comments on the root item, each with their nested replies.
+    pub comments: Vec<HackerNewsCommentNode>,
+}
+
+impl HackerNewsThread {
+    /// Assembles a thread from the root item and its retrieved comments, ordering replies by each item's `kids`.
+    /// Comments not reachable from the root item are ignored.
+    pub(crate) fn assemble(
+        root: HackerNewsItem,
+        mut comments: HashMap<HackerNewsID, HackerNewsComment>,
+    ) -> Self {
+        let top_level = root.kids.clone().unwrap_or_default();
+        let comments = assemble_nodes(&top_level, &mut comments);
+
+        Self { root, comments }
+    }
+
+    /// Counts all comments in the thread, including deleted and dead comments.
+    pub fn len(&self) -> usize {
+        self.comments
+            .iter()
+            .map(|node| 1 + node.reply_count())
+            .sum()
+    }
+
+    /// Determines if the thread has no comments.
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+
+    /// Iterates all comments in the thread depth-first in display order, along with their depth
+    /// where top level comments have a depth of zero.
+    pub fn iter(&self) -> HackerNewsThreadIter<'_> {
+        HackerNewsThreadIter {
+            stack: self.comments.iter().rev().map(|node| (0, node)).collect(),
+        }
+    }
+
+    /// Finds a comment within the thread by its ID.
+    pub fn find(&self, id: HackerNewsID) -> Option<&HackerNewsCommentNode> {
+        self.iter()
+            .map(|(_, node)| node)
+            .find(|node| node.comment.id == id)
+    }
+}
+
+fn assemble_nodes(
+    ids: &[HackerNewsID],
+    comments: &mut HashMap<HackerNewsID, HackerNewsComment>,
+) -> Vec<HackerNewsCommentNode> {
+    let mut nodes = Vec::with_capacity(ids.len());
+
+    for id in ids {
+        if let Some(comment) = comments.remove(id) {
+            let replies = assemble_nodes(&comment.sub_comments, comments);
+            nodes.push(HackerNewsCommentNode { comment, replies });
+        }
+    }
+
+    nodes
+}
+
+/// A depth-first iterator over comments in a thread, yielding each comment's depth alongside it.
+#[derive(Debug)]
+pub struct HackerNewsThreadIter<'a> {
+    stack: Vec<(usize, &'a HackerNewsCommentNode)>,
+}
+
+impl<'a> Iterator for HackerNewsThreadIter<'a> {
+    type Item = (usize, &'a HackerNewsCommentNode);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, node) = self.stack.pop()?;
+
+        self.stack
+            .extend(node.replies.iter().rev().map(|reply| (depth + 1, reply)));
+
+        Some((depth, node))
+    }
+}
@@ -38,12 +38,33 @@
     clippy::single_char_pattern
 )]
 
+pub mod algolia;
+pub mod analytics;
+pub mod archive;
+pub mod cancellation;
 pub mod client;
 pub mod errors;
+pub mod export;
+pub mod feeds;
+#[cfg(feature = "graphql")]
+pub mod graphql;
 mod http;
+#[cfg(feature = "index")]
+pub mod index;
 pub mod items;
+#[cfg(feature = "axum")]
+pub mod proxy;
 pub mod realtime;
+pub mod text;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod urls;
 pub mod users;
+mod versions;
+#[cfg(feature = "watch")]
+pub mod watch;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 
 /// The ID associated to all Hacker News items and users.
 pub type HackerNewsID = u32;
@@ -0,0 +1,188 @@
+//! A ready-made [axum](https://docs.rs/axum) router proxying the Hacker News API, available behind the `axum` feature.
+//! Responses include caching headers suited to how often each resource changes, and client errors are mapped to
+//! their HTTP equivalents, i.e. `404` for missing items and users and `502` when the API can't be reached. Server
+//! errors respond with a generic message, logging the underlying error with [tracing](https://docs.rs/tracing).
+//!
+//! ```no_run
+//! use axum::Router;
+//! use newswrap::{client::HackerNewsClient, proxy};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let app: Router = Router::new().nest("/hn", proxy::router(HackerNewsClient::new()));
+//!
+//!     axum::Server::bind(&([0, 0, 0, 0], 8000).into())
+//!         .serve(app.into_make_service())
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    client::HackerNewsClient, errors::HackerNewsClientError, realtime::HackerNewsStoryList,
+    HackerNewsID,
+};
+
+/// Seconds items and threads may be cached for, as scores and comments change frequently.
+const ITEM_MAX_AGE_SECONDS: u64 = 60;
+
+/// Seconds users may be cached for, as profiles change infrequently.
+const USER_MAX_AGE_SECONDS: u64 = 300;
+
+/// Seconds the realtime lists and updates may be cached for.
+const LIVE_MAX_AGE_SECONDS: u64 = 30;
+
+/// Shared state for the router's handlers.
+type ProxyState = HackerNewsClient;
+
+/// Builds a router proxying the API with the client, mountable within an application's router, e.g. with
+/// `Router::nest`. The router exposes:
+///
+/// - `/item/:id` and `/thread/:id` for items and their comment trees
+/// - `/user/:name` for users
+/// - `/top`, `/new`, `/best`, `/ask`, `/show` and `/jobs` for the realtime lists
+/// - `/maxitem` and `/updates` for the latest item ID and recently updated items and profiles
+pub fn router<S>(client: HackerNewsClient) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let mut router = Router::new()
+        .route("/item/:id", get(get_item))
+        .route("/thread/:id", get(get_thread))
+        .route("/user/:name", get(get_user))
+        .route("/maxitem", get(get_max_item))
+        .route("/updates", get(get_updates));
+
+    for list in HackerNewsStoryList::ALL {
+        router = router.route(
+            list_path(list),
+            get(move |State(client): State<ProxyState>| get_story_list(client, list)),
+        );
+    }
+
+    router.with_state(client)
+}
+
+/// The route each realtime list is served from.
+fn list_path(list: HackerNewsStoryList) -> &'static str {
+    match list {
+        HackerNewsStoryList::Top => "/top",
+        HackerNewsStoryList::New => "/new",
+        HackerNewsStoryList::Best => "/best",
+        HackerNewsStoryList::Ask => "/ask",
+        HackerNewsStoryList::Show => "/show",
+        HackerNewsStoryList::Jobs => "/jobs",
+    }
+}
+
+/// Serializes the body as JSON with a public `Cache-Control` header.
+fn cached<T: Serialize>(max_age: u64, body: T) -> Response {
+    (
+        [(
+            header::CACHE_CONTROL,
+            format!("public, max-age={}", max_age),
+        )],
+        Json(body),
+    )
+        .into_response()
+}
+
+async fn get_item(
+    Path(id): Path<HackerNewsID>,
+    State(client): State<ProxyState>,
+) -> Result<Response, HackerNewsClientError> {
+    let item = client.items.get_item(id).await?;
+    Ok(cached(ITEM_MAX_AGE_SECONDS, item))
+}
+
+async fn get_thread(
+    Path(id): Path<HackerNewsID>,
+    State(client): State<ProxyState>,
+) -> Result<Response, HackerNewsClientError> {
+    let thread = client.items.get_thread(id).await?;
+    Ok(cached(ITEM_MAX_AGE_SECONDS, thread))
+}
+
+async fn get_user(
+    Path(name): Path<String>,
+    State(client): State<ProxyState>,
+) -> Result<Response, HackerNewsClientError> {
+    let user = client.users.get_user(&name).await?;
+    Ok(cached(USER_MAX_AGE_SECONDS, user))
+}
+
+async fn get_max_item(State(client): State<ProxyState>) -> Result<Response, HackerNewsClientError> {
+    let id = client.realtime.get_latest_item_id().await?;
+    Ok(cached(LIVE_MAX_AGE_SECONDS, id))
+}
+
+async fn get_updates(State(client): State<ProxyState>) -> Result<Response, HackerNewsClientError> {
+    let updates = client.realtime.get_recent_updates().await?;
+    Ok(cached(LIVE_MAX_AGE_SECONDS, updates))
+}
+
+async fn get_story_list(
+    client: ProxyState,
+    list: HackerNewsStoryList,
+) -> Result<Response, HackerNewsClientError> {
+    let ids = client.realtime.get_story_list(list).await?;
+    Ok(cached(LIVE_MAX_AGE_SECONDS, ids))
+}
+
+impl HackerNewsClientError {
+    /// The HTTP status best describing the error when proxying the API, i.e. `404` for missing items and users,
+    /// `504` for timed out requests and operations past their deadline, `503` for cancelled operations and `502` for
+    /// other failures reaching or interpreting the API. Items of an unexpected type, e.g. a story within a comment
+    /// tree, are the API returning data the proxy can't interpret rather than a missing resource, so map to `502`.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            HackerNewsClientError::ItemOrUserNotFound(_) => StatusCode::NOT_FOUND,
+            HackerNewsClientError::RequestError(error) if error.is_timeout() => {
+                StatusCode::GATEWAY_TIMEOUT
+            }
+            HackerNewsClientError::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+            HackerNewsClientError::Cancelled => StatusCode::SERVICE_UNAVAILABLE,
+            HackerNewsClientError::RequestError(_)
+            | HackerNewsClientError::InvalidTypeMapping(_)
+            | HackerNewsClientError::AssociatedParentNotFound(_)
+            | HackerNewsClientError::ImplicitConversionError(_)
+            | HackerNewsClientError::InvalidIdentifier(_)
+            | HackerNewsClientError::TransportError(_) => StatusCode::BAD_GATEWAY,
+            HackerNewsClientError::ExportError(_)
+            | HackerNewsClientError::WebhookError(_)
+            | HackerNewsClientError::InfallibleOperation => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Responds with the error's status code and a JSON body containing its message, e.g. `{"error": "..."}`. Server
+/// errors respond with the status's reason instead, as their messages may contain upstream URLs and connection
+/// details, and are logged in full.
+impl IntoResponse for HackerNewsClientError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+
+        let message = if status.is_server_error() {
+            tracing::error!(error = %self, status = status.as_u16(), "failed to proxy the Hacker News API");
+            status
+                .canonical_reason()
+                .unwrap_or("Internal Server Error")
+                .to_string()
+        } else {
+            self.to_string()
+        };
+
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
@@ -1,92 +1,133 @@
 //! Realtime client API for interacting with live data endpoints of Hacker News.
 
-use crate::{errors::HackerNewsResult, http::InternalHttpClient, HackerNewsID};
+use crate::{
+    client::RequestOptions,
+    errors::HackerNewsResult,
+    http::InternalHttpClient,
+    items::stories::{HackerNewsStory, StoryKind},
+    versions::ApiResource,
+    HackerNewsID,
+};
 
-use super::{HackerNewsItemList, HackerNewsUpdatedItems};
+use super::{HackerNewsItemList, HackerNewsStoryList, HackerNewsUpdatedItems};
 
 /// An internal client for interacting with the realtime data endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HackerNewsRealtimeClient {
     internal_client: InternalHttpClient,
 }
 
-/// Endpoint for the latest item ID.
-const MAX_ITEM_ENDPOINT: &str = "maxitem";
-
-/// Endpoints for stories.
-const TOP_STORIES_ENDPOINT: &str = "topstories";
-const NEW_STORIES_ENDPOINT: &str = "newstories";
-const BEST_STORIES_ENDPOINT: &str = "beststories";
-
-/// Endpoints for jobs, polls, and asks.
-const ASK_STORIES_ENDPOINT: &str = "askstories";
-const SHOW_STORIES_ENDPOINT: &str = "showstories";
-const JOB_STORIES_ENDPOINT: &str = "jobstories";
-
-/// Endpoint for updated items and profiles.
-const UPDATES_ENDPOINT: &str = "updates";
-
 impl HackerNewsRealtimeClient {
     /// Constructs a new instance of the realtime client from the root HTTP client.
     pub fn new(internal_client: InternalHttpClient) -> Self {
         Self { internal_client }
     }
 
-    async fn get_realtime_story_data(
-        &self,
-        endpoint: &str,
-    ) -> HackerNewsResult<HackerNewsItemList> {
-        let stories = self.internal_client.get_item(endpoint).await?;
-        Ok(stories)
-    }
-
     /// Retrieves the latest item ID to be created, referred to by Hacker News as the max item ID.
     pub async fn get_latest_item_id(&self) -> HackerNewsResult<HackerNewsID> {
-        let item_id = self.internal_client.get_item(MAX_ITEM_ENDPOINT).await?;
+        self.get_latest_item_id_with(RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves the latest item ID to be created with the request options applied.
+    pub async fn get_latest_item_id_with(
+        &self,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsID> {
+        let item_id = self
+            .internal_client
+            .get(ApiResource::MaxItem, &options)
+            .await?;
         Ok(item_id)
     }
 
     /// Retrieves the top 500 stories and jobs.
     pub async fn get_top_stories(&self) -> HackerNewsResult<HackerNewsItemList> {
-        self.get_realtime_story_data(TOP_STORIES_ENDPOINT).await
+        self.get_story_list(HackerNewsStoryList::Top).await
     }
 
     /// Retrieves the latest 500 stories.
     pub async fn get_latest_stories(&self) -> HackerNewsResult<HackerNewsItemList> {
-        self.get_realtime_story_data(NEW_STORIES_ENDPOINT).await
+        self.get_story_list(HackerNewsStoryList::New).await
     }
 
     /// Retrieves the best 500 stories.
     pub async fn get_best_stories(&self) -> HackerNewsResult<HackerNewsItemList> {
-        self.get_realtime_story_data(BEST_STORIES_ENDPOINT).await
+        self.get_story_list(HackerNewsStoryList::Best).await
     }
 
     /// Retrieves up to 200 of the latest Ask Hacker News stories.
     pub async fn get_ask_hacker_news_stories(&self) -> HackerNewsResult<HackerNewsItemList> {
-        self.get_realtime_story_data(ASK_STORIES_ENDPOINT).await
+        self.get_story_list(HackerNewsStoryList::Ask).await
     }
 
     /// Retrieves up to 200 of the latest Show Hacker News stories.
     pub async fn get_show_hacker_news_stories(&self) -> HackerNewsResult<HackerNewsItemList> {
-        self.get_realtime_story_data(SHOW_STORIES_ENDPOINT).await
+        self.get_story_list(HackerNewsStoryList::Show).await
     }
 
     /// Retrieves up to 200 of the latest job stories.
     pub async fn get_job_hacker_news_stories(&self) -> HackerNewsResult<HackerNewsItemList> {
-        self.get_realtime_story_data(JOB_STORIES_ENDPOINT).await
+        self.get_story_list(HackerNewsStoryList::Jobs).await
+    }
+
+    /// Retrieves the IDs for any of the realtime story lists.
+    pub async fn get_story_list(
+        &self,
+        list: HackerNewsStoryList,
+    ) -> HackerNewsResult<HackerNewsItemList> {
+        self.get_story_list_with(list, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves the IDs for any of the realtime story lists with the request options applied.
+    pub async fn get_story_list_with(
+        &self,
+        list: HackerNewsStoryList,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsItemList> {
+        let stories = self
+            .internal_client
+            .get(ApiResource::StoryList(list), &options)
+            .await?;
+        Ok(stories)
+    }
+
+    /// Classifies the story using the current Ask HN and Show HN lists, falling back to its title prefix
+    /// and URL for stories in neither list.
+    pub async fn get_story_kind(&self, story: &HackerNewsStory) -> HackerNewsResult<StoryKind> {
+        let ask_stories = self.get_ask_hacker_news_stories().await?;
+        let show_stories = self.get_show_hacker_news_stories().await?;
+        Ok(story.kind_with_listings(&ask_stories, &show_stories))
+    }
+
+    /// Retrieves the most recently updated items and profiles in a single request.
+    pub async fn get_recent_updates(&self) -> HackerNewsResult<HackerNewsUpdatedItems> {
+        self.get_recent_updates_with(RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves the most recently updated items and profiles with the request options applied.
+    pub async fn get_recent_updates_with(
+        &self,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsUpdatedItems> {
+        let updated_items = self
+            .internal_client
+            .get(ApiResource::Updates, &options)
+            .await?;
+        Ok(updated_items)
     }
 
     /// Retrieves the most recently updated items.
     pub async fn get_recently_updated_items(&self) -> HackerNewsResult<HackerNewsItemList> {
-        let updated_items: HackerNewsUpdatedItems =
-            self.internal_client.get_item(UPDATES_ENDPOINT).await?;
+        let updated_items = self.get_recent_updates().await?;
         Ok(updated_items.items)
     }
 
-    /// Retrieves the most recently updated profiles>.
-    pub async fn get_recently_updated_profiles(&self) -> HackerNewsResult<HackerNewsItemList> {
-        let updated_items: HackerNewsUpdatedItems =
-            self.internal_client.get_item(UPDATES_ENDPOINT).await?;
+    /// Retrieves the usernames of the most recently updated profiles.
+    pub async fn get_recently_updated_profiles(&self) -> HackerNewsResult<Vec<String>> {
+        let updated_items = self.get_recent_updates().await?;
         Ok(updated_items.profiles)
     }
 }
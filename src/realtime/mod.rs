@@ -1,6 +1,8 @@
 //! Data associated to the live data API endpoints pertaining to top stories, latest items, recently updated users, etc.
 //! Live data endpoints will vary in terms of their content and provide realtime insights into data captured by Hacker News.
 
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
 use crate::HackerNewsID;
@@ -15,6 +17,41 @@ type HackerNewsItemList = Vec<HackerNewsID>;
 pub struct HackerNewsUpdatedItems {
     /// Represents recently updated item IDs.
     pub items: HackerNewsItemList,
-    /// Represents recently updated profile IDs.
-    pub profiles: HackerNewsItemList,
+    /// Represents recently updated profile usernames.
+    pub profiles: Vec<String>,
+}
+
+/// The realtime story lists, for requesting any of them by value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HackerNewsStoryList {
+    /// The current top stories and jobs.
+    Top,
+    /// The newest stories.
+    New,
+    /// The best stories.
+    Best,
+    /// The latest Ask HN stories.
+    Ask,
+    /// The latest Show HN stories.
+    Show,
+    /// The latest job stories.
+    Jobs,
+}
+
+impl HackerNewsStoryList {
+    /// All lists in the order Hacker News displays them.
+    pub const ALL: [HackerNewsStoryList; 6] = [
+        HackerNewsStoryList::Top,
+        HackerNewsStoryList::New,
+        HackerNewsStoryList::Best,
+        HackerNewsStoryList::Ask,
+        HackerNewsStoryList::Show,
+        HackerNewsStoryList::Jobs,
+    ];
+}
+
+impl Display for HackerNewsStoryList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
 }
@@ -0,0 +1,156 @@
+//! Rendering helpers for the HTML fragments returned by Hacker News. Item text, titles, and user about sections
+//! are returned as the raw markup Hacker News displays, containing paragraphs, italics, links, code blocks, and
+//! encoded entities. The helpers within convert those fragments into plain text or Markdown suitable for
+//! terminals, chat applications, and other non-HTML destinations, or sanitize them for embedding within web pages.
+//!
+//! ```
+//! use newswrap::text;
+//!
+//! let html = "It&#x27;s <i>finally</i> here.<p>See <a href=\"https://example.com\">the docs</a>.";
+//!
+//! assert_eq!(text::to_plain_text(html), "It's finally here.\n\nSee the docs (https://example.com).");
+//! assert_eq!(text::to_markdown(html), "It's *finally* here.\n\nSee [the docs](https://example.com).");
+//! ```
+
+mod render;
+mod sanitize;
+pub(crate) mod tokenizer;
+
+use serde::{Deserialize, Serialize};
+
+pub use self::sanitize::{sanitize, SanitizedHtml};
+pub(crate) use self::{
+    render::{escape_markdown, escape_markdown_href},
+    sanitize::{escape_html, sanitize_href},
+};
+
+use self::{
+    render::{render, RenderFormat},
+    tokenizer::{tokenize, Token},
+};
+
+/// The longest entity name, including its numeric prefix, considered for decoding.
+const MAX_ENTITY_LENGTH: usize = 10;
+
+/// A hyperlink found within a Hacker News HTML fragment.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HackerNewsLink {
+    /// The link's target URL, as found in the `href` attribute.
+    pub url: String,
+    /// The displayed text of the link. Hacker News truncates long URLs displayed as link text.
+    pub text: String,
+}
+
+/// Converts a Hacker News HTML fragment into plain text, separating paragraphs with blank lines
+/// and expanding links into their full URLs.
+pub fn to_plain_text(html: &str) -> String {
+    render(html, RenderFormat::Plain)
+}
+
+/// Converts a Hacker News HTML fragment into Markdown, preserving italics, links, and code blocks.
+pub fn to_markdown(html: &str) -> String {
+    render(html, RenderFormat::Markdown)
+}
+
+/// Extracts all links from a Hacker News HTML fragment in the order they appear.
+pub fn extract_links(html: &str) -> Vec<HackerNewsLink> {
+    let mut links = Vec::new();
+    let mut current_link: Option<HackerNewsLink> = None;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Start { ref name, .. } if name == "a" => {
+                if let Some(link) = current_link.take() {
+                    links.push(link);
+                }
+
+                current_link = token.attribute("href").map(|href| HackerNewsLink {
+                    url: href.to_string(),
+                    text: String::new(),
+                });
+            }
+            Token::End { name } if name == "a" => {
+                if let Some(link) = current_link.take() {
+                    links.push(link);
+                }
+            }
+            Token::Text(text) => {
+                if let Some(link) = current_link.as_mut() {
+                    link.text.push_str(&decode_entities(text));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(link) = current_link {
+        links.push(link);
+    }
+
+    links
+}
+
+/// Decodes named and numeric HTML entities, e.g. `&#x27;` and `&quot;`. Unrecognized entities are left as-is.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find('&') {
+        decoded.push_str(&remaining[..start]);
+        remaining = &remaining[start..];
+
+        let entity = remaining
+            .char_indices()
+            .take(MAX_ENTITY_LENGTH + 2)
+            .find(|(_, c)| *c == ';')
+            .and_then(|(end, _)| decode_entity(&remaining[1..end]).map(|c| (end, c)));
+
+        match entity {
+            Some((end, character)) => {
+                decoded.push(character);
+                remaining = &remaining[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                remaining = &remaining[1..];
+            }
+        }
+    }
+
+    decoded.push_str(remaining);
+    decoded
+}
+
+/// Decodes a single entity name without its surrounding `&` and `;`.
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(numeric) = entity.strip_prefix('#') {
+        let code_point = match numeric.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => numeric.parse::<u32>().ok()?,
+        };
+
+        return char::from_u32(code_point);
+    }
+
+    let character = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        _ => return None,
+    };
+
+    Some(character)
+}
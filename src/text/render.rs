@@ -0,0 +1,227 @@
+//! Plain text and Markdown renderers over tokenized Hacker News HTML.
+
+use super::{
+    decode_entities,
+    tokenizer::{tokenize, Token},
+};
+
+/// Characters escaped when emitting Markdown text so they render literally, including those that would otherwise
+/// open raw HTML or entities, e.g. a decoded `&lt;script&gt;` within a comment.
+const MARKDOWN_ESCAPED_CHARACTERS: [char; 9] = ['\\', '*', '_', '`', '[', ']', '<', '>', '&'];
+
+/// The output format of a rendered fragment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenderFormat {
+    /// Plain text with links expanded inline.
+    Plain,
+    /// CommonMark compatible Markdown.
+    Markdown,
+}
+
+/// Renders the HTML fragment into the requested format.
+pub(crate) fn render(html: &str, format: RenderFormat) -> String {
+    let mut output = String::new();
+    let mut link: Option<(String, String)> = None;
+    let mut code_block: Option<String> = None;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => {
+                let decoded = decode_entities(text);
+
+                if let Some(code) = code_block.as_mut() {
+                    code.push_str(&decoded);
+                } else if let Some((_, link_text)) = link.as_mut() {
+                    link_text.push_str(&decoded);
+                } else {
+                    push_text(&mut output, &decoded, format);
+                }
+            }
+            Token::Start { ref name, .. } => match name.as_str() {
+                "p" if code_block.is_none() => paragraph_break(&mut output),
+                "br" => match code_block.as_mut() {
+                    Some(code) => code.push('\n'),
+                    None => output.push('\n'),
+                },
+                "pre" => {
+                    paragraph_break(&mut output);
+                    code_block = Some(String::new());
+                }
+                "a" if code_block.is_none() => {
+                    if let Some((href, text)) = link.take() {
+                        push_link(&mut output, &href, &text, format);
+                    }
+
+                    link = token
+                        .attribute("href")
+                        .map(|href| (href.to_string(), String::new()));
+                }
+                _ if code_block.is_none() && link.is_none() => {
+                    push_marker(&mut output, name, format)
+                }
+                _ => {}
+            },
+            Token::End { ref name } => match name.as_str() {
+                "pre" => {
+                    if let Some(code) = code_block.take() {
+                        push_code_block(&mut output, &code, format);
+                    }
+                }
+                "a" => {
+                    if let Some((href, text)) = link.take() {
+                        push_link(&mut output, &href, &text, format);
+                    }
+                }
+                _ if code_block.is_none() && link.is_none() => {
+                    push_marker(&mut output, name, format)
+                }
+                _ => {}
+            },
+        }
+    }
+
+    if let Some((href, text)) = link {
+        push_link(&mut output, &href, &text, format);
+    }
+
+    if let Some(code) = code_block {
+        push_code_block(&mut output, &code, format);
+    }
+
+    output.trim().to_string()
+}
+
+/// Separates paragraphs by a single blank line, collapsing consecutive breaks.
+fn paragraph_break(output: &mut String) {
+    let trimmed_length = output.trim_end().len();
+    output.truncate(trimmed_length);
+
+    if !output.is_empty() {
+        output.push_str("\n\n");
+    }
+}
+
+fn push_text(output: &mut String, text: &str, format: RenderFormat) {
+    match format {
+        RenderFormat::Plain => output.push_str(text),
+        RenderFormat::Markdown => output.push_str(&escape_markdown(text)),
+    }
+}
+
+/// Emits inline formatting markers for emphasis and inline code. Plain text drops formatting entirely.
+fn push_marker(output: &mut String, tag: &str, format: RenderFormat) {
+    if format == RenderFormat::Plain {
+        return;
+    }
+
+    let marker = match tag {
+        "i" | "em" => "*",
+        "b" | "strong" => "**",
+        "code" => "`",
+        _ => return,
+    };
+
+    output.push_str(marker);
+}
+
+fn push_link(output: &mut String, href: &str, text: &str, format: RenderFormat) {
+    let text = text.trim();
+    let displays_url = text.is_empty() || is_displayed_url(text, href);
+
+    match format {
+        RenderFormat::Plain if displays_url => output.push_str(href),
+        RenderFormat::Plain => output.push_str(&format!("{} ({})", text, href)),
+        RenderFormat::Markdown if displays_url => {
+            output.push_str(&format!("<{}>", escape_markdown_href(href)))
+        }
+        RenderFormat::Markdown => output.push_str(&format!(
+            "[{}]({})",
+            escape_markdown(text),
+            escape_markdown_href(href)
+        )),
+    }
+}
+
+fn push_code_block(output: &mut String, code: &str, format: RenderFormat) {
+    let code = dedent(code);
+
+    match format {
+        RenderFormat::Plain => output.push_str(&code),
+        RenderFormat::Markdown => output.push_str(&format!("```\n{}\n```", code)),
+    }
+
+    output.push_str("\n\n");
+}
+
+/// Determines if the link text is the URL itself, or the URL truncated by Hacker News with a trailing ellipsis.
+fn is_displayed_url(text: &str, href: &str) -> bool {
+    text == href
+        || text
+            .strip_suffix("...")
+            .is_some_and(|prefix| href.starts_with(prefix))
+}
+
+/// Removes the common leading indentation Hacker News users add to mark code blocks, along with surrounding blank lines.
+fn dedent(code: &str) -> String {
+    let lines: Vec<&str> = code
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .collect();
+
+    let last_line = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let lines = &lines[..last_line];
+
+    let indentation = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| line.get(indentation..).unwrap_or_default().trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Percent-encodes characters that would end a Markdown link destination or autolink early, i.e. angle brackets,
+/// parentheses and whitespace.
+pub(crate) fn escape_markdown_href(href: &str) -> String {
+    let mut escaped = String::with_capacity(href.len());
+
+    for character in href.chars() {
+        match character {
+            '<' | '>' | '(' | ')' => escaped.push_str(&format!("%{:02X}", character as u32)),
+            character if character.is_whitespace() => {
+                let mut buffer = [0; 4];
+
+                for byte in character.encode_utf8(&mut buffer).bytes() {
+                    escaped.push_str(&format!("%{:02X}", byte));
+                }
+            }
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Escapes characters that would otherwise be interpreted as Markdown or raw HTML.
+pub(crate) fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        if MARKDOWN_ESCAPED_CHARACTERS.contains(&character) {
+            escaped.push('\\');
+        }
+
+        escaped.push(character);
+    }
+
+    escaped
+}
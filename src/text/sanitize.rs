@@ -0,0 +1,168 @@
+//! HTML sanitization for safely re-rendering Hacker News content within other web applications.
+
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use super::{
+    decode_entities,
+    tokenizer::{tokenize, Token},
+};
+
+/// Tags Hacker News emits in item text and user about sections, and the only tags retained when sanitizing.
+const ALLOWED_TAGS: [&str; 5] = ["p", "i", "a", "pre", "code"];
+
+/// Tags whose content is dropped entirely along with the tag itself.
+const DROPPED_CONTENT_TAGS: [&str; 9] = [
+    "script", "style", "iframe", "object", "embed", "template", "noscript", "textarea", "title",
+];
+
+/// URL schemes allowed on sanitized links. Relative links, e.g. `item?id=1`, are also allowed.
+const ALLOWED_SCHEMES: [&str; 2] = ["http", "https"];
+
+/// The `rel` attribute applied to all sanitized links.
+const LINK_REL: &str = "nofollow noopener";
+
+/// HTML that has been sanitized for re-rendering, containing only the tag set Hacker News emits with all
+/// text escaped and all links rewritten with `rel="nofollow noopener"`. Instances can only be created by
+/// sanitizing markup, so holding a value is proof the content is safe to embed.
+///
+/// ```
+/// use newswrap::text;
+///
+/// let sanitized = text::sanitize("<i>Hi</i><script>alert(1)</script> <a href=\"javascript:alert(1)\">there</a>");
+///
+/// assert_eq!(sanitized.as_str(), "<i>Hi</i> there");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct SanitizedHtml(String);
+
+impl SanitizedHtml {
+    /// Returns the sanitized markup.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the sanitized markup, returning the underlying string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for SanitizedHtml {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for SanitizedHtml {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<SanitizedHtml> for String {
+    fn from(value: SanitizedHtml) -> Self {
+        value.0
+    }
+}
+
+/// Sanitizes a Hacker News HTML fragment, retaining only the tags Hacker News emits. Disallowed tags are stripped
+/// while keeping their text, except for tags like `<script>` whose content is dropped entirely. Links keep only an
+/// `http`, `https`, or relative `href` and are otherwise unwrapped into their text. Unclosed tags are closed at the
+/// end of the fragment.
+pub fn sanitize(html: &str) -> SanitizedHtml {
+    let mut output = String::with_capacity(html.len());
+    let mut open_tags: Vec<String> = Vec::new();
+    let mut dropped_content_tag: Option<String> = None;
+
+    for token in tokenize(html) {
+        if let Some(dropped) = dropped_content_tag.as_deref() {
+            if matches!(&token, Token::End { name } if name == dropped) {
+                dropped_content_tag = None;
+            }
+
+            continue;
+        }
+
+        match token {
+            Token::Text(text) => output.push_str(&escape_html(&decode_entities(text))),
+            Token::Start { ref name, .. } if DROPPED_CONTENT_TAGS.contains(&name.as_str()) => {
+                dropped_content_tag = Some(name.clone());
+            }
+            Token::Start { ref name, .. } if name == "p" => output.push_str("<p>"),
+            Token::Start { ref name, .. } if name == "a" => {
+                if let Some(href) = token.attribute("href").and_then(sanitize_href) {
+                    output.push_str(&format!(
+                        "<a href=\"{}\" rel=\"{}\">",
+                        escape_html(&href),
+                        LINK_REL
+                    ));
+                    open_tags.push(name.clone());
+                }
+            }
+            Token::Start { name, .. } if ALLOWED_TAGS.contains(&name.as_str()) => {
+                output.push_str(&format!("<{}>", name));
+                open_tags.push(name);
+            }
+            Token::End { name } => {
+                if let Some(position) = open_tags.iter().rposition(|open| *open == name) {
+                    for open in open_tags.drain(position..).rev() {
+                        output.push_str(&format!("</{}>", open));
+                    }
+                }
+            }
+            Token::Start { .. } => {}
+        }
+    }
+
+    for open in open_tags.into_iter().rev() {
+        output.push_str(&format!("</{}>", open));
+    }
+
+    SanitizedHtml(output)
+}
+
+/// Validates a link target, allowing only relative links and those with an allowed scheme.
+pub(crate) fn sanitize_href(href: &str) -> Option<String> {
+    let href: String = href
+        .trim()
+        .chars()
+        .filter(|c| !c.is_control() && !c.is_whitespace())
+        .collect();
+
+    if href.is_empty() {
+        return None;
+    }
+
+    let scheme_end = href.find(':');
+    let path_start = href.find(['/', '?', '#']);
+
+    match (scheme_end, path_start) {
+        (Some(scheme_end), Some(path_start)) if path_start < scheme_end => Some(href),
+        (Some(scheme_end), _) => {
+            let scheme = href[..scheme_end].to_ascii_lowercase();
+            ALLOWED_SCHEMES.contains(&scheme.as_str()).then_some(href)
+        }
+        (None, _) => Some(href),
+    }
+}
+
+/// Escapes text for inclusion in element content and quoted attribute values.
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
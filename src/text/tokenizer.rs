@@ -0,0 +1,189 @@
+//! A minimal HTML tokenizer for the subset of markup Hacker News emits in item and user fields.
+
+use super::decode_entities;
+
+/// A single unit of markup within a Hacker News HTML fragment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// Raw text between tags, still containing any encoded entities.
+    Text(&'a str),
+    /// An opening tag with its lowercased name and entity-decoded attributes.
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+    },
+    /// A closing tag with its lowercased name.
+    End { name: String },
+}
+
+impl<'a> Token<'a> {
+    /// Retrieves an attribute value on an opening tag, matching on the lowercased attribute name.
+    pub(crate) fn attribute(&self, attribute: &str) -> Option<&str> {
+        match self {
+            Token::Start { attributes, .. } => attributes
+                .iter()
+                .find(|(name, _)| name == attribute)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the HTML fragment into text and tags. Malformed tags are treated as text rather than dropped,
+/// and comments and doctype declarations are skipped entirely.
+pub(crate) fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut position = 0;
+    let bytes = html.as_bytes();
+
+    while position < bytes.len() {
+        if bytes[position] != b'<' {
+            position += 1;
+            continue;
+        }
+
+        let remaining = &html[position..];
+
+        let (consumed, token) = if remaining.starts_with("<!--") {
+            match remaining.find("-->") {
+                Some(end) => (end + 3, None),
+                None => (remaining.len(), None),
+            }
+        } else if remaining.starts_with("<!") || remaining.starts_with("<?") {
+            match remaining.find('>') {
+                Some(end) => (end + 1, None),
+                None => (remaining.len(), None),
+            }
+        } else {
+            match parse_tag(remaining) {
+                Some((consumed, token)) => (consumed, Some(token)),
+                None => {
+                    position += 1;
+                    continue;
+                }
+            }
+        };
+
+        if text_start < position {
+            tokens.push(Token::Text(&html[text_start..position]));
+        }
+
+        if let Some(token) = token {
+            tokens.push(token);
+        }
+
+        position += consumed;
+        text_start = position;
+    }
+
+    if text_start < html.len() {
+        tokens.push(Token::Text(&html[text_start..]));
+    }
+
+    tokens
+}
+
+/// Parses a tag at the start of the input, returning the number of bytes consumed and the token.
+fn parse_tag(input: &str) -> Option<(usize, Token<'static>)> {
+    let mut chars = input.char_indices().skip(1).peekable();
+
+    let closing = matches!(chars.peek(), Some((_, '/')));
+    if closing {
+        chars.next();
+    }
+
+    let name_start = chars.peek()?.0;
+    if !chars.peek()?.1.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let mut name_end = name_start;
+    while let Some((index, character)) = chars.peek() {
+        if character.is_ascii_alphanumeric() {
+            name_end = index + character.len_utf8();
+            chars.next();
+        } else {
+            break;
+        }
+    }
+
+    let name = input[name_start..name_end].to_ascii_lowercase();
+    let mut attributes = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut tag_end = None;
+
+    let attributes_start = name_end;
+    for (index, character) in chars {
+        match quote {
+            Some(open) if character == open => quote = None,
+            Some(_) => {}
+            None if character == '"' || character == '\'' => quote = Some(character),
+            None if character == '>' => {
+                tag_end = Some(index);
+                break;
+            }
+            None => {}
+        }
+    }
+
+    let tag_end = tag_end?;
+
+    if closing {
+        return Some((tag_end + 1, Token::End { name }));
+    }
+
+    parse_attributes(&input[attributes_start..tag_end], &mut attributes);
+
+    Some((tag_end + 1, Token::Start { name, attributes }))
+}
+
+/// Parses `name="value"`, `name='value'`, `name=value`, and bare `name` attributes.
+fn parse_attributes(input: &str, attributes: &mut Vec<(String, String)>) {
+    let mut remaining = input.trim_start();
+
+    while !remaining.is_empty() {
+        let name_end = remaining
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(remaining.len());
+
+        if name_end == 0 {
+            remaining = remaining[1..].trim_start();
+            continue;
+        }
+
+        let name = remaining[..name_end].to_ascii_lowercase();
+        remaining = remaining[name_end..].trim_start();
+
+        let value = if let Some(after_equals) = remaining.strip_prefix('=') {
+            let after_equals = after_equals.trim_start();
+
+            match after_equals.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let value_end = after_equals[1..]
+                        .find(quote)
+                        .map(|end| end + 1)
+                        .unwrap_or(after_equals.len());
+                    let value = &after_equals[1..value_end];
+                    remaining = after_equals
+                        .get(value_end + 1..)
+                        .unwrap_or_default()
+                        .trim_start();
+                    value
+                }
+                _ => {
+                    let value_end = after_equals
+                        .find(char::is_whitespace)
+                        .unwrap_or(after_equals.len());
+                    let value = &after_equals[..value_end];
+                    remaining = after_equals[value_end..].trim_start();
+                    value
+                }
+            }
+        } else {
+            ""
+        };
+
+        attributes.push((name, decode_entities(value)));
+    }
+}
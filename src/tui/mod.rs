@@ -0,0 +1,348 @@
+//! An interactive terminal reader for browsing the realtime story lists and their comment threads,
+//! available behind the `tui` feature. The [`App`] holds all reader state and is driven by key events,
+//! allowing it to be exercised without a terminal, while [`run`] wires it up to the user's terminal.
+//!
+//! ```no_run
+//! use newswrap::{client::HackerNewsClient, tui};
+//!
+//! #[tokio::main]
+//! async fn main() -> std::io::Result<()> {
+//!     tui::run(HackerNewsClient::new()).await
+//! }
+//! ```
+
+mod view;
+
+use std::{collections::HashSet, io};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    Frame,
+};
+
+use crate::{
+    client::HackerNewsClient,
+    errors::HackerNewsResult,
+    items::{
+        threads::{HackerNewsCommentNode, HackerNewsThread},
+        HackerNewsItem,
+    },
+    realtime::HackerNewsStoryList,
+    HackerNewsID,
+};
+
+/// Default number of stories loaded for each list.
+const DEFAULT_STORY_LIMIT: usize = 30;
+
+/// Number of rows moved when paging through lists and threads.
+const PAGE_SIZE: usize = 10;
+
+/// The position of the list within the tabs.
+fn list_position(list: HackerNewsStoryList) -> usize {
+    HackerNewsStoryList::ALL
+        .iter()
+        .position(|candidate| *candidate == list)
+        .unwrap_or_default()
+}
+
+fn next_list(list: HackerNewsStoryList) -> HackerNewsStoryList {
+    let count = HackerNewsStoryList::ALL.len();
+    HackerNewsStoryList::ALL[(list_position(list) + 1) % count]
+}
+
+fn previous_list(list: HackerNewsStoryList) -> HackerNewsStoryList {
+    let count = HackerNewsStoryList::ALL.len();
+    HackerNewsStoryList::ALL[(list_position(list) + count - 1) % count]
+}
+
+/// An open comment thread along with its collapsed comments and selection.
+#[derive(Debug)]
+pub struct ThreadView {
+    thread: HackerNewsThread,
+    collapsed: HashSet<HackerNewsID>,
+    selected: usize,
+}
+
+impl ThreadView {
+    fn new(thread: HackerNewsThread) -> Self {
+        Self {
+            thread,
+            collapsed: HashSet::new(),
+            selected: 0,
+        }
+    }
+
+    /// The thread being viewed.
+    pub fn thread(&self) -> &HackerNewsThread {
+        &self.thread
+    }
+
+    /// The index of the selected comment within the visible comments.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Determines if the comment's replies are hidden.
+    pub fn is_collapsed(&self, id: HackerNewsID) -> bool {
+        self.collapsed.contains(&id)
+    }
+
+    /// Lists the comments currently visible in display order along with their depth,
+    /// omitting the replies of collapsed comments.
+    pub fn visible_comments(&self) -> Vec<(usize, &HackerNewsCommentNode)> {
+        let mut visible = Vec::new();
+        let mut stack: Vec<(usize, &HackerNewsCommentNode)> = self
+            .thread
+            .comments
+            .iter()
+            .rev()
+            .map(|node| (0, node))
+            .collect();
+
+        while let Some((depth, node)) = stack.pop() {
+            visible.push((depth, node));
+
+            if !self.is_collapsed(node.comment.id) {
+                stack.extend(node.replies.iter().rev().map(|reply| (depth + 1, reply)));
+            }
+        }
+
+        visible
+    }
+
+    fn toggle_selected(&mut self) {
+        let selected = self
+            .visible_comments()
+            .get(self.selected)
+            .filter(|(_, node)| !node.replies.is_empty())
+            .map(|(_, node)| node.comment.id);
+
+        if let Some(id) = selected {
+            if !self.collapsed.remove(&id) {
+                self.collapsed.insert(id);
+            }
+        }
+    }
+}
+
+/// The screen currently displayed by the reader.
+#[derive(Debug)]
+pub enum Screen {
+    /// The list of stories for the selected realtime list.
+    Stories,
+    /// A story's comment thread.
+    Thread(Box<ThreadView>),
+}
+
+/// Reader state for browsing stories and threads, driven by key events.
+#[derive(Debug)]
+pub struct App {
+    client: HackerNewsClient,
+    list: HackerNewsStoryList,
+    stories: Vec<HackerNewsItem>,
+    selected: usize,
+    screen: Screen,
+    status: Option<String>,
+    limit: usize,
+    should_quit: bool,
+}
+
+impl App {
+    /// Constructs a new reader using the client for all requests, starting on the top stories.
+    pub fn new(client: HackerNewsClient) -> Self {
+        Self {
+            client,
+            list: HackerNewsStoryList::Top,
+            stories: Vec::new(),
+            selected: 0,
+            screen: Screen::Stories,
+            status: None,
+            limit: DEFAULT_STORY_LIMIT,
+            should_quit: false,
+        }
+    }
+
+    /// Configures the number of stories loaded for each list.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// The realtime list currently being browsed.
+    pub fn list(&self) -> HackerNewsStoryList {
+        self.list
+    }
+
+    /// The stories loaded for the current list.
+    pub fn stories(&self) -> &[HackerNewsItem] {
+        &self.stories
+    }
+
+    /// The currently selected story.
+    pub fn selected_story(&self) -> Option<&HackerNewsItem> {
+        self.stories.get(self.selected)
+    }
+
+    /// The screen currently displayed.
+    pub fn screen(&self) -> &Screen {
+        &self.screen
+    }
+
+    /// The latest status message, e.g. errors from failed requests.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Determines if the user has asked to quit.
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    /// Reloads the stories for the current list, reporting failures in the status message.
+    pub async fn refresh(&mut self) {
+        let result = self.load_stories().await;
+        self.report(result);
+    }
+
+    /// Handles a key press, loading stories or threads as needed.
+    pub async fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        if key.code == KeyCode::Char('q')
+            || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL))
+        {
+            self.should_quit = true;
+            return;
+        }
+
+        match &mut self.screen {
+            Screen::Stories => self.handle_stories_key(key.code).await,
+            Screen::Thread(view) => {
+                let visible = view.visible_comments().len();
+
+                match key.code {
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        view.selected = (view.selected + 1).min(visible.saturating_sub(1));
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        view.selected = view.selected.saturating_sub(1);
+                    }
+                    KeyCode::PageDown => {
+                        view.selected = (view.selected + PAGE_SIZE).min(visible.saturating_sub(1));
+                    }
+                    KeyCode::PageUp => view.selected = view.selected.saturating_sub(PAGE_SIZE),
+                    KeyCode::Enter | KeyCode::Char(' ') => view.toggle_selected(),
+                    KeyCode::Esc | KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => {
+                        self.screen = Screen::Stories;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Renders the current screen into the frame.
+    pub fn render(&self, frame: &mut Frame) {
+        view::render(self, frame);
+    }
+
+    async fn handle_stories_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.stories.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::PageDown => {
+                self.selected =
+                    (self.selected + PAGE_SIZE).min(self.stories.len().saturating_sub(1));
+            }
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(PAGE_SIZE),
+            KeyCode::Tab | KeyCode::Char('l') => {
+                self.list = next_list(self.list);
+                self.refresh().await;
+            }
+            KeyCode::BackTab => {
+                self.list = previous_list(self.list);
+                self.refresh().await;
+            }
+            KeyCode::Char(digit @ '1'..='6') => {
+                let position = digit as usize - '1' as usize;
+                self.list = HackerNewsStoryList::ALL[position];
+                self.refresh().await;
+            }
+            KeyCode::Char('r') => self.refresh().await,
+            KeyCode::Enter | KeyCode::Right => {
+                if let Some(id) = self.selected_story().map(|story| story.id) {
+                    let result = self.client.items.get_thread(id).await;
+
+                    if let Some(thread) = self.report(result) {
+                        self.screen = Screen::Thread(Box::new(ThreadView::new(thread)));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    async fn load_stories(&mut self) -> HackerNewsResult<()> {
+        let ids = self.client.realtime.get_story_list(self.list).await?;
+        let ids = &ids[..self.limit.min(ids.len())];
+
+        self.stories = self.client.items.get_existing_items(ids).await?;
+        self.selected = 0;
+        self.screen = Screen::Stories;
+
+        Ok(())
+    }
+
+    /// Records the outcome of a request in the status message, returning the successful value.
+    fn report<T>(&mut self, result: HackerNewsResult<T>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.status = None;
+                Some(value)
+            }
+            Err(error) => {
+                self.status = Some(error.to_string());
+                None
+            }
+        }
+    }
+}
+
+/// Waits for the next terminal event on the blocking thread pool, keeping the runtime's workers free.
+async fn read_event() -> io::Result<Event> {
+    tokio::task::spawn_blocking(event::read)
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// Runs the reader in the current terminal until the user quits, restoring the terminal afterwards.
+pub async fn run(client: HackerNewsClient) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let mut app = App::new(client);
+
+    terminal.draw(|frame| app.render(frame))?;
+    app.refresh().await;
+
+    let result = loop {
+        if let Err(error) = terminal.draw(|frame| app.render(frame)) {
+            break Err(error);
+        }
+
+        match read_event().await {
+            Ok(Event::Key(key)) => app.handle_key(key).await,
+            Ok(_) => {}
+            Err(error) => break Err(error),
+        }
+
+        if app.should_quit() {
+            break Ok(());
+        }
+    };
+
+    ratatui::restore();
+    result
+}
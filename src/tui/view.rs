@@ -0,0 +1,209 @@
+//! Rendering of the reader's story lists and comment threads.
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
+    Frame,
+};
+
+use crate::{
+    items::{threads::HackerNewsCommentNode, HackerNewsItem},
+    realtime::HackerNewsStoryList,
+    tui::{list_position, App, Screen, ThreadView},
+};
+
+/// Indentation applied per level of replies.
+const THREAD_INDENT: usize = 2;
+
+/// Minimum width reserved for comment text regardless of nesting depth.
+const MIN_TEXT_WIDTH: usize = 20;
+
+const STORIES_HELP: &str = "j/k move  enter open  tab/1-6 switch list  r reload  q quit";
+
+const THREAD_HELP: &str = "j/k move  space collapse  esc back  q quit";
+
+pub(super) fn render(app: &App, frame: &mut Frame) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    render_tabs(app, frame, header);
+
+    match &app.screen {
+        Screen::Stories => render_stories(app, frame, body),
+        Screen::Thread(view) => render_thread(view, frame, body),
+    }
+
+    let footer_text = match (&app.status, &app.screen) {
+        (Some(status), _) => Line::from(status.as_str().red()),
+        (None, Screen::Stories) => Line::from(STORIES_HELP.dim()),
+        (None, Screen::Thread(_)) => Line::from(THREAD_HELP.dim()),
+    };
+
+    frame.render_widget(Paragraph::new(footer_text), footer);
+}
+
+fn render_tabs(app: &App, frame: &mut Frame, area: Rect) {
+    let titles = HackerNewsStoryList::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, list)| format!("{} {}", index + 1, list));
+
+    let tabs = Tabs::new(titles)
+        .select(list_position(app.list))
+        .highlight_style(Style::new().add_modifier(Modifier::BOLD | Modifier::REVERSED));
+
+    frame.render_widget(tabs, area);
+}
+
+fn render_stories(app: &App, frame: &mut Frame, area: Rect) {
+    let items: Vec<ListItem> = app
+        .stories
+        .iter()
+        .enumerate()
+        .map(|(rank, story)| story_item(rank, story))
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::new().borders(Borders::TOP))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+    let mut state = ListState::default().with_selected(Some(app.selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn story_item(rank: usize, story: &HackerNewsItem) -> ListItem<'static> {
+    let mut title = vec![
+        Span::raw(format!("{:>3}. ", rank + 1)),
+        Span::raw(story.title_plain().unwrap_or_default()).bold(),
+    ];
+
+    if let Some(site) = story.site() {
+        title.push(Span::raw(format!(" ({})", site)).dim());
+    }
+
+    let details = format!(
+        "     {} points by {} | {} comments",
+        story.score.unwrap_or_default(),
+        story.by.as_deref().unwrap_or_default(),
+        story.descendants.unwrap_or_default()
+    );
+
+    ListItem::new(vec![Line::from(title), Line::from(details.dim())])
+}
+
+fn render_thread(view: &ThreadView, frame: &mut Frame, area: Rect) {
+    let root = &view.thread.root;
+    let width = usize::from(area.width);
+    let mut lines = vec![Line::from(root.title_plain().unwrap_or_default().bold())];
+
+    lines.push(Line::from(
+        format!(
+            "{} points by {} | {} comments",
+            root.score.unwrap_or_default(),
+            root.by.as_deref().unwrap_or_default(),
+            root.descendants.unwrap_or_default()
+        )
+        .dim(),
+    ));
+
+    if let Some(text) = root.text_plain().filter(|text| !text.is_empty()) {
+        lines.push(Line::default());
+        lines.extend(wrap(&text, width).into_iter().map(Line::from));
+    }
+
+    let mut selected_offset = 0;
+
+    for (index, (depth, node)) in view.visible_comments().into_iter().enumerate() {
+        let indent = " ".repeat(depth * THREAD_INDENT);
+        let text_width = width.saturating_sub(indent.len()).max(MIN_TEXT_WIDTH);
+        let selected = index == view.selected;
+
+        lines.push(Line::default());
+
+        if selected {
+            selected_offset = lines.len();
+        }
+
+        let header = Line::from(vec![
+            Span::raw(indent.clone()),
+            Span::raw(comment_header(view, node)).dim(),
+        ]);
+
+        lines.push(if selected { header.reversed() } else { header });
+
+        if view.is_collapsed(node.comment.id) {
+            continue;
+        }
+
+        for line in wrap(&comment_text(node), text_width) {
+            lines.push(Line::from(format!("{}{}", indent, line)));
+        }
+    }
+
+    // keep the selected comment's header within view
+    let height = usize::from(area.height.saturating_sub(1));
+    let scroll = selected_offset.saturating_sub(height / 2);
+
+    let thread = Paragraph::new(lines)
+        .block(Block::new().borders(Borders::TOP))
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0));
+
+    frame.render_widget(thread, area);
+}
+
+fn comment_header(view: &ThreadView, node: &HackerNewsCommentNode) -> String {
+    let author = if node.comment.deleted {
+        "[deleted]"
+    } else {
+        node.comment.by.as_str()
+    };
+
+    if view.is_collapsed(node.comment.id) {
+        format!("[+] {} ({} hidden)", author, node.reply_count())
+    } else {
+        format!("[-] {}", author)
+    }
+}
+
+fn comment_text(node: &HackerNewsCommentNode) -> String {
+    if node.comment.deleted {
+        "[deleted]".to_string()
+    } else if node.comment.dead {
+        "[dead]".to_string()
+    } else {
+        node.comment.text_plain()
+    }
+}
+
+/// Wraps text on word boundaries to fit within the width, preserving paragraph breaks.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            let length = line.chars().count();
+
+            if length > 0 && length + 1 + word.chars().count() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+
+            line.push_str(word);
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
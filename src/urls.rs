@@ -0,0 +1,231 @@
+//! URL helpers for story links and Hacker News permalinks. Story URLs are returned by the API as raw strings,
+//! and the helpers within parse them, compute the site Hacker News displays next to a story title, strip tracking
+//! parameters for deduplicating links, and generate permalinks back to Hacker News for items, users, and sites.
+//!
+//! ```
+//! use newswrap::urls;
+//!
+//! let url = urls::parse_url("https://www.github.com/HackerNews/API?utm_source=hn#readme").unwrap();
+//!
+//! assert_eq!(urls::site(&url).unwrap(), "github.com/HackerNews");
+//! assert_eq!(urls::canonicalize(&url).as_str(), "https://www.github.com/HackerNews/API");
+//! assert_eq!(urls::item_permalink(8863), "https://news.ycombinator.com/item?id=8863");
+//! ```
+
+pub use url::Url;
+
+use crate::HackerNewsID;
+
+/// Base URL of the Hacker News website used for generating permalinks.
+pub const HACKER_NEWS_WEB_URL: &str = "https://news.ycombinator.com";
+
+/// Sites Hacker News displays along with the first path segment, as they host content for many different users.
+const USER_PATH_SITES: [&str; 8] = [
+    "github.com",
+    "gitlab.com",
+    "bitbucket.org",
+    "codeberg.org",
+    "sr.ht",
+    "twitter.com",
+    "x.com",
+    "medium.com",
+];
+
+/// Multi-label suffixes under which domains are registered, along with hosting platforms where each subdomain
+/// belongs to a different user, e.g. `example.co.uk` and `user.github.io`. This is intentionally incomplete, covering
+/// suffixes common among submissions rather than the full public suffix list, so hosts under unlisted suffixes are
+/// grouped by their last two labels.
+const MULTI_LABEL_SUFFIXES: [&str; 32] = [
+    "co.uk",
+    "org.uk",
+    "ac.uk",
+    "gov.uk",
+    "me.uk",
+    "com.au",
+    "net.au",
+    "org.au",
+    "edu.au",
+    "gov.au",
+    "co.nz",
+    "org.nz",
+    "co.jp",
+    "ne.jp",
+    "ac.jp",
+    "co.kr",
+    "co.in",
+    "co.za",
+    "com.br",
+    "com.cn",
+    "com.tw",
+    "com.mx",
+    "com.tr",
+    "com.sg",
+    "github.io",
+    "gitlab.io",
+    "blogspot.com",
+    "substack.com",
+    "wordpress.com",
+    "netlify.app",
+    "vercel.app",
+    "pages.dev",
+];
+
+/// Query parameters added for tracking purposes that do not affect the content of the linked page.
+/// Generic names such as `ref` are left alone, as sites like GitHub use them to select content, e.g. a branch.
+const TRACKING_PARAMETERS: [&str; 12] = [
+    "fbclid", "gclid", "dclid", "msclkid", "yclid", "igshid", "mc_cid", "mc_eid", "_hsenc",
+    "_hsmi", "ref_src", "ref_url",
+];
+
+/// Query parameter prefixes added for tracking purposes, e.g. `utm_source` and `utm_campaign`.
+const TRACKING_PARAMETER_PREFIXES: [&str; 2] = ["utm_", "pk_"];
+
+/// Parses a raw URL returned from the API, returning `None` for empty or malformed URLs.
+pub fn parse_url(url: &str) -> Option<Url> {
+    let url = url.trim();
+
+    if url.is_empty() {
+        return None;
+    }
+
+    Url::parse(url).ok()
+}
+
+/// Computes the registrable domain of a host, i.e. the domain registered under its public suffix.
+/// IP addresses are returned as-is.
+pub fn registrable_domain(host: &str) -> String {
+    let host = host.trim_end_matches('.').to_lowercase();
+
+    if host.parse::<std::net::IpAddr>().is_ok() || host.starts_with('[') {
+        return host;
+    }
+
+    let labels: Vec<&str> = host.split('.').collect();
+
+    let suffix_labels = match labels.len() {
+        length if length >= 2 => {
+            let last_two = labels[length - 2..].join(".");
+            if MULTI_LABEL_SUFFIXES.contains(&last_two.as_str()) {
+                2
+            } else {
+                1
+            }
+        }
+        _ => return host,
+    };
+
+    let domain_labels = (suffix_labels + 1).min(labels.len());
+    labels[labels.len() - domain_labels..].join(".")
+}
+
+/// Computes the site Hacker News displays next to story titles, e.g. `nytimes.com` for `https://www.nytimes.com/...`
+/// and `github.com/rust-lang` for `https://github.com/rust-lang/rust`.
+pub fn site(url: &Url) -> Option<String> {
+    let domain = registrable_domain(url.host_str()?);
+
+    if USER_PATH_SITES.contains(&domain.as_str()) {
+        let user = url
+            .path_segments()
+            .and_then(|mut segments| segments.next())
+            .filter(|segment| !segment.is_empty());
+
+        if let Some(user) = user {
+            return Some(format!("{}/{}", domain, user));
+        }
+    }
+
+    Some(domain)
+}
+
+/// Canonicalizes a URL for comparison, removing tracking query parameters, fragments, and trailing slashes,
+/// and sorting the remaining query parameters.
+pub fn canonicalize(url: &Url) -> Url {
+    let mut canonical = url.clone();
+    canonical.set_fragment(None);
+
+    let mut parameters: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| !is_tracking_parameter(name))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    parameters.sort();
+
+    if parameters.is_empty() {
+        canonical.set_query(None);
+    } else {
+        canonical.query_pairs_mut().clear().extend_pairs(parameters);
+    }
+
+    let path = canonical.path();
+    if path.len() > 1 && path.ends_with('/') {
+        let trimmed = path.trim_end_matches('/').to_string();
+        canonical.set_path(&trimmed);
+    }
+
+    canonical
+}
+
+/// Computes a key for deduplicating links, treating URLs differing only by scheme, a `www.` prefix,
+/// or tracking parameters as the same link.
+pub fn deduplication_key(url: &Url) -> String {
+    let canonical = canonicalize(url);
+    let host = canonical.host_str().unwrap_or_default();
+    let host = host.strip_prefix("www.").unwrap_or(host);
+
+    match canonical.query() {
+        Some(query) => format!("{}{}?{}", host, canonical.path(), query),
+        None => format!("{}{}", host, canonical.path()),
+    }
+}
+
+/// Generates the Hacker News permalink for an item.
+pub fn item_permalink(id: HackerNewsID) -> String {
+    format!("{}/item?id={}", HACKER_NEWS_WEB_URL, id)
+}
+
+/// Generates the Hacker News permalink for a user's profile.
+pub fn user_permalink(username: &str) -> String {
+    format!(
+        "{}/user?id={}",
+        HACKER_NEWS_WEB_URL,
+        encode_query_value(username)
+    )
+}
+
+/// Generates the Hacker News permalink listing all submissions from a site, as displayed by [`site`].
+pub fn site_permalink(site: &str) -> String {
+    format!(
+        "{}/from?site={}",
+        HACKER_NEWS_WEB_URL,
+        encode_query_value(site)
+    )
+}
+
+fn is_tracking_parameter(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+
+    TRACKING_PARAMETERS.contains(&name.as_str())
+        || TRACKING_PARAMETER_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+}
+
+/// Encodes a value as a single path segment of an API URL, so names containing `/` or `?` can't reach other
+/// endpoints. Returns `None` for the `.` and `..` segments, which URL parsing would resolve away regardless.
+pub(crate) fn encode_path_segment(value: &str) -> Option<String> {
+    if value.is_empty() || value == "." || value == ".." {
+        return None;
+    }
+
+    let mut url = Url::parse("http://localhost").ok()?;
+    url.path_segments_mut().ok()?.push(value);
+
+    Some(url.path()[1..].to_string())
+}
+
+/// Encodes a query value, leaving the `/` separating a site from its user unencoded as Hacker News does.
+fn encode_query_value(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes())
+        .collect::<String>()
+        .replace("%2F", "/")
+}
@@ -1,13 +1,14 @@
 //! User interactions, models, and endpoints for the Hacker News API.
 
-use crate::{errors::HackerNewsResult, http::InternalHttpClient};
+use crate::{
+    client::RequestOptions, errors::HackerNewsResult, http::InternalHttpClient,
+    versions::ApiResource,
+};
 
 use super::HackerNewsUser;
 
-const USER_ENDPOINT: &str = "user";
-
 /// An internal users client for interacting with user endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HackerNewsUserClient {
     internal_client: InternalHttpClient,
 }
@@ -20,9 +21,19 @@ impl HackerNewsUserClient {
 
     /// Retrieves a user from the user endpoint based on the provided username.
     pub async fn get_user(&self, username: &str) -> HackerNewsResult<HackerNewsUser> {
+        self.get_user_with(username, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieves a user based on the provided username with the request options applied.
+    pub async fn get_user_with(
+        &self,
+        username: &str,
+        options: RequestOptions,
+    ) -> HackerNewsResult<HackerNewsUser> {
         let user: HackerNewsUser = self
             .internal_client
-            .get_item_with_id(USER_ENDPOINT, username)
+            .get(ApiResource::User(username), &options)
             .await?;
 
         Ok(user)
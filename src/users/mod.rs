@@ -5,10 +5,13 @@ pub mod client;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
-use crate::HackerNewsID;
+use crate::{
+    text::{self, HackerNewsLink, SanitizedHtml},
+    urls, HackerNewsID,
+};
 
 /// Represents a Hacker News user and their associated metadata.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HackerNewsUser {
     /// Username of the account.
     pub id: String,
@@ -19,7 +22,8 @@ pub struct HackerNewsUser {
     pub karma: u32,
     /// The user's optional self-description. HTML.
     pub about: Option<String>,
-    /// List of the user's stories, polls and comments.
+    /// List of the user's stories, polls and comments, returned by the API as `submitted`.
+    #[serde(alias = "submitted")]
     pub stories: Option<Vec<HackerNewsID>>,
 }
 
@@ -33,4 +37,32 @@ impl HackerNewsUser {
     pub fn has_related_stories(&self) -> bool {
         self.stories.is_some()
     }
+
+    /// Renders the user's about section as plain text, if populated.
+    pub fn about_plain(&self) -> Option<String> {
+        self.about.as_deref().map(text::to_plain_text)
+    }
+
+    /// Renders the user's about section as Markdown, if populated.
+    pub fn about_markdown(&self) -> Option<String> {
+        self.about.as_deref().map(text::to_markdown)
+    }
+
+    /// Sanitizes the user's about section for embedding within other web pages, if populated.
+    pub fn about_sanitized(&self) -> Option<SanitizedHtml> {
+        self.about.as_deref().map(text::sanitize)
+    }
+
+    /// Extracts all links found within the user's about section.
+    pub fn about_links(&self) -> Vec<HackerNewsLink> {
+        self.about
+            .as_deref()
+            .map(text::extract_links)
+            .unwrap_or_default()
+    }
+
+    /// Generates the Hacker News permalink for the user's profile.
+    pub fn permalink(&self) -> String {
+        urls::user_permalink(&self.id)
+    }
 }
@@ -0,0 +1,111 @@
+//! Layouts of each version of the API, mapping the resources requested by the clients to URLs and parsing responses
+//! into the models. Only version 0 exists today, and mirrors are supported only when they serve the same layout from
+//! another base URL. There is no public hook for custom layouts; a future version would be added here as a variant
+//! along with its module.
+
+mod v0;
+
+use std::fmt::Display;
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    errors::HackerNewsResult,
+    items::HackerNewsItem,
+    realtime::{HackerNewsStoryList, HackerNewsUpdatedItems},
+    users::HackerNewsUser,
+    HackerNewsID,
+};
+
+pub(crate) use v0::BASE_URL as V0_BASE_URL;
+
+/// Version information for the Hacker News API containing the base URLs. Version 0 is currently the only version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// Represents version 0 of the Hacker News API.
+    #[default]
+    V0,
+}
+
+impl ApiVersion {
+    /// The URL of the version's API, including the version path.
+    pub fn base_url(&self) -> &'static str {
+        match self {
+            Self::V0 => v0::BASE_URL,
+        }
+    }
+
+    /// Builds the URL of the resource relative to the base URL of the version's API, returning `None` for resources
+    /// that can't be addressed, such as a username of `..`.
+    pub(crate) fn url(&self, base_url: &str, resource: &ApiResource<'_>) -> Option<String> {
+        match self {
+            Self::V0 => v0::url(base_url, resource),
+        }
+    }
+
+    /// Parses the response into the model, returning `None` when the API responds without the resource.
+    pub(crate) async fn parse<T: ApiResponse>(
+        &self,
+        response: reqwest::Response,
+    ) -> HackerNewsResult<Option<T>> {
+        match self {
+            Self::V0 => v0::parse(response).await,
+        }
+    }
+}
+
+/// A resource requested by the clients, independent of where each version of the API serves it from.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ApiResource<'a> {
+    /// An item by its ID.
+    Item(HackerNewsID),
+    /// A user by their username.
+    User(&'a str),
+    /// The ID of the latest item.
+    MaxItem,
+    /// The IDs within one of the realtime story lists.
+    StoryList(HackerNewsStoryList),
+    /// The recently updated items and profiles.
+    Updates,
+}
+
+/// Names the resource when reporting it missing, e.g. the ID of an item or a user's username.
+impl Display for ApiResource<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Item(id) => write!(f, "{}", id),
+            Self::User(username) => write!(f, "{}", username),
+            Self::MaxItem => write!(f, "maxitem"),
+            Self::StoryList(list) => write!(f, "{}", list),
+            Self::Updates => write!(f, "updates"),
+        }
+    }
+}
+
+/// A model returned by the API, parsed from each version's representation of it so versions may differ in their
+/// response formats without changing the models.
+pub(crate) trait ApiResponse: Sized {
+    /// The model as returned by version 0 of the API.
+    type V0: DeserializeOwned + Into<Self>;
+}
+
+impl ApiResponse for HackerNewsItem {
+    type V0 = Self;
+}
+
+impl ApiResponse for HackerNewsUser {
+    type V0 = Self;
+}
+
+impl ApiResponse for HackerNewsUpdatedItems {
+    type V0 = Self;
+}
+
+impl ApiResponse for HackerNewsID {
+    type V0 = Self;
+}
+
+impl ApiResponse for Vec<HackerNewsID> {
+    type V0 = Self;
+}
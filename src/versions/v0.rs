@@ -0,0 +1,46 @@
+//! Version 0 of the API, served by Firebase with each resource at its own `.json` path and models returned as is.
+
+use crate::{errors::HackerNewsResult, realtime::HackerNewsStoryList, urls};
+
+use super::{ApiResource, ApiResponse};
+
+/// URL of version 0 of the API.
+pub(crate) const BASE_URL: &str = "https://hacker-news.firebaseio.com/v0";
+
+/// Builds the URL of the resource, e.g. `{base_url}/item/8863.json`, encoding usernames so they can't address
+/// other resources.
+pub(super) fn url(base_url: &str, resource: &ApiResource<'_>) -> Option<String> {
+    let url = match resource {
+        ApiResource::Item(id) => format!("{}/item/{}.json", base_url, id),
+        ApiResource::User(username) => format!(
+            "{}/user/{}.json",
+            base_url,
+            urls::encode_path_segment(username)?
+        ),
+        ApiResource::MaxItem => format!("{}/maxitem.json", base_url),
+        ApiResource::StoryList(list) => format!("{}/{}.json", base_url, story_list_path(*list)),
+        ApiResource::Updates => format!("{}/updates.json", base_url),
+    };
+
+    Some(url)
+}
+
+/// The path of each realtime story list.
+fn story_list_path(list: HackerNewsStoryList) -> &'static str {
+    match list {
+        HackerNewsStoryList::Top => "topstories",
+        HackerNewsStoryList::New => "newstories",
+        HackerNewsStoryList::Best => "beststories",
+        HackerNewsStoryList::Ask => "askstories",
+        HackerNewsStoryList::Show => "showstories",
+        HackerNewsStoryList::Jobs => "jobstories",
+    }
+}
+
+/// Parses the response, which is `null` for resources that do not exist.
+pub(super) async fn parse<T: ApiResponse>(
+    response: reqwest::Response,
+) -> HackerNewsResult<Option<T>> {
+    let parsed = response.json::<Option<T::V0>>().await?;
+    Ok(parsed.map(Into::into))
+}
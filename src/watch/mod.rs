@@ -0,0 +1,324 @@
+//! Alerts for new and updated items matching user-defined rules, available behind the `watch` feature. Items are
+//! discovered by polling for the latest item ID, and rules match on keywords or patterns in an item's title or text,
+//! linked domains, authors, replies to an author's items and story score thresholds. A `ReplyNotifier` streams new
+//! replies to a single user's recent comments and stories.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use newswrap::{
+//!     client::HackerNewsClient,
+//!     watch::{WatchCondition, WatchRule, Watcher},
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!     let mut watcher = Watcher::new()
+//!         .rule(WatchRule::new("mentions", WatchCondition::Keyword("newswrap".to_string())))
+//!         .rule(WatchRule::new("replies", WatchCondition::ReplyTo("joeymckenzie".to_string())));
+//!
+//!     watcher
+//!         .run(&client, Duration::from_secs(30), |event| {
+//!             println!("[{}] {}", event.rule, event.item.permalink());
+//!         })
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod poller;
+mod replies;
+mod rules;
+
+use std::{
+    collections::{HashSet, VecDeque},
+    time::Duration,
+};
+
+use tokio::sync::mpsc;
+
+use crate::{
+    cancellation::Cancellation,
+    client::HackerNewsClient,
+    errors::{HackerNewsClientError, HackerNewsResult},
+    http,
+    items::HackerNewsItem,
+    HackerNewsID,
+};
+
+pub use poller::ItemPoller;
+pub use replies::{ReplyCheckpoint, ReplyNotification, ReplyNotifier};
+pub use rules::{WatchCondition, WatchRule};
+
+/// Maximum number of rule matches remembered to avoid notifying the same match twice.
+const MAX_NOTIFIED_MATCHES: usize = 10_000;
+
+/// An item matching one of the watcher's rules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchEvent {
+    /// The name of the matching rule.
+    pub rule: String,
+    /// The matching item.
+    pub item: HackerNewsItem,
+}
+
+/// Evaluates rules against items created since the last poll, emitting an event the first time each rule matches
+/// an item. Rules on story scores are also evaluated against recently updated items, as scores change over time.
+#[derive(Debug, Clone, Default)]
+pub struct Watcher {
+    rules: Vec<WatchRule>,
+    poller: ItemPoller,
+    notified: HashSet<(String, HackerNewsID)>,
+    notified_order: VecDeque<(String, HackerNewsID)>,
+}
+
+impl Watcher {
+    /// Constructs a new watcher without any rules, starting from the latest item on its first poll.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rule to those evaluated against items.
+    pub fn rule(mut self, rule: WatchRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Configures the poller used to discover new items, e.g. to resume from a persisted checkpoint.
+    pub fn poller(mut self, poller: ItemPoller) -> Self {
+        self.poller = poller;
+        self
+    }
+
+    /// The ID of the latest item seen by the watcher, for persisting as a checkpoint.
+    pub fn checkpoint(&self) -> Option<HackerNewsID> {
+        self.poller.last_seen()
+    }
+
+    /// Evaluates the rules against the item, returning events for rules that have not previously matched it.
+    /// Deleted and dead items are ignored.
+    pub async fn evaluate(
+        &mut self,
+        client: &HackerNewsClient,
+        item: &HackerNewsItem,
+    ) -> HackerNewsResult<Vec<WatchEvent>> {
+        let events = self.matches(client, item).await?;
+
+        for event in &events {
+            self.remember(event);
+        }
+
+        Ok(events)
+    }
+
+    /// Evaluates the rules against items created since the last poll, along with recently updated items
+    /// when any rule depends on story scores. The checkpoint and notified matches are only updated once
+    /// the whole poll succeeds, so a failed poll is retried in full by the next one.
+    pub async fn poll(&mut self, client: &HackerNewsClient) -> HackerNewsResult<Vec<WatchEvent>> {
+        let mut poller = self.poller;
+        let mut items = poller.poll(client).await?;
+
+        if self
+            .rules
+            .iter()
+            .any(|rule| rule.condition.changes_over_time())
+        {
+            let updated = client.realtime.get_recently_updated_items().await?;
+            items.extend(client.items.get_existing_items(&updated).await?);
+        }
+
+        let mut events: Vec<WatchEvent> = Vec::new();
+
+        for item in &items {
+            for event in self.matches(client, item).await? {
+                if !events
+                    .iter()
+                    .any(|matched| matched.rule == event.rule && matched.item.id == event.item.id)
+                {
+                    events.push(event);
+                }
+            }
+        }
+
+        self.poller = poller;
+
+        for event in &events {
+            self.remember(event);
+        }
+
+        Ok(events)
+    }
+
+    /// Evaluates the rules against the item without remembering the matches.
+    async fn matches(
+        &self,
+        client: &HackerNewsClient,
+        item: &HackerNewsItem,
+    ) -> HackerNewsResult<Vec<WatchEvent>> {
+        if item.deleted.unwrap_or_default() || item.dead.unwrap_or_default() {
+            return Ok(Vec::new());
+        }
+
+        let requires_parent = self
+            .rules
+            .iter()
+            .any(|rule| rule.condition.requires_parent());
+
+        let parent_author = match item.parent {
+            Some(parent) if requires_parent => match client.items.get_item(parent).await {
+                Ok(parent) => parent.by,
+                Err(HackerNewsClientError::ItemOrUserNotFound(_)) => None,
+                Err(error) => return Err(error),
+            },
+            _ => None,
+        };
+
+        let mut events = Vec::new();
+
+        for rule in &self.rules {
+            if rule.condition.matches(item, parent_author.as_deref())
+                && !self.notified.contains(&(rule.name.clone(), item.id))
+            {
+                events.push(WatchEvent {
+                    rule: rule.name.clone(),
+                    item: item.clone(),
+                });
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Remembers the notified match, forgetting the oldest once the limit is reached.
+    fn remember(&mut self, event: &WatchEvent) {
+        let key = (event.rule.clone(), event.item.id);
+
+        if !self.notified.insert(key.clone()) {
+            return;
+        }
+
+        if self.notified_order.len() == MAX_NOTIFIED_MATCHES {
+            if let Some(oldest) = self.notified_order.pop_front() {
+                self.notified.remove(&oldest);
+            }
+        }
+
+        self.notified_order.push_back(key);
+    }
+
+    /// Polls on the interval, passing each event to the callback. Failed polls are logged and retried with
+    /// exponential backoff, keeping the checkpoint so no items are missed.
+    pub async fn run(
+        &mut self,
+        client: &HackerNewsClient,
+        interval: Duration,
+        mut on_event: impl FnMut(WatchEvent),
+    ) -> HackerNewsResult<()> {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        let mut failures = 0;
+
+        loop {
+            interval.tick().await;
+
+            match self.poll(client).await {
+                Ok(events) => {
+                    failures = 0;
+                    events.into_iter().for_each(&mut on_event);
+                }
+                Err(error) => tokio::time::sleep(back_off(&mut failures, &error)).await,
+            }
+        }
+    }
+
+    /// Polls on the interval, passing each event to the callback until the token is cancelled or the deadline passes,
+    /// returning the reason. Polls in progress run to completion so no events are lost, and failed polls are logged
+    /// and retried with exponential backoff.
+    pub async fn run_until(
+        &mut self,
+        client: &HackerNewsClient,
+        interval: Duration,
+        cancellation: &Cancellation,
+        mut on_event: impl FnMut(WatchEvent),
+    ) -> HackerNewsResult<()> {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        let mut failures = 0;
+
+        loop {
+            cancellation
+                .run(async {
+                    interval.tick().await;
+                    Ok(())
+                })
+                .await?;
+
+            match self.poll(client).await {
+                Ok(events) => {
+                    failures = 0;
+                    events.into_iter().for_each(&mut on_event);
+                }
+                Err(error) => {
+                    let delay = back_off(&mut failures, &error);
+                    cancellation
+                        .run(async {
+                            tokio::time::sleep(delay).await;
+                            Ok(())
+                        })
+                        .await?;
+                }
+            }
+        }
+    }
+
+    /// Polls on the interval, sending each event to the channel until the receiver is dropped. Failed polls are logged
+    /// and retried with exponential backoff.
+    pub async fn forward(
+        &mut self,
+        client: &HackerNewsClient,
+        interval: Duration,
+        sender: mpsc::Sender<WatchEvent>,
+    ) -> HackerNewsResult<()> {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        let mut failures = 0;
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = sender.closed() => return Ok(()),
+            }
+
+            let events = match self.poll(client).await {
+                Ok(events) => events,
+                Err(error) => {
+                    tokio::select! {
+                        _ = tokio::time::sleep(back_off(&mut failures, &error)) => continue,
+                        _ = sender.closed() => return Ok(()),
+                    }
+                }
+            };
+
+            failures = 0;
+
+            for event in events {
+                if sender.send(event).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// Logs the failed poll, returning how long to wait before polling again as consecutive failures accumulate.
+fn back_off(failures: &mut u32, error: &HackerNewsClientError) -> Duration {
+    *failures = failures.saturating_add(1);
+    tracing::warn!(%error, failures = *failures, "failed to poll for watched items, retrying");
+
+    http::retry_delay(*failures)
+}
@@ -0,0 +1,118 @@
+//! Polling for newly created items by walking item IDs up to the latest item.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cancellation::{Cancellation, Partial},
+    client::{HackerNewsClient, RequestOptions},
+    errors::HackerNewsResult,
+    items::HackerNewsItem,
+    HackerNewsID,
+};
+
+/// Default maximum number of items retrieved on each poll.
+const DEFAULT_MAX_ITEMS_PER_POLL: u32 = 500;
+
+/// Retrieves items created since the last poll, using the latest item ID as a high water mark. The poller's
+/// checkpoint serializes, allowing polling to resume where it left off across restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemPoller {
+    last_seen: Option<HackerNewsID>,
+    max_items_per_poll: u32,
+}
+
+impl Default for ItemPoller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ItemPoller {
+    /// Constructs a new poller, starting from the latest item on its first poll.
+    pub fn new() -> Self {
+        Self {
+            last_seen: None,
+            max_items_per_poll: DEFAULT_MAX_ITEMS_PER_POLL,
+        }
+    }
+
+    /// Starts polling from the item following the ID, e.g. a checkpoint persisted from a previous run.
+    pub fn starting_after(mut self, id: HackerNewsID) -> Self {
+        self.last_seen = Some(id);
+        self
+    }
+
+    /// Configures the maximum number of items retrieved on each poll, catching up over subsequent polls
+    /// when more items have been created since the last poll.
+    pub fn max_items_per_poll(mut self, max_items_per_poll: u32) -> Self {
+        self.max_items_per_poll = max_items_per_poll.max(1);
+        self
+    }
+
+    /// The ID of the latest item seen by the poller.
+    pub fn last_seen(&self) -> Option<HackerNewsID> {
+        self.last_seen
+    }
+
+    /// Retrieves items created since the last poll in order of creation, skipping items not yet available. The
+    /// checkpoint advances only as far as the latest retrieved item, so items not yet available at the end of a poll
+    /// are requested again on the next. The first poll of a poller without a checkpoint returns no items, starting
+    /// from the latest item.
+    pub async fn poll(
+        &mut self,
+        client: &HackerNewsClient,
+    ) -> HackerNewsResult<Vec<HackerNewsItem>> {
+        let latest = client.realtime.get_latest_item_id().await?;
+        let ids = self.pending_ids(latest);
+        let items = client.items.get_existing_items(&ids).await?;
+        self.advance(items.iter().map(|item| item.id));
+
+        Ok(items)
+    }
+
+    /// Retrieves items created since the last poll until cancelled or past the deadline. Polls stopped early return
+    /// the items retrieved so far, advancing the checkpoint to the latest of them so the next poll resumes where this
+    /// one stopped.
+    pub async fn poll_until(
+        &mut self,
+        client: &HackerNewsClient,
+        cancellation: &Cancellation,
+    ) -> Partial<Vec<HackerNewsItem>> {
+        let latest = match cancellation.run(client.realtime.get_latest_item_id()).await {
+            Ok(latest) => latest,
+            Err(error) => return Partial::new(Vec::new(), Some(error)),
+        };
+
+        let ids = self.pending_ids(latest);
+        let items = client
+            .items
+            .get_existing_items_until(&ids, cancellation, RequestOptions::default())
+            .await;
+
+        let error = items.error;
+        let items: Vec<HackerNewsItem> = items.value.into_iter().flatten().collect();
+        self.advance(items.iter().map(|item| item.id));
+
+        Partial::new(items, error)
+    }
+
+    /// Advances the checkpoint to the latest retrieved item, leaving trailing items not yet available to be
+    /// requested again.
+    fn advance(&mut self, retrieved: impl Iterator<Item = HackerNewsID>) {
+        if let Some(latest) = retrieved.max() {
+            self.last_seen = self.last_seen.max(Some(latest));
+        }
+    }
+
+    /// The IDs of items created since the last poll, up to the maximum per poll. The first poll of a poller without a
+    /// checkpoint starts from the latest item, returning no IDs.
+    fn pending_ids(&mut self, latest: HackerNewsID) -> Vec<HackerNewsID> {
+        let Some(last_seen) = self.last_seen else {
+            self.last_seen = Some(latest);
+            return Vec::new();
+        };
+
+        let until = latest.min(last_seen.saturating_add(self.max_items_per_poll));
+        (last_seen + 1..=until).collect()
+    }
+}
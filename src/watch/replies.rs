@@ -0,0 +1,290 @@
+//! Notifications of new direct replies to a user's comments and stories.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::Duration,
+};
+
+use futures::{stream, Stream};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cancellation::Cancellation,
+    client::HackerNewsClient,
+    errors::HackerNewsResult,
+    items::{comments::HackerNewsComment, HackerNewsItem},
+    HackerNewsID,
+};
+
+use super::ItemPoller;
+
+/// Default number of the user's most recent submissions watched for replies.
+const DEFAULT_MAX_TRACKED_SUBMISSIONS: usize = 100;
+
+/// Number of notified replies remembered for deduplication.
+const MAX_NOTIFIED_REPLIES: usize = 1000;
+
+/// A new direct reply to one of the user's items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplyNotification {
+    /// The reply.
+    pub reply: HackerNewsComment,
+    /// The user's comment or story that was replied to.
+    pub parent: HackerNewsItem,
+}
+
+/// The state of a reply notifier, persisted to resume notifying after a restart without re-notifying replies.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReplyCheckpoint {
+    /// Username of the account replies are watched for.
+    pub username: String,
+    /// Replies at or before this item are never notified, set to the latest item on the first poll.
+    pub since: Option<HackerNewsID>,
+    /// The latest item scanned for replies.
+    pub last_seen: Option<HackerNewsID>,
+    /// Recently notified replies, oldest first.
+    pub notified: Vec<HackerNewsID>,
+}
+
+/// Notifies new direct replies to a user's recent comments and stories, found by scanning newly created items for
+/// replies to the user's submissions and by checking the replies of submissions that were recently updated.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use futures::StreamExt;
+/// use newswrap::{client::HackerNewsClient, watch::ReplyNotifier};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = HackerNewsClient::new();
+///     let mut notifier = ReplyNotifier::new("joeymckenzie");
+///     let mut replies = Box::pin(notifier.stream(&client, Duration::from_secs(60)));
+///
+///     while let Some(notification) = replies.next().await {
+///         let notification = notification?;
+///         println!("{} replied: {}", notification.reply.by, notification.reply.text_plain());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplyNotifier {
+    username: String,
+    since: Option<HackerNewsID>,
+    poller: ItemPoller,
+    submissions: HashSet<HackerNewsID>,
+    notified: VecDeque<HackerNewsID>,
+    max_tracked_submissions: usize,
+}
+
+impl ReplyNotifier {
+    /// Constructs a new notifier for the user, notifying replies created after its first poll.
+    pub fn new(username: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            since: None,
+            poller: ItemPoller::new(),
+            submissions: HashSet::new(),
+            notified: VecDeque::new(),
+            max_tracked_submissions: DEFAULT_MAX_TRACKED_SUBMISSIONS,
+        }
+    }
+
+    /// Constructs a notifier resuming from a persisted checkpoint.
+    pub fn from_checkpoint(checkpoint: ReplyCheckpoint) -> Self {
+        let mut notifier = Self::new(checkpoint.username);
+        notifier.since = checkpoint.since;
+        notifier.notified = checkpoint.notified.into();
+
+        while notifier.notified.len() > MAX_NOTIFIED_REPLIES {
+            notifier.notified.pop_front();
+        }
+
+        if let Some(last_seen) = checkpoint.last_seen {
+            notifier.poller = notifier.poller.starting_after(last_seen);
+        }
+
+        notifier
+    }
+
+    /// Configures the number of the user's most recent submissions watched for replies.
+    pub fn max_tracked_submissions(mut self, max_tracked_submissions: usize) -> Self {
+        self.max_tracked_submissions = max_tracked_submissions;
+        self
+    }
+
+    /// Configures the poller used to scan newly created items, e.g. to limit the items retrieved on each poll.
+    pub fn poller(mut self, poller: ItemPoller) -> Self {
+        self.poller = poller;
+        self
+    }
+
+    /// Captures the notifier's state for persisting.
+    pub fn checkpoint(&self) -> ReplyCheckpoint {
+        ReplyCheckpoint {
+            username: self.username.clone(),
+            since: self.since,
+            last_seen: self.poller.last_seen(),
+            notified: self.notified.iter().copied().collect(),
+        }
+    }
+
+    /// Retrieves new direct replies to the user's recent submissions since the last poll, oldest first. The
+    /// notifier's checkpoint is only updated once the whole poll succeeds, so a failed poll is retried in full
+    /// by the next one. Replies that fail to convert into comments are skipped.
+    pub async fn poll(
+        &mut self,
+        client: &HackerNewsClient,
+    ) -> HackerNewsResult<Vec<ReplyNotification>> {
+        let mut poller = self.poller;
+        let new_items = poller.poll(client).await?;
+        let since = self
+            .since
+            .unwrap_or_else(|| poller.last_seen().unwrap_or_default());
+
+        let user = client.users.get_user(&self.username).await?;
+        self.submissions = user
+            .stories
+            .unwrap_or_default()
+            .into_iter()
+            .take(self.max_tracked_submissions)
+            .collect();
+
+        let mut replies: HashMap<HackerNewsID, HackerNewsItem> = new_items
+            .into_iter()
+            .filter(|item| self.is_new_reply(item, since))
+            .map(|item| (item.id, item))
+            .collect();
+
+        // replies to updated submissions may not have been scanned yet, or were created before a restart
+        let updated: Vec<HackerNewsID> = client
+            .realtime
+            .get_recently_updated_items()
+            .await?
+            .into_iter()
+            .filter(|id| self.submissions.contains(id))
+            .collect();
+
+        let unseen_kids: Vec<HackerNewsID> = client
+            .items
+            .get_existing_items(&updated)
+            .await?
+            .into_iter()
+            .flat_map(|item| item.kids.unwrap_or_default())
+            .filter(|id| *id > since && !replies.contains_key(id) && !self.notified.contains(id))
+            .collect();
+
+        for item in client.items.get_existing_items(&unseen_kids).await? {
+            if self.is_new_reply(&item, since) {
+                replies.insert(item.id, item);
+            }
+        }
+
+        let mut replies: Vec<HackerNewsItem> = replies.into_values().collect();
+        replies.sort_by_key(|reply| reply.id);
+
+        let mut parent_ids: Vec<HackerNewsID> =
+            replies.iter().filter_map(|reply| reply.parent).collect();
+        parent_ids.sort_unstable();
+        parent_ids.dedup();
+
+        let parents: HashMap<HackerNewsID, HackerNewsItem> = client
+            .items
+            .get_existing_items(&parent_ids)
+            .await?
+            .into_iter()
+            .map(|parent| (parent.id, parent))
+            .collect();
+
+        let mut notifications = Vec::with_capacity(replies.len());
+
+        for reply in replies {
+            let Some(parent) = reply.parent.and_then(|id| parents.get(&id)) else {
+                continue;
+            };
+
+            let Ok(reply) = HackerNewsComment::try_from(reply) else {
+                continue;
+            };
+
+            notifications.push(ReplyNotification {
+                reply,
+                parent: parent.clone(),
+            });
+        }
+
+        self.poller = poller;
+        self.since = Some(since);
+
+        for notification in &notifications {
+            self.remember(notification.reply.id);
+        }
+
+        Ok(notifications)
+    }
+
+    /// Retrieves new direct replies like [`ReplyNotifier::poll`] until cancelled or past the deadline. Polls stopped
+    /// early leave the checkpoint untouched and return the reason, so the next poll retries them in full.
+    pub async fn poll_until(
+        &mut self,
+        client: &HackerNewsClient,
+        cancellation: &Cancellation,
+    ) -> HackerNewsResult<Vec<ReplyNotification>> {
+        cancellation.run(self.poll(client)).await
+    }
+
+    /// Polls on the interval, yielding each new reply as it's found. Failed polls are yielded as errors
+    /// and polling continues on the next interval.
+    pub fn stream<'a>(
+        &'a mut self,
+        client: &'a HackerNewsClient,
+        interval: Duration,
+    ) -> impl Stream<Item = HackerNewsResult<ReplyNotification>> + 'a {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        stream::unfold(
+            (self, interval, VecDeque::new()),
+            move |(notifier, mut interval, mut pending)| async move {
+                loop {
+                    if let Some(notification) = pending.pop_front() {
+                        return Some((Ok(notification), (notifier, interval, pending)));
+                    }
+
+                    interval.tick().await;
+
+                    match notifier.poll(client).await {
+                        Ok(notifications) => pending.extend(notifications),
+                        Err(error) => return Some((Err(error), (notifier, interval, pending))),
+                    }
+                }
+            },
+        )
+    }
+
+    /// Determines if the item is a visible comment replying to one of the user's submissions that has not
+    /// previously been notified.
+    fn is_new_reply(&self, item: &HackerNewsItem, since: HackerNewsID) -> bool {
+        item.id > since
+            && item.is_comment()
+            && !item.deleted.unwrap_or_default()
+            && !item.dead.unwrap_or_default()
+            && item.parent.is_some_and(|id| self.submissions.contains(&id))
+            && item
+                .by
+                .as_deref()
+                .is_some_and(|by| !by.eq_ignore_ascii_case(&self.username))
+            && !self.notified.contains(&item.id)
+    }
+
+    /// Remembers the notified reply, forgetting the oldest once the limit is reached.
+    fn remember(&mut self, id: HackerNewsID) {
+        while self.notified.len() >= MAX_NOTIFIED_REPLIES {
+            self.notified.pop_front();
+        }
+
+        self.notified.push_back(id);
+    }
+}
@@ -0,0 +1,131 @@
+//! User-defined rules evaluated against items.
+
+use regex::Regex;
+
+use crate::{items::HackerNewsItem, HackerNewsID};
+
+/// A condition an item must satisfy for a rule to match.
+#[derive(Debug, Clone)]
+pub enum WatchCondition {
+    /// Matches items mentioning the keyword or phrase as whole words in their title or text, ignoring case.
+    Keyword(String),
+    /// Matches items whose title or text matches the pattern, rendered as plain text.
+    Pattern(Regex),
+    /// Matches stories linking to the domain or its subdomains, e.g. `github.com`.
+    Domain(String),
+    /// Matches items by the author, ignoring case.
+    Author(String),
+    /// Matches direct replies to items by the author, ignoring case.
+    ReplyTo(String),
+    /// Matches direct replies to any of the items.
+    ReplyToItems(Vec<HackerNewsID>),
+    /// Matches stories scoring at least the minimum.
+    MinScore(u32),
+    /// Matches items satisfying all of the conditions.
+    All(Vec<WatchCondition>),
+    /// Matches items satisfying any of the conditions.
+    Any(Vec<WatchCondition>),
+}
+
+impl WatchCondition {
+    /// Determines if the item satisfies the condition, given the author of the item's parent if known.
+    pub(super) fn matches(&self, item: &HackerNewsItem, parent_author: Option<&str>) -> bool {
+        match self {
+            WatchCondition::Keyword(keyword) => {
+                searchable_text(item).any(|text| contains_keyword(&text, keyword))
+            }
+            WatchCondition::Pattern(pattern) => {
+                searchable_text(item).any(|text| pattern.is_match(&text))
+            }
+            WatchCondition::Domain(expected) => item
+                .parsed_url()
+                .and_then(|url| url.host_str().map(str::to_lowercase))
+                .is_some_and(|domain| {
+                    let expected = expected.to_lowercase();
+                    domain == expected || domain.ends_with(&format!(".{}", expected))
+                }),
+            WatchCondition::Author(author) => item
+                .by
+                .as_deref()
+                .is_some_and(|by| by.eq_ignore_ascii_case(author)),
+            WatchCondition::ReplyTo(author) => {
+                parent_author.is_some_and(|parent| parent.eq_ignore_ascii_case(author))
+            }
+            WatchCondition::ReplyToItems(ids) => item.parent.is_some_and(|id| ids.contains(&id)),
+            WatchCondition::MinScore(min_score) => {
+                item.is_story() && item.score.unwrap_or_default() >= *min_score
+            }
+            WatchCondition::All(conditions) => conditions
+                .iter()
+                .all(|condition| condition.matches(item, parent_author)),
+            WatchCondition::Any(conditions) => conditions
+                .iter()
+                .any(|condition| condition.matches(item, parent_author)),
+        }
+    }
+
+    /// Determines if the condition may match an item after it was created, e.g. once a story reaches a score.
+    pub(super) fn changes_over_time(&self) -> bool {
+        match self {
+            WatchCondition::MinScore(_) => true,
+            WatchCondition::All(conditions) | WatchCondition::Any(conditions) => {
+                conditions.iter().any(WatchCondition::changes_over_time)
+            }
+            _ => false,
+        }
+    }
+
+    /// Determines if evaluating the condition requires the author of the item's parent.
+    pub(super) fn requires_parent(&self) -> bool {
+        match self {
+            WatchCondition::ReplyTo(_) => true,
+            WatchCondition::All(conditions) | WatchCondition::Any(conditions) => {
+                conditions.iter().any(WatchCondition::requires_parent)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A named condition, identifying which rule matched an item.
+#[derive(Debug, Clone)]
+pub struct WatchRule {
+    /// The name of the rule, included on events.
+    pub name: String,
+    /// The condition items must satisfy.
+    pub condition: WatchCondition,
+}
+
+impl WatchRule {
+    /// Constructs a new rule with the name and condition.
+    pub fn new(name: impl Into<String>, condition: WatchCondition) -> Self {
+        Self {
+            name: name.into(),
+            condition,
+        }
+    }
+}
+
+/// The item's title and text rendered as plain text.
+fn searchable_text(item: &HackerNewsItem) -> impl Iterator<Item = String> {
+    [item.title_plain(), item.text_plain()]
+        .into_iter()
+        .flatten()
+}
+
+/// Determines if the text contains the keyword surrounded by word boundaries, ignoring case.
+fn contains_keyword(text: &str, keyword: &str) -> bool {
+    let text = text.to_lowercase();
+    let keyword = keyword.trim().to_lowercase();
+
+    if keyword.is_empty() {
+        return false;
+    }
+
+    text.match_indices(&keyword).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + keyword.len()..].chars().next();
+
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
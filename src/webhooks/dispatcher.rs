@@ -0,0 +1,391 @@
+//! Delivery of events to webhooks with signing, retries and a dead letter log.
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use time::OffsetDateTime;
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
+
+use crate::{
+    cancellation::Cancellation,
+    client::HackerNewsClient,
+    errors::{HackerNewsClientError, HackerNewsResult},
+    http,
+};
+
+use super::{EventSource, WebhookEvent, WebhookEventKind};
+
+/// Header containing the HMAC-SHA256 signature of the timestamp and payload, e.g. `sha256=8f3a...`.
+const SIGNATURE_HEADER: &str = "X-Newswrap-Signature";
+
+/// Header containing the Unix timestamp the payload was signed at, for receivers to reject replayed deliveries.
+const TIMESTAMP_HEADER: &str = "X-Newswrap-Timestamp";
+
+/// Header containing the kind of event delivered, e.g. `item_matched`.
+const EVENT_HEADER: &str = "X-Newswrap-Event";
+
+/// Default number of delivery attempts before an event is recorded as a dead letter.
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Default delay before retrying a failed delivery, doubling on each subsequent attempt.
+const DEFAULT_RETRY_DELAY_MILLIS: u64 = 500;
+
+/// Maximum number of times the retry delay doubles, bounding the delay however many attempts are configured.
+const MAX_RETRY_DOUBLINGS: u32 = 8;
+
+/// Maximum delay between delivery attempts, unless the configured retry delay is longer.
+const MAX_RETRY_DELAY_SECONDS: u64 = 300;
+
+/// Default timeout for each delivery attempt.
+const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+
+/// Maximum number of dead letters kept in memory, forgetting the oldest once reached.
+const MAX_DEAD_LETTERS: usize = 1000;
+
+/// A webhook endpoint events are delivered to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Webhook {
+    url: String,
+    secret: Option<String>,
+    events: Option<Vec<WebhookEventKind>>,
+}
+
+impl Webhook {
+    /// Constructs a new webhook delivering all events to the URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            secret: None,
+            events: None,
+        }
+    }
+
+    /// Signs payloads with the secret, including the signature within the `X-Newswrap-Signature` header and the
+    /// time of signing within the `X-Newswrap-Timestamp` header.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// Restricts the events delivered to the webhook to the kinds.
+    pub fn events(mut self, kinds: impl IntoIterator<Item = WebhookEventKind>) -> Self {
+        self.events = Some(kinds.into_iter().collect());
+        self
+    }
+
+    /// The URL events are delivered to.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Computes the signature of `{timestamp}.{payload}` with the secret as sent in the `X-Newswrap-Signature` header,
+    /// for receivers to verify payloads against using the timestamp from the `X-Newswrap-Timestamp` header.
+    pub fn signature(secret: &str, timestamp: i64, payload: &[u8]) -> HackerNewsResult<String> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .map_err(|_| HackerNewsClientError::InfallibleOperation)?;
+        mac.update(format!("{}.", timestamp).as_bytes());
+        mac.update(payload);
+
+        let digest: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        Ok(format!("sha256={}", digest))
+    }
+
+    fn accepts(&self, kind: WebhookEventKind) -> bool {
+        self.events
+            .as_ref()
+            .is_none_or(|events| events.contains(&kind))
+    }
+}
+
+/// An event that could not be delivered to a webhook.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeadLetter {
+    /// The URL of the webhook the event was not delivered to.
+    pub url: String,
+    /// The undelivered event.
+    pub event: WebhookEvent,
+    /// The number of delivery attempts made.
+    pub attempts: u32,
+    /// The error encountered on the final attempt.
+    pub error: String,
+    /// The time the final attempt failed.
+    #[serde(with = "time::serde::rfc3339")]
+    pub failed_at: OffsetDateTime,
+}
+
+/// Delivers events to webhooks as JSON payloads, retrying failed deliveries with exponential backoff and recording
+/// events that could not be delivered as dead letters.
+#[derive(Debug, Clone)]
+pub struct WebhookDispatcher {
+    http: reqwest::Client,
+    webhooks: Vec<Webhook>,
+    max_attempts: u32,
+    retry_delay: Duration,
+    timeout: Duration,
+    dead_letter_log: Option<PathBuf>,
+    dead_letters: Vec<DeadLetter>,
+}
+
+impl Default for WebhookDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebhookDispatcher {
+    /// Constructs a new dispatcher without any webhooks.
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            webhooks: Vec::new(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_delay: Duration::from_millis(DEFAULT_RETRY_DELAY_MILLIS),
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
+            dead_letter_log: None,
+            dead_letters: Vec::new(),
+        }
+    }
+
+    /// Adds the webhook to those events are delivered to.
+    pub fn webhook(mut self, webhook: Webhook) -> Self {
+        self.webhooks.push(webhook);
+        self
+    }
+
+    /// Configures the number of delivery attempts made before an event is recorded as a dead letter.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Configures the delay before retrying a failed delivery, doubling on each subsequent attempt up to five minutes.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Configures the timeout for each delivery attempt.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Appends dead letters to the file as JSON lines, keeping every dead letter rather than only the most recent.
+    pub fn dead_letter_log(mut self, path: impl AsRef<Path>) -> Self {
+        self.dead_letter_log = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// The most recent events that could not be delivered, oldest first, keeping up to a thousand.
+    pub fn dead_letters(&self) -> &[DeadLetter] {
+        &self.dead_letters
+    }
+
+    /// Delivers the event to each webhook accepting its kind, returning the number of successful deliveries.
+    /// Undeliverable events are recorded as dead letters rather than returned as errors.
+    pub async fn dispatch(&mut self, event: &WebhookEvent) -> HackerNewsResult<usize> {
+        let payload = serde_json::to_vec(event)
+            .map_err(|error| HackerNewsClientError::WebhookError(error.into()))?;
+        let mut delivered = 0;
+
+        for webhook in self.webhooks.clone() {
+            if !webhook.accepts(event.kind()) {
+                continue;
+            }
+
+            match self.deliver(&webhook, event.kind(), &payload).await? {
+                None => delivered += 1,
+                Some(error) => {
+                    self.record(DeadLetter {
+                        url: webhook.url.clone(),
+                        event: event.clone(),
+                        attempts: self.max_attempts,
+                        error,
+                        failed_at: OffsetDateTime::now_utc(),
+                    })
+                    .await?
+                }
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    /// Polls the source on the interval, delivering each event until a dead letter can't be recorded. Failed polls
+    /// are logged and retried with exponential backoff.
+    pub async fn run(
+        &mut self,
+        client: &HackerNewsClient,
+        source: &mut EventSource,
+        interval: Duration,
+    ) -> HackerNewsResult<()> {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        let mut failures: u32 = 0;
+
+        loop {
+            interval.tick().await;
+
+            let events = match source.poll(client).await {
+                Ok(events) => events,
+                Err(error) => {
+                    failures = failures.saturating_add(1);
+                    tracing::warn!(%error, failures, "failed to poll for webhook events, retrying");
+                    tokio::time::sleep(http::retry_delay(failures)).await;
+                    continue;
+                }
+            };
+
+            failures = 0;
+
+            for event in events {
+                self.dispatch(&event).await?;
+            }
+        }
+    }
+
+    /// Polls the source on the interval, delivering each event until a dead letter can't be recorded, the token is
+    /// cancelled or the deadline passes, returning the reason. Polls and deliveries in progress run to completion so
+    /// no events are lost, and failed polls are logged and retried with exponential backoff.
+    pub async fn run_until(
+        &mut self,
+        client: &HackerNewsClient,
+        source: &mut EventSource,
+        interval: Duration,
+        cancellation: &Cancellation,
+    ) -> HackerNewsResult<()> {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut failures: u32 = 0;
+
+        loop {
+            cancellation
+                .run(async {
+                    interval.tick().await;
+                    Ok(())
+                })
+                .await?;
+
+            let events = match source.poll(client).await {
+                Ok(events) => events,
+                Err(error) => {
+                    failures = failures.saturating_add(1);
+                    tracing::warn!(%error, failures, "failed to poll for webhook events, retrying");
+                    cancellation
+                        .run(async {
+                            tokio::time::sleep(http::retry_delay(failures)).await;
+                            Ok(())
+                        })
+                        .await?;
+                    continue;
+                }
+            };
+
+            failures = 0;
+
+            for event in events {
+                self.dispatch(&event).await?;
+            }
+        }
+    }
+
+    /// Attempts to deliver the payload, returning the final error if every attempt failed.
+    async fn deliver(
+        &self,
+        webhook: &Webhook,
+        kind: WebhookEventKind,
+        payload: &[u8],
+    ) -> HackerNewsResult<Option<String>> {
+        let mut error = String::new();
+
+        for attempt in 0..self.max_attempts {
+            if attempt > 0 {
+                tokio::time::sleep(self.backoff(attempt)).await;
+            }
+
+            let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+            let signature = webhook
+                .secret
+                .as_deref()
+                .map(|secret| Webhook::signature(secret, timestamp, payload))
+                .transpose()?;
+
+            let mut request = self
+                .http
+                .post(&webhook.url)
+                .timeout(self.timeout)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(EVENT_HEADER, kind.to_string())
+                .body(payload.to_vec());
+
+            if let Some(signature) = signature {
+                request = request
+                    .header(SIGNATURE_HEADER, signature)
+                    .header(TIMESTAMP_HEADER, timestamp.to_string());
+            }
+
+            match request
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+            {
+                Ok(_) => return Ok(None),
+                Err(delivery_error) => error = delivery_error.to_string(),
+            }
+        }
+
+        Ok(Some(error))
+    }
+
+    /// The delay before the attempt, doubling the retry delay for each attempt after the first retry.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let doublings = (attempt - 1).min(MAX_RETRY_DOUBLINGS);
+        let max_delay = Duration::from_secs(MAX_RETRY_DELAY_SECONDS).max(self.retry_delay);
+
+        self.retry_delay
+            .saturating_mul(2_u32.saturating_pow(doublings))
+            .min(max_delay)
+    }
+
+    /// Records the dead letter, appending it to the log if configured.
+    async fn record(&mut self, dead_letter: DeadLetter) -> HackerNewsResult<()> {
+        if let Some(path) = &self.dead_letter_log {
+            let mut line = serde_json::to_string(&dead_letter)
+                .map_err(|error| HackerNewsClientError::WebhookError(error.into()))?;
+            line.push('\n');
+
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .await
+                .map_err(|error| HackerNewsClientError::WebhookError(error.into()))?;
+
+            file.write_all(line.as_bytes())
+                .await
+                .map_err(|error| HackerNewsClientError::WebhookError(error.into()))?;
+            file.flush()
+                .await
+                .map_err(|error| HackerNewsClientError::WebhookError(error.into()))?;
+        }
+
+        if self.dead_letters.len() >= MAX_DEAD_LETTERS {
+            self.dead_letters.remove(0);
+        }
+
+        self.dead_letters.push(dead_letter);
+        Ok(())
+    }
+}
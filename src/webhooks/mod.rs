@@ -0,0 +1,232 @@
+//! Forwarding of Hacker News events to HTTP webhooks, available behind the `webhooks` feature. Events are discovered by
+//! polling for items matching watch rules, stories entering the top stories, and recently updated items, then
+//! delivered as signed JSON payloads with retries, recording undeliverable events to a dead letter log.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use newswrap::{
+//!     client::HackerNewsClient,
+//!     watch::{WatchCondition, WatchRule, Watcher},
+//!     webhooks::{EventSource, Webhook, WebhookDispatcher},
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = HackerNewsClient::new();
+//!     let mut source = EventSource::new()
+//!         .watcher(Watcher::new().rule(WatchRule::new(
+//!             "mentions",
+//!             WatchCondition::Keyword("newswrap".to_string()),
+//!         )))
+//!         .top_stories(true);
+//!
+//!     let mut dispatcher = WebhookDispatcher::new()
+//!         .webhook(Webhook::new("https://example.com/hooks/hn").secret("shh"))
+//!         .dead_letter_log("dead-letters.jsonl");
+//!
+//!     dispatcher
+//!         .run(&client, &mut source, Duration::from_secs(60))
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod dispatcher;
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::HackerNewsClient, errors::HackerNewsResult, items::HackerNewsItem, watch::Watcher,
+    HackerNewsID,
+};
+
+pub use dispatcher::{DeadLetter, Webhook, WebhookDispatcher};
+
+/// Number of top stories displayed on the front page.
+const FRONT_PAGE_SIZE: usize = 30;
+
+/// The kinds of events delivered to webhooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventKind {
+    /// A new or updated item matched one of the watcher's rules.
+    ItemMatched,
+    /// A story entered the front page of the top stories.
+    EnteredTopStories,
+    /// An item was recently updated.
+    ItemUpdated,
+}
+
+impl Display for WebhookEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookEventKind::ItemMatched => write!(f, "item_matched"),
+            WebhookEventKind::EnteredTopStories => write!(f, "entered_top_stories"),
+            WebhookEventKind::ItemUpdated => write!(f, "item_updated"),
+        }
+    }
+}
+
+/// An event delivered to webhooks, serialized as the payload's JSON body tagged by its `event` kind.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A new or updated item matched one of the watcher's rules.
+    ItemMatched {
+        /// The name of the matching rule.
+        rule: String,
+        /// The matching item.
+        item: HackerNewsItem,
+    },
+    /// A story entered the front page of the top stories.
+    EnteredTopStories {
+        /// The story's one-based position within the top stories.
+        rank: usize,
+        /// The story.
+        item: HackerNewsItem,
+    },
+    /// An item was recently updated, e.g. a story's score changed or a comment was edited.
+    ItemUpdated {
+        /// The updated item.
+        item: HackerNewsItem,
+    },
+}
+
+impl WebhookEvent {
+    /// The kind of the event.
+    pub fn kind(&self) -> WebhookEventKind {
+        match self {
+            WebhookEvent::ItemMatched { .. } => WebhookEventKind::ItemMatched,
+            WebhookEvent::EnteredTopStories { .. } => WebhookEventKind::EnteredTopStories,
+            WebhookEvent::ItemUpdated { .. } => WebhookEventKind::ItemUpdated,
+        }
+    }
+
+    /// The item the event pertains to.
+    pub fn item(&self) -> &HackerNewsItem {
+        match self {
+            WebhookEvent::ItemMatched { item, .. }
+            | WebhookEvent::EnteredTopStories { item, .. }
+            | WebhookEvent::ItemUpdated { item } => item,
+        }
+    }
+}
+
+/// Discovers events by polling the API, combining items matching a watcher's rules, stories entering the front page
+/// of the top stories and recently updated items.
+#[derive(Debug, Clone, Default)]
+pub struct EventSource {
+    watcher: Option<Watcher>,
+    top_stories: Option<Vec<HackerNewsID>>,
+    track_top_stories: bool,
+    track_updates: bool,
+}
+
+impl EventSource {
+    /// Constructs a new source without any events enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emits events for items matching the watcher's rules.
+    pub fn watcher(mut self, watcher: Watcher) -> Self {
+        self.watcher = Some(watcher);
+        self
+    }
+
+    /// Configures whether events are emitted for stories entering the front page of the top stories. Stories on the
+    /// front page when first polled are not considered to have entered it.
+    pub fn top_stories(mut self, track_top_stories: bool) -> Self {
+        self.track_top_stories = track_top_stories;
+        self
+    }
+
+    /// Configures the stories already known to be on the front page, e.g. persisted from a previous run.
+    pub fn known_top_stories(mut self, ids: impl IntoIterator<Item = HackerNewsID>) -> Self {
+        self.top_stories = Some(ids.into_iter().take(FRONT_PAGE_SIZE).collect());
+        self
+    }
+
+    /// Configures whether events are emitted for recently updated items.
+    pub fn updates(mut self, track_updates: bool) -> Self {
+        self.track_updates = track_updates;
+        self
+    }
+
+    /// The stories on the front page as of the last poll.
+    pub fn current_top_stories(&self) -> &[HackerNewsID] {
+        self.top_stories.as_deref().unwrap_or_default()
+    }
+
+    /// Retrieves events that have occurred since the last poll. The source's state, including the watcher's
+    /// checkpoint and the known top stories, is only updated once the whole poll succeeds, so no events are lost
+    /// to a failed poll.
+    pub async fn poll(&mut self, client: &HackerNewsClient) -> HackerNewsResult<Vec<WebhookEvent>> {
+        let mut entered_events = Vec::new();
+        let mut top_stories = None;
+
+        if self.track_top_stories {
+            let mut current = client.realtime.get_top_stories().await?;
+            current.truncate(FRONT_PAGE_SIZE);
+
+            if let Some(previous) = &self.top_stories {
+                let entered: Vec<HackerNewsID> = current
+                    .iter()
+                    .copied()
+                    .filter(|id| !previous.contains(id))
+                    .collect();
+
+                for item in client.items.get_existing_items(&entered).await? {
+                    let rank = current.iter().position(|id| *id == item.id);
+
+                    entered_events.push(WebhookEvent::EnteredTopStories {
+                        rank: rank.unwrap_or_default() + 1,
+                        item,
+                    });
+                }
+            }
+
+            top_stories = Some(current);
+        }
+
+        let mut updated_events = Vec::new();
+
+        if self.track_updates {
+            let updated = client.realtime.get_recently_updated_items().await?;
+
+            updated_events.extend(
+                client
+                    .items
+                    .get_existing_items(&updated)
+                    .await?
+                    .into_iter()
+                    .map(|item| WebhookEvent::ItemUpdated { item }),
+            );
+        }
+
+        // the watcher commits its own checkpoint on success, so it's polled last once nothing else can fail
+        let mut events = Vec::new();
+
+        if let Some(watcher) = self.watcher.as_mut() {
+            events.extend(watcher.poll(client).await?.into_iter().map(|event| {
+                WebhookEvent::ItemMatched {
+                    rule: event.rule,
+                    item: event.item,
+                }
+            }));
+        }
+
+        if top_stories.is_some() {
+            self.top_stories = top_stories;
+        }
+
+        events.extend(entered_events);
+        events.extend(updated_events);
+
+        Ok(events)
+    }
+}
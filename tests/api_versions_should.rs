@@ -0,0 +1,70 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    client::{ApiVersion, HackerNewsClient, API_BASE_URL},
+    realtime::HackerNewsStoryList,
+};
+use serde_json::json;
+
+#[test]
+fn target_version_zero_by_default() {
+    // arrange
+    let client = HackerNewsClient::new();
+
+    // act
+    let version = client.version;
+
+    // assert
+    assert_eq!(version, ApiVersion::V0);
+    assert_eq!(version.base_url(), API_BASE_URL);
+}
+
+#[tokio::test]
+async fn request_resources_from_the_version_zero_layout() {
+    // arrange
+    let stub = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("maxitem", json!(105))
+        .with("topstories", json!([100]))
+        .with("askstories", json!([]))
+        .with(
+            "updates",
+            json!({ "items": [100], "profiles": ["rustacean"] }),
+        )
+        .with(
+            "user/rustacean",
+            json!({ "id": "rustacean", "created": 1600000000, "karma": 1234 }),
+        );
+    let requests = stub.requests();
+    let client = HackerNewsClient::builder()
+        .version(ApiVersion::V0)
+        .base_url(stub.serve().await)
+        .build()
+        .unwrap();
+
+    // act
+    client.items.get_item(100).await.unwrap();
+    client.users.get_user("rustacean").await.unwrap();
+    client.realtime.get_latest_item_id().await.unwrap();
+    client.realtime.get_top_stories().await.unwrap();
+    client
+        .realtime
+        .get_story_list(HackerNewsStoryList::Ask)
+        .await
+        .unwrap();
+    client.realtime.get_recent_updates().await.unwrap();
+
+    // assert
+    assert_eq!(
+        *requests.lock().unwrap(),
+        vec![
+            "item/100",
+            "user/rustacean",
+            "maxitem",
+            "topstories",
+            "askstories",
+            "updates"
+        ]
+    );
+}
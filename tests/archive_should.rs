@@ -0,0 +1,155 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{archive::HackerNewsArchive, errors::HackerNewsClientError};
+
+async fn archive() -> HackerNewsArchive {
+    archive_of(STORY_THREAD_FIXTURE).await
+}
+
+async fn archive_of(fixture: &str) -> HackerNewsArchive {
+    let client = StubApi::new().with_items(fixture).client().await;
+
+    let thread = client.items.get_thread(100).await.unwrap();
+    HackerNewsArchive::try_from(thread).unwrap()
+}
+
+#[tokio::test]
+async fn render_threads_as_self_contained_html() {
+    // arrange
+    let archive = archive().await;
+
+    // act
+    let html = archive.to_html();
+
+    // assert
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<style>"));
+    assert!(!html.contains("<link"));
+    assert!(!html.contains("<script"));
+    assert!(html.contains("<title>Show HN: A Hacker News client written in Rust</title>"));
+    assert!(html.contains("<a href=\"https://github.com/rustacean/hn-client\">"));
+    assert!(html.contains(
+        "42 points by <a href=\"https://news.ycombinator.com/user?id=rustacean\">rustacean</a>"
+    ));
+    assert!(html.contains("<time datetime=\"2023-11-14T22:13:20Z\">2023-11-14 22:13 UTC</time>"));
+    assert_eq!(html.matches("<article").count(), 5);
+    assert_eq!(html.matches("</article>").count(), 5);
+    assert!(html.contains("<article class=\"comment\" id=\"101\">"));
+    assert!(html.contains("Does it support <i>async</i>?"));
+    assert!(
+        html.contains("<a href=\"https://docs.rs/newswrap\" rel=\"nofollow noopener\">docs</a>")
+    );
+}
+
+#[tokio::test]
+async fn nest_replies_beneath_their_parents_in_html() {
+    // arrange
+    let archive = archive().await;
+
+    // act
+    let html = archive.to_html();
+
+    // assert
+    let parent = html.find("id=\"101\"").unwrap();
+    let replies = html[parent..].find("<div class=\"replies\">").unwrap() + parent;
+    let reply = html.find("id=\"103\"").unwrap();
+    let nested_reply = html.find("id=\"105\"").unwrap();
+    let sibling = html.find("id=\"102\"").unwrap();
+
+    assert!(parent < replies && replies < reply);
+    assert!(reply < nested_reply && nested_reply < sibling);
+}
+
+#[tokio::test]
+async fn render_placeholders_for_deleted_and_dead_comments() {
+    // arrange
+    let archive = archive().await;
+
+    // act
+    let html = archive.to_html();
+    let markdown = archive.to_markdown();
+
+    // assert
+    assert!(html.contains("<p class=\"placeholder\">[dead]</p>"));
+    assert!(html.contains("<p class=\"placeholder\">[deleted]</p>"));
+    assert!(!html.contains("Buy now!"));
+    assert!(markdown.contains("*\\[dead\\]*"));
+    assert!(markdown.contains("**\\[deleted\\]**"));
+    assert!(!markdown.contains("Buy now!"));
+}
+
+#[tokio::test]
+async fn render_threads_as_markdown_with_nested_quotes() {
+    // arrange
+    let archive = archive().await;
+
+    // act
+    let markdown = archive.to_markdown();
+
+    // assert
+    assert!(markdown.starts_with(
+        "# [Show HN: A Hacker News client written in Rust](https://github.com/rustacean/hn-client)\n"
+    ));
+    assert!(markdown.contains("[5 comments](https://news.ycombinator.com/item?id=100)"));
+    assert!(markdown.contains(
+        "**[alice](https://news.ycombinator.com/user?id=alice)** | [2023-11-14 22:23 UTC](https://news.ycombinator.com/item?id=101)"
+    ));
+    assert!(markdown.contains("\nThis looks great! Does it support *async*?\n"));
+    assert!(
+        markdown.contains("\n> **[rustacean](https://news.ycombinator.com/user?id=rustacean)**")
+    );
+    assert!(markdown.contains("\n> > Thanks!\n"));
+}
+
+#[tokio::test]
+async fn reject_threads_not_rooted_at_a_story() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+    let thread = client.items.get_thread(101).await.unwrap();
+
+    // act
+    let archive = HackerNewsArchive::try_from(thread);
+
+    // assert
+    assert!(matches!(
+        archive,
+        Err(HackerNewsClientError::InvalidTypeMapping(_))
+    ));
+}
+
+#[tokio::test]
+async fn link_titles_only_to_web_urls() {
+    // arrange
+    let mut items: Vec<serde_json::Value> = serde_json::from_str(STORY_THREAD_FIXTURE).unwrap();
+    items[0]["url"] = "javascript:alert(document.cookie)".into();
+    let archive = archive_of(&serde_json::to_string(&items).unwrap()).await;
+
+    // act
+    let html = archive.to_html();
+    let markdown = archive.to_markdown();
+
+    // assert
+    assert!(!html.contains("javascript:"));
+    assert!(html.contains("<h1><a href=\"https://news.ycombinator.com/item?id=100\">"));
+    assert!(!markdown.contains("javascript:"));
+    assert!(markdown.starts_with("# Show HN: A Hacker News client written in Rust\n"));
+}
+
+#[tokio::test]
+async fn escape_title_links_and_sites_in_markdown() {
+    // arrange
+    let mut items: Vec<serde_json::Value> = serde_json::from_str(STORY_THREAD_FIXTURE).unwrap();
+    items[0]["url"] = "https://github.com/my_org/Rust_(language)".into();
+    let archive = archive_of(&serde_json::to_string(&items).unwrap()).await;
+
+    // act
+    let markdown = archive.to_markdown();
+
+    // assert
+    assert!(markdown.contains("(https://github.com/my_org/Rust_%28language%29)"));
+    assert!(markdown.contains("Site: [github.com/my\\_org]("));
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use std::{
+    collections::{HashMap, HashSet},
+    net::TcpListener,
+    sync::Arc,
+    time::Duration,
+};
+
+use axum::{
+    extract::{Path, State},
+    routing::get,
+    Json, Router,
+};
+use common::STORY_THREAD_FIXTURE;
+use newswrap::{
+    cancellation::{Cancellation, CancellationToken},
+    client::HackerNewsClient,
+    errors::HackerNewsClientError,
+    HackerNewsID,
+};
+use serde_json::{json, Value};
+
+/// Serves the story thread fixture, stalling responses for the slow items well past any test's deadline.
+#[derive(Debug)]
+struct Upstream {
+    items: HashMap<String, Value>,
+    slow: HashSet<String>,
+}
+
+async fn respond(Path(path): Path<String>, State(upstream): State<Arc<Upstream>>) -> Json<Value> {
+    let id = path.trim_end_matches(".json");
+
+    if upstream.slow.contains(id) {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+    }
+
+    Json(upstream.items.get(id).cloned().unwrap_or(Value::Null))
+}
+
+/// Starts the upstream API on a random local port, returning a client pointing to it.
+fn serve(slow: &[HackerNewsID]) -> HackerNewsClient {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let items: Vec<Value> = serde_json::from_str(STORY_THREAD_FIXTURE).unwrap();
+    let upstream = Upstream {
+        items: items
+            .into_iter()
+            .map(|item| (item["id"].to_string(), item))
+            .collect(),
+        slow: slow.iter().map(ToString::to_string).collect(),
+    };
+
+    let router = Router::new()
+        .route("/v0/item/:id", get(respond))
+        .route("/v0/maxitem.json", get(|| async { Json(json!(105)) }))
+        .route("/v0/topstories.json", get(|| async { Json(json!([100])) }))
+        .with_state(Arc::new(upstream));
+
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .await
+            .unwrap();
+    });
+
+    HackerNewsClient::builder()
+        .base_url(format!("http://{}/v0", address))
+        .build()
+        .unwrap()
+}
+
+fn ids(items: &[newswrap::items::HackerNewsItem]) -> Vec<HackerNewsID> {
+    items.iter().map(|item| item.id).collect()
+}
+
+#[tokio::test]
+async fn return_items_retrieved_before_the_deadline() {
+    // arrange
+    let client = serve(&[103]);
+    let cancellation = Cancellation::new().timeout(Duration::from_millis(200));
+
+    // act
+    let items = client
+        .items
+        .get_items_until(&[100, 101, 102, 103, 104], &cancellation)
+        .await;
+
+    // assert
+    assert_eq!(ids(&items.value), vec![100, 101, 102]);
+    assert!(matches!(
+        items.error,
+        Some(HackerNewsClientError::DeadlineExceeded)
+    ));
+}
+
+#[tokio::test]
+async fn return_items_retrieved_before_cancellation() {
+    // arrange
+    let client = serve(&[102]);
+    let token = CancellationToken::new();
+    let cancellation = Cancellation::new().token(token.clone());
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        token.cancel();
+    });
+
+    // act
+    let items = client
+        .items
+        .get_items_until(&[100, 101, 102, 103], &cancellation)
+        .await;
+
+    // assert
+    assert_eq!(ids(&items.value), vec![100, 101]);
+    assert!(matches!(
+        items.error,
+        Some(HackerNewsClientError::Cancelled)
+    ));
+}
+
+#[tokio::test]
+async fn complete_when_neither_cancelled_nor_past_the_deadline() {
+    // arrange
+    let client = serve(&[]);
+    let cancellation = Cancellation::new()
+        .token(CancellationToken::new())
+        .timeout(Duration::from_secs(5));
+
+    // act
+    let items = client
+        .items
+        .get_items_until(&[100, 101], &cancellation)
+        .await;
+
+    // assert
+    assert!(items.is_complete());
+    assert_eq!(ids(&items.into_result().unwrap()), vec![100, 101]);
+}
+
+#[tokio::test]
+async fn return_partial_threads_when_the_walk_is_stopped() {
+    // arrange
+    let client = serve(&[103]);
+    let cancellation = Cancellation::new().timeout(Duration::from_millis(200));
+
+    // act
+    let thread = client
+        .items
+        .get_thread_until(100, &cancellation)
+        .await
+        .unwrap();
+
+    // assert
+    assert!(matches!(
+        thread.error,
+        Some(HackerNewsClientError::DeadlineExceeded)
+    ));
+    assert_eq!(thread.value.root.id, 100);
+    assert_eq!(thread.value.len(), 2);
+    assert!(thread.value.find(101).is_some());
+    assert!(thread.value.find(103).is_none());
+}
+
+#[tokio::test]
+async fn fail_immediately_when_already_cancelled() {
+    // arrange
+    let client = serve(&[]);
+    let token = CancellationToken::new();
+    token.cancel();
+    let cancellation = Cancellation::new().token(token);
+
+    // act
+    let items = client.items.get_items_until(&[100], &cancellation).await;
+    let thread = client.items.get_thread_until(100, &cancellation).await;
+
+    // assert
+    assert!(items.value.is_empty());
+    assert!(matches!(
+        items.error,
+        Some(HackerNewsClientError::Cancelled)
+    ));
+    assert!(matches!(thread, Err(HackerNewsClientError::Cancelled)));
+}
+
+#[cfg(feature = "watch")]
+#[tokio::test]
+async fn resume_polling_after_items_retrieved_before_the_deadline() {
+    use newswrap::watch::ItemPoller;
+
+    // arrange
+    let client = serve(&[103]);
+    let mut poller = ItemPoller::new().starting_after(100);
+    let cancellation = Cancellation::new().timeout(Duration::from_millis(200));
+
+    // act
+    let items = poller.poll_until(&client, &cancellation).await;
+
+    // assert
+    assert_eq!(ids(&items.value), vec![101, 102]);
+    assert!(matches!(
+        items.error,
+        Some(HackerNewsClientError::DeadlineExceeded)
+    ));
+    assert_eq!(poller.last_seen(), Some(102));
+}
+
+#[cfg(feature = "watch")]
+#[tokio::test]
+async fn keep_reply_checkpoints_when_polls_pass_the_deadline() {
+    use newswrap::watch::{ReplyCheckpoint, ReplyNotifier};
+
+    // arrange
+    let client = serve(&[103]);
+    let checkpoint = ReplyCheckpoint {
+        username: "rustacean".to_string(),
+        since: Some(99),
+        last_seen: Some(99),
+        notified: Vec::new(),
+    };
+    let mut notifier = ReplyNotifier::from_checkpoint(checkpoint.clone());
+    let cancellation = Cancellation::new().timeout(Duration::from_millis(200));
+
+    // act
+    let notifications = notifier.poll_until(&client, &cancellation).await;
+
+    // assert
+    assert!(matches!(
+        notifications,
+        Err(HackerNewsClientError::DeadlineExceeded)
+    ));
+    assert_eq!(notifier.checkpoint(), checkpoint);
+}
+
+#[tokio::test]
+async fn record_no_samples_when_tracking_passes_the_deadline() {
+    use newswrap::analytics::StoryTracker;
+
+    // arrange
+    let client = serve(&[100]);
+    let mut tracker = StoryTracker::new([100]);
+    let cancellation = Cancellation::new().timeout(Duration::from_millis(200));
+
+    // act
+    let samples = tracker.poll_until(&client, &cancellation).await;
+
+    // assert
+    assert!(matches!(
+        samples,
+        Err(HackerNewsClientError::DeadlineExceeded)
+    ));
+    assert!(tracker.series(100).is_none());
+}
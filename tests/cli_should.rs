@@ -0,0 +1,137 @@
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use serde_json::{json, Value};
+use tokio::process::Command;
+
+async fn run_hn(base_url: &str, args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_hn"))
+        .arg("--base-url")
+        .arg(base_url)
+        .args(args)
+        .output()
+        .await
+        .unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn print_items_as_json() {
+    // arrange
+    let base_url = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .serve()
+        .await;
+
+    // act
+    let (success, stdout) = run_hn(&base_url, &["item", "100", "--format", "json"]).await;
+
+    // assert
+    assert!(success);
+    let item: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(item["id"], 100);
+    assert_eq!(item["by"], "rustacean");
+}
+
+#[tokio::test]
+async fn print_realtime_lists_with_limits() {
+    // arrange
+    let base_url = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([100, 101, 103]))
+        .serve()
+        .await;
+
+    // act
+    let (success, stdout) = run_hn(&base_url, &["top", "--limit", "1", "--format", "table"]).await;
+
+    // assert
+    assert!(success);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("ID"));
+    assert!(lines[1].contains("Show HN: A Hacker News client written in Rust"));
+}
+
+#[tokio::test]
+async fn skip_missing_items_in_realtime_lists() {
+    // arrange
+    let base_url = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([999, 100]))
+        .serve()
+        .await;
+
+    // act
+    let (success, stdout) = run_hn(&base_url, &["top", "--format", "json"]).await;
+
+    // assert
+    assert!(success);
+    let items: Vec<Value> = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["id"], 100);
+}
+
+#[tokio::test]
+async fn print_threads_as_plain_text() {
+    // arrange
+    let base_url = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .serve()
+        .await;
+
+    // act
+    let (success, stdout) = run_hn(&base_url, &["thread", "100", "--depth", "1"]).await;
+
+    // assert
+    assert!(success);
+    assert!(stdout.starts_with("Show HN: A Hacker News client written in Rust"));
+    assert!(stdout.contains("    See the docs (https://docs.rs/newswrap)."));
+    assert!(stdout.contains("[dead]"));
+    assert!(stdout.contains("[deleted]"));
+    assert!(!stdout.contains("Thanks!"));
+}
+
+#[tokio::test]
+async fn print_users_and_updates() {
+    // arrange
+    let base_url = StubApi::new()
+        .with(
+            "user/alice",
+            json!({"id": "alice", "created": 1600000000, "karma": 1234, "about": "I like <i>Rust</i>.", "submitted": [101, 105]}),
+        )
+        .with("updates", json!({"items": [101, 105], "profiles": ["alice", "bob"]}))
+        .serve()
+        .await;
+
+    // act
+    let (user_success, user) = run_hn(&base_url, &["user", "alice"]).await;
+    let (updates_success, updates) = run_hn(&base_url, &["updates"]).await;
+
+    // assert
+    assert!(user_success);
+    assert!(user.contains("karma: 1234"));
+    assert!(user.contains("submissions: 2"));
+    assert!(user.contains("I like Rust."));
+    assert!(updates_success);
+    assert_eq!(updates, "items: 101, 105\nprofiles: alice, bob\n");
+}
+
+#[tokio::test]
+async fn fail_for_missing_items() {
+    // arrange
+    let base_url = StubApi::new().serve().await;
+
+    // act
+    let (success, stdout) = run_hn(&base_url, &["item", "12345"]).await;
+
+    // assert
+    assert!(!success);
+    assert!(stdout.is_empty());
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::client::{HackerNewsClient, API_BASE_URL};
+
+#[tokio::test]
+async fn send_requests_to_the_configured_base_url() {
+    // arrange
+    let base_url = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .serve()
+        .await;
+    let client = HackerNewsClient::builder()
+        .base_url(format!("{}/", base_url))
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    // act
+    let item = client.items.get_item(100).await.unwrap();
+
+    // assert
+    assert_eq!(item.id, 100);
+}
+
+#[test]
+fn point_to_the_public_api_by_default() {
+    // arrange
+    let builder = HackerNewsClient::builder();
+
+    // act
+    let client = builder.build();
+
+    // assert
+    assert!(client.is_ok());
+    assert_eq!(API_BASE_URL, "https://hacker-news.firebaseio.com/v0");
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    algolia::client::HackerNewsSearchClient, client::HackerNewsClient,
+    items::client::HackerNewsItemClient, realtime::client::HackerNewsRealtimeClient,
+    users::client::HackerNewsUserClient,
+};
+use serde_json::json;
+
+/// Compiles only for types that can be cloned and moved into spawned tasks.
+fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+
+#[test]
+fn be_cloneable_and_shareable_across_tasks() {
+    assert_shareable::<HackerNewsClient>();
+    assert_shareable::<HackerNewsItemClient>();
+    assert_shareable::<HackerNewsUserClient>();
+    assert_shareable::<HackerNewsRealtimeClient>();
+    assert_shareable::<HackerNewsSearchClient>();
+}
+
+#[tokio::test]
+async fn serve_requests_from_sub_clients_moved_into_spawned_tasks() {
+    // arrange
+    let stub = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("maxitem", json!(105))
+        .with(
+            "user/rustacean",
+            json!({ "id": "rustacean", "created": 1600000000, "karma": 1234 }),
+        );
+    let requests = stub.requests();
+    let client = stub.client().await;
+
+    // act
+    let items = client.items.clone();
+    let users = client.users.clone();
+    let realtime = client.realtime.clone();
+
+    let item = tokio::spawn(async move { items.get_item(100).await });
+    let user = tokio::spawn(async move { users.get_user("rustacean").await });
+    let max_item = tokio::spawn(async move { realtime.get_latest_item_id().await });
+    let cloned = tokio::spawn({
+        let client = client.clone();
+        async move { client.items.get_item(101).await }
+    });
+
+    // assert
+    assert_eq!(item.await.unwrap().unwrap().id, 100);
+    assert_eq!(user.await.unwrap().unwrap().karma, 1234);
+    assert_eq!(max_item.await.unwrap().unwrap(), 105);
+    assert_eq!(cloned.await.unwrap().unwrap().id, 101);
+    assert_eq!(requests.lock().unwrap().len(), 4);
+}
@@ -0,0 +1,116 @@
+//! A local stand-in for the Hacker News API, serving fixtures by path for tests that should not depend on the network.
+
+#![allow(dead_code)]
+
+use std::{
+    collections::HashMap,
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    extract::{Path, RawQuery, State},
+    routing::get,
+    Json, Router,
+};
+use newswrap::client::HackerNewsClient;
+use serde_json::Value;
+
+pub const STORY_THREAD_FIXTURE: &str = include_str!("../fixtures/threads/story_thread.json");
+
+/// Serves JSON responses keyed by their path relative to the API version, e.g. `item/8863`,
+/// returning `null` for unknown paths as the Firebase API does. Paths under the search API's
+/// `/api/v1` prefix are served from the same responses, preferring those registered with the
+/// request's query string, e.g. `search?query=rust`.
+#[derive(Debug, Default)]
+pub struct StubApi {
+    responses: HashMap<String, Value>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+/// Shared state for the stub's handler, recording each requested path.
+#[derive(Debug)]
+struct StubState {
+    responses: HashMap<String, Value>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl StubApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a response for the path, e.g. `topstories`, `user/pg` or `search?query=rust`.
+    pub fn with(mut self, path: &str, body: Value) -> Self {
+        self.responses.insert(path.to_string(), body);
+        self
+    }
+
+    /// Registers each item within the JSON array fixture under its `item/{id}` path.
+    pub fn with_items(mut self, fixture: &str) -> Self {
+        let items: Vec<Value> = serde_json::from_str(fixture).expect("fixture should be an array");
+
+        for item in items {
+            let id = item["id"].as_u64().expect("items should have an ID");
+            self.responses.insert(format!("item/{}", id), item);
+        }
+
+        self
+    }
+
+    /// The paths requested once serving, in the order they were received, e.g. `item/8863`.
+    pub fn requests(&self) -> Arc<Mutex<Vec<String>>> {
+        self.requests.clone()
+    }
+
+    /// Starts serving responses on a random local port, returning the API base URL including the version path.
+    pub async fn serve(self) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let router = Router::new()
+            .route("/v0/*path", get(respond))
+            .route("/api/v1/*path", get(respond))
+            .with_state(Arc::new(StubState {
+                responses: self.responses,
+                requests: self.requests,
+            }));
+
+        tokio::spawn(async move {
+            axum::Server::from_tcp(listener)
+                .unwrap()
+                .serve(router.into_make_service())
+                .await
+                .unwrap();
+        });
+
+        format!("http://{}/v0", address)
+    }
+
+    /// Starts serving responses and builds a client pointing to them.
+    pub async fn client(self) -> HackerNewsClient {
+        let base_url = self.serve().await;
+        let search_base_url = format!("{}/api/v1", base_url.trim_end_matches("/v0"));
+
+        HackerNewsClient::builder()
+            .base_url(base_url)
+            .search_base_url(search_base_url)
+            .build()
+            .unwrap()
+    }
+}
+
+async fn respond(
+    Path(path): Path<String>,
+    RawQuery(query): RawQuery,
+    State(state): State<Arc<StubState>>,
+) -> Json<Value> {
+    let path = path.trim_end_matches(".json");
+    state.requests.lock().unwrap().push(path.to_string());
+
+    let response = query
+        .and_then(|query| state.responses.get(&format!("{}?{}", path, query)))
+        .or_else(|| state.responses.get(path));
+
+    Json(response.cloned().unwrap_or(Value::Null))
+}
@@ -0,0 +1,265 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use futures::{stream, StreamExt};
+#[cfg(feature = "csv")]
+use newswrap::users::HackerNewsUser;
+use newswrap::{
+    errors::HackerNewsClientError,
+    export::{JsonLinesExporter, RecordExporter},
+    items::HackerNewsItem,
+};
+
+#[cfg(feature = "csv")]
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+#[cfg(feature = "csv")]
+const COMMENT_FIXTURE: &str = include_str!("fixtures/items/comment.json");
+
+/// A writer that fails every write, standing in for a full disk or closed pipe.
+struct FailingWriter;
+
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn fixture_items() -> Vec<HackerNewsItem> {
+    serde_json::from_str(STORY_THREAD_FIXTURE).unwrap()
+}
+
+#[cfg(feature = "csv")]
+fn user() -> HackerNewsUser {
+    serde_json::from_str(
+        r#"{"id":"pg","created":1160418092,"karma":157236,"about":"Bug fixer, essayist","submitted":[3, 2, 1]}"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn write_items_as_json_lines() {
+    // arrange
+    let items = fixture_items();
+    let mut exporter = JsonLinesExporter::<_, HackerNewsItem>::new(Vec::new());
+
+    // act
+    let written = exporter.write_all(&items).unwrap();
+    let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+    // assert
+    assert_eq!(written, items.len());
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), items.len());
+
+    let first: HackerNewsItem = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(first, items[0]);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn write_items_as_csv_with_stable_columns() {
+    use newswrap::export::CsvExporter;
+
+    // arrange
+    let items = fixture_items();
+    let mut exporter = CsvExporter::<_, HackerNewsItem>::new(Vec::new());
+
+    // act
+    exporter.write_all(&items[..2]).unwrap();
+    let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+    // assert
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines[0],
+        "id,type,by,time,title,url,text,score,descendants,parent,poll,kids,parts,deleted,dead"
+    );
+    assert_eq!(
+        lines[1],
+        "100,story,rustacean,2023-11-14T22:13:20Z,Show HN: A Hacker News client written in Rust,https://github.com/rustacean/hn-client,,42,5,,,101 102,,false,false"
+    );
+    assert_eq!(
+        lines[2],
+        "101,comment,alice,2023-11-14T22:23:20Z,,,This looks great! Does it support <i>async</i>?,,,100,,103 104,,false,false"
+    );
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn write_header_for_empty_csv_exports() {
+    use newswrap::export::CsvExporter;
+
+    // arrange
+    let exporter = CsvExporter::<_, HackerNewsUser>::new(Vec::new());
+
+    // act
+    let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+    // assert
+    assert_eq!(output, "id,created,karma,about,submitted\n");
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn fail_csv_writes_for_timestamps_that_cannot_be_formatted() {
+    use newswrap::export::CsvExporter;
+    use time::UtcOffset;
+
+    // arrange
+    let mut user = user();
+    user.created = user
+        .created
+        .to_offset(UtcOffset::from_hms(0, 0, 30).unwrap());
+    let mut exporter = CsvExporter::new(Vec::new());
+
+    // act
+    let result = exporter.write(&user);
+
+    // assert
+    assert!(matches!(result, Err(HackerNewsClientError::ExportError(_))));
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn write_typed_items_and_users_as_csv() {
+    use newswrap::{
+        export::CsvExporter,
+        items::{comments::HackerNewsComment, stories::HackerNewsStory},
+    };
+
+    // arrange
+    let story: HackerNewsStory = serde_json::from_str::<HackerNewsItem>(STORY_FIXTURE)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let comment: HackerNewsComment = serde_json::from_str::<HackerNewsItem>(COMMENT_FIXTURE)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let mut stories = CsvExporter::new(Vec::new());
+    let mut comments = CsvExporter::new(Vec::new());
+    let mut users = CsvExporter::new(Vec::new());
+
+    // act
+    stories.write(&story).unwrap();
+    comments.write(&comment).unwrap();
+    users.write(&user()).unwrap();
+
+    let stories = String::from_utf8(stories.finish().unwrap()).unwrap();
+    let comments = String::from_utf8(comments.finish().unwrap()).unwrap();
+    let users = String::from_utf8(users.finish().unwrap()).unwrap();
+
+    // assert
+    assert!(stories.starts_with("id,type,by,time,title"));
+    assert!(stories
+        .lines()
+        .nth(1)
+        .unwrap()
+        .starts_with("8863,story,dhouston,"));
+    assert!(comments.lines().nth(1).unwrap().contains(",comment,"));
+    assert_eq!(
+        users.lines().nth(1).unwrap(),
+        "pg,2006-10-09T18:21:32Z,157236,\"Bug fixer, essayist\",3 2 1"
+    );
+}
+
+#[tokio::test]
+async fn write_batch_fetches_and_streams() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+
+    let batch = client.items.get_items(&[100, 101]).await.unwrap();
+    let items = stream::iter([103, 105]).then(|id| client.items.get_item(id));
+    let mut exporter = JsonLinesExporter::<_, HackerNewsItem>::new(Vec::new());
+
+    // act
+    exporter.write_all(batch).unwrap();
+    let streamed = exporter.write_stream(items).await.unwrap();
+    let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+    // assert
+    assert_eq!(streamed, 2);
+
+    let ids: Vec<u32> = output
+        .lines()
+        .map(|line| serde_json::from_str::<HackerNewsItem>(line).unwrap().id)
+        .collect();
+    assert_eq!(ids, vec![100, 101, 103, 105]);
+}
+
+#[tokio::test]
+async fn stop_streams_at_the_first_failed_record() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+
+    let items = stream::iter([100, 999, 101]).then(|id| client.items.get_item(id));
+    let mut exporter = JsonLinesExporter::<_, HackerNewsItem>::new(Vec::new());
+
+    // act
+    let result = exporter.write_stream(items).await;
+    let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(HackerNewsClientError::ItemOrUserNotFound(_))
+    ));
+    assert_eq!(output.lines().count(), 1);
+}
+
+#[test]
+fn carry_the_writer_error_as_the_source() {
+    // arrange
+    let items = fixture_items();
+    let mut exporter = JsonLinesExporter::<_, HackerNewsItem>::new(FailingWriter);
+
+    // act
+    let result = exporter.write(&items[0]);
+
+    // assert
+    let error = result.unwrap_err();
+    assert!(matches!(error, HackerNewsClientError::ExportError(_)));
+
+    let source =
+        std::error::Error::source(&error).expect("export errors should carry their source");
+    assert!(source.to_string().contains("disk full"));
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn write_items_as_parquet() {
+    use newswrap::export::{to_record_batch, ParquetExporter};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    // arrange
+    let items = fixture_items();
+    let mut exporter = ParquetExporter::<_, HackerNewsItem>::new(Vec::new()).unwrap();
+
+    // act
+    exporter.write_all(&items).unwrap();
+    let output = exporter.finish().unwrap();
+    let batch = to_record_batch::<HackerNewsItem, _>(&items).unwrap();
+
+    // assert
+    let reader = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(output))
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches: Vec<_> = reader.map(Result::unwrap).collect();
+
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].num_rows(), items.len());
+    assert_eq!(batches[0], batch);
+    assert_eq!(batch.schema().field(11).name(), "kids");
+}
@@ -0,0 +1,201 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    feeds::HackerNewsFeed,
+    items::{stories::HackerNewsStory, stories::StoryKind, HackerNewsItem},
+    realtime::HackerNewsStoryList,
+};
+use serde_json::json;
+
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+const JOB_FIXTURE: &str = include_str!("fixtures/items/job.json");
+
+fn story(fixture: &str) -> HackerNewsStory {
+    serde_json::from_str::<HackerNewsItem>(fixture)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+fn ask_story() -> HackerNewsStory {
+    story(
+        r#"{"by":"asker","descendants":12,"id":200,"score":7,"time":1700000000,"title":"Ask HN: Tabs &amp; spaces?","type":"story","text":"Which <i>do</i> you use?"}"#,
+    )
+}
+
+fn count(document: &str, element: &str) -> usize {
+    document.matches(element).count()
+}
+
+#[test]
+fn render_stories_as_rss() {
+    // arrange
+    let stories = [story(STORY_FIXTURE), ask_story()];
+    let feed = HackerNewsFeed::new("Best of HN")
+        .link("https://news.ycombinator.com/best")
+        .with_stories(&stories);
+
+    // act
+    let rss = feed.to_rss();
+
+    // assert
+    assert!(rss.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\""));
+    assert!(rss.contains("<title>Best of HN</title>"));
+    assert!(rss.contains("<link>https://news.ycombinator.com/best</link>"));
+    assert!(rss.contains("<lastBuildDate>Tue, 14 Nov 2023 22:13:20 +0000</lastBuildDate>"));
+    assert_eq!(count(&rss, "<item>"), 2);
+    assert_eq!(count(&rss, "</item>"), 2);
+
+    assert!(rss.contains("<title>My YC app: Dropbox - Throw away your USB drive</title>"));
+    assert!(rss.contains("<link>http://www.getdropbox.com/u/2/screencast.html</link>"));
+    assert!(rss.contains("<comments>https://news.ycombinator.com/item?id=8863</comments>"));
+    assert!(rss.contains("<dc:creator>dhouston</dc:creator>"));
+    assert!(rss.contains("<pubDate>Wed, 04 Apr 2007 19:16:40 +0000</pubDate>"));
+    assert!(rss.contains("&lt;p&gt;111 points by dhouston"));
+
+    assert!(rss.contains("<title>Ask HN: Tabs &amp; spaces?</title>"));
+    assert!(rss.contains("<link>https://news.ycombinator.com/item?id=200</link>"));
+    assert!(rss.contains("<category>Ask</category>"));
+    assert!(rss.contains("Which &lt;i&gt;do&lt;/i&gt; you use?"));
+}
+
+#[test]
+fn render_stories_as_atom() {
+    // arrange
+    let stories = [story(STORY_FIXTURE), ask_story()];
+    let feed = HackerNewsFeed::new("Best of HN").with_stories(&stories);
+
+    // act
+    let atom = feed.to_atom();
+
+    // assert
+    assert!(atom.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+    assert!(atom.contains("<id>https://news.ycombinator.com</id>"));
+    assert!(atom.contains("<updated>2023-11-14T22:13:20Z</updated>"));
+    assert_eq!(count(&atom, "<entry>"), 2);
+    assert!(atom.contains("<id>https://news.ycombinator.com/item?id=8863</id>"));
+    assert!(atom.contains(
+        "<link rel=\"alternate\" href=\"http://www.getdropbox.com/u/2/screencast.html\"/>"
+    ));
+    assert!(atom.contains(
+        "<link rel=\"replies\" type=\"text/html\" href=\"https://news.ycombinator.com/item?id=8863\"/>"
+    ));
+    assert!(atom.contains("<author><name>dhouston</name></author>"));
+    assert!(atom.contains("<published>2007-04-04T19:16:40Z</published>"));
+    assert!(atom.contains("<category term=\"Ask\"/>"));
+}
+
+#[test]
+fn filter_entries_by_score_comments_kind_and_limit() {
+    // arrange
+    let stories = [story(STORY_FIXTURE), ask_story()];
+    let job: HackerNewsItem = serde_json::from_str(JOB_FIXTURE).unwrap();
+    let jobs = [job.try_into().unwrap()];
+
+    // act
+    let by_score = HackerNewsFeed::new("Scored")
+        .min_score(50)
+        .with_stories(&stories)
+        .with_jobs(&jobs)
+        .to_rss();
+    let by_comments = HackerNewsFeed::new("Discussed")
+        .min_comments(20)
+        .with_stories(&stories)
+        .to_rss();
+    let by_kind = HackerNewsFeed::new("Asks")
+        .kinds([StoryKind::Ask])
+        .with_stories(&stories)
+        .with_jobs(&jobs)
+        .to_rss();
+    let limited = HackerNewsFeed::new("Limited")
+        .limit(1)
+        .with_jobs(&jobs)
+        .with_stories(&stories)
+        .to_rss();
+
+    // assert
+    assert_eq!(count(&by_score, "<item>"), 1);
+    assert!(by_score.contains("?id=8863"));
+    assert_eq!(count(&by_comments, "<item>"), 1);
+    assert!(by_comments.contains("?id=8863"));
+    assert_eq!(count(&by_kind, "<item>"), 1);
+    assert!(by_kind.contains("?id=200"));
+    assert_eq!(count(&limited, "<item>"), 1);
+    assert!(limited.contains("Justin.tv is looking for a Lead Flash Engineer!"));
+}
+
+#[tokio::test]
+async fn load_realtime_lists() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("item/192327", serde_json::from_str(JOB_FIXTURE).unwrap())
+        .with("showstories", json!([100, 101]))
+        .with("jobstories", json!([192327]))
+        .client()
+        .await;
+
+    // act
+    let show = HackerNewsFeed::new("Show HN")
+        .kinds([StoryKind::Show])
+        .load_list(&client, HackerNewsStoryList::Show)
+        .await
+        .unwrap()
+        .to_atom();
+    let jobs = HackerNewsFeed::new("Jobs")
+        .load_list(&client, HackerNewsStoryList::Jobs)
+        .await
+        .unwrap()
+        .to_rss();
+
+    // assert
+    assert_eq!(count(&show, "<entry>"), 1);
+    assert!(show.contains("<title>Show HN: A Hacker News client written in Rust</title>"));
+    assert!(show.contains("<category term=\"Show\"/>"));
+    assert_eq!(count(&jobs, "<item>"), 1);
+    assert!(jobs.contains("<link>https://www.justin.tv/jobs</link>"));
+}
+
+#[tokio::test]
+async fn page_through_limited_lists_until_filled_skipping_missing_items() {
+    // arrange
+    let api = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("item/192327", serde_json::from_str(JOB_FIXTURE).unwrap())
+        .with("topstories", json!([100, 999, 101, 192327, 102]));
+    let requests = api.requests();
+    let client = api.client().await;
+
+    // act
+    let feed = HackerNewsFeed::new("Top")
+        .limit(2)
+        .load_list(&client, HackerNewsStoryList::Top)
+        .await
+        .unwrap()
+        .to_rss();
+
+    // assert
+    assert_eq!(count(&feed, "<item>"), 2);
+
+    let requests = requests.lock().unwrap();
+    assert!(requests.contains(&"item/999".to_string()));
+    assert!(requests.contains(&"item/192327".to_string()));
+    assert!(!requests.contains(&"item/102".to_string()));
+}
+
+#[test]
+fn omit_authors_of_entries_without_one() {
+    // arrange
+    let mut anonymous = story(STORY_FIXTURE);
+    anonymous.by = String::new();
+    let feed = HackerNewsFeed::new("Anonymous").with_stories([&anonymous]);
+
+    // act
+    let atom = feed.to_atom();
+    let rss = feed.to_rss();
+
+    // assert
+    assert!(!atom.contains("<author>"));
+    assert!(!rss.contains("<dc:creator>"));
+}
@@ -0,0 +1,190 @@
+#![cfg(feature = "graphql")]
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::graphql::{self, HackerNewsSchema};
+use serde_json::{json, Value};
+
+async fn schema() -> (HackerNewsSchema, Arc<Mutex<Vec<String>>>) {
+    let stub = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([100, 8863]))
+        .with(
+            "user/rustacean",
+            json!({ "id": "rustacean", "created": 1600000000, "karma": 1234, "submitted": [103, 100] }),
+        )
+        .with(
+            "user/alice",
+            json!({ "id": "alice", "created": 1650000000, "karma": 56, "submitted": [105, 101] }),
+        );
+    let requests = stub.requests();
+    let client = stub.client().await;
+
+    (graphql::schema(client), requests)
+}
+
+async fn execute(schema: &HackerNewsSchema, query: &str) -> Value {
+    let response = schema.execute(query).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    response.data.into_json().unwrap()
+}
+
+#[tokio::test]
+async fn resolve_nested_stories_comments_and_authors() {
+    // arrange
+    let (schema, _) = schema().await;
+
+    // act
+    let data = execute(
+        &schema,
+        "{ story(id: 100) { title author { karma } comments { id deleted author { id } replies { id } } } }",
+    )
+    .await;
+
+    // assert
+    assert_eq!(
+        data,
+        json!({
+            "story": {
+                "title": "Show HN: A Hacker News client written in Rust",
+                "author": { "karma": 1234 },
+                "comments": [
+                    { "id": 101, "deleted": false, "author": { "id": "alice" }, "replies": [{ "id": 103 }, { "id": 104 }] },
+                    { "id": 102, "deleted": true, "author": null, "replies": [] }
+                ]
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn load_each_item_and_user_once_per_query() {
+    // arrange
+    let (schema, requests) = schema().await;
+
+    // act
+    execute(
+        &schema,
+        "{ story(id: 100) { author { id } comments { author { id } replies { author { id } replies { author { id } parent { id } } } } } }",
+    )
+    .await;
+
+    // assert
+    let requests = requests.lock().unwrap();
+    let count = |path: &str| requests.iter().filter(|request| *request == path).count();
+
+    assert_eq!(count("user/alice"), 1);
+    assert_eq!(count("user/rustacean"), 1);
+
+    for id in 100..=105 {
+        assert_eq!(count(&format!("item/{}", id)), 1, "item {}", id);
+    }
+}
+
+#[tokio::test]
+async fn resolve_realtime_lists() {
+    // arrange
+    let (schema, _) = schema().await;
+
+    // act
+    let data = execute(
+        &schema,
+        "{ storyIds(list: TOP) stories(list: TOP, first: 1) { id type score } }",
+    )
+    .await;
+
+    // assert
+    assert_eq!(
+        data,
+        json!({
+            "storyIds": [100, 8863],
+            "stories": [{ "id": 100, "type": "STORY", "score": 42 }]
+        })
+    );
+}
+
+#[tokio::test]
+async fn resolve_user_submissions() {
+    // arrange
+    let (schema, _) = schema().await;
+
+    // act
+    let data = execute(
+        &schema,
+        r#"{ user(username: "rustacean") { karma submissions(first: 1) { id type parent { by } } } missing: user(username: "nobody") { id } }"#,
+    )
+    .await;
+
+    // assert
+    assert_eq!(
+        data,
+        json!({
+            "user": {
+                "karma": 1234,
+                "submissions": [{ "id": 103, "type": "COMMENT", "parent": { "by": "alice" } }]
+            },
+            "missing": null
+        })
+    );
+}
+
+#[tokio::test]
+async fn report_errors_for_mismatched_item_types() {
+    // arrange
+    let (schema, _) = schema().await;
+
+    // act
+    let response = schema.execute("{ comment(id: 100) { id } }").await;
+
+    // assert
+    assert_eq!(response.errors.len(), 1);
+    assert!(response.errors[0].message.contains("not a valid"));
+}
+
+#[tokio::test]
+async fn reject_queries_nested_too_deeply_or_requesting_too_many_items() {
+    // arrange
+    let (schema, requests) = schema().await;
+    let deep = format!(
+        "{{ comment(id: 101) {}{}{} }}",
+        "{ replies(first: 1) ".repeat(20),
+        "{ id }",
+        " }".repeat(20)
+    );
+
+    // act
+    let deep = schema.execute(deep).await;
+    let wide = schema
+        .execute("{ stories(list: TOP, first: 500) { kids(first: 500) { id } } }")
+        .await;
+
+    // assert
+    assert_eq!(deep.errors.len(), 1);
+    assert!(deep.errors[0].message.contains("nested too deep"));
+    assert_eq!(wide.errors.len(), 1);
+    assert!(wide.errors[0].message.contains("too complex"));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn resolve_the_first_thirty_kids_by_default() {
+    // arrange
+    let kids: Vec<u32> = (201..=240).collect();
+    let stub = StubApi::new().with(
+        "item/200",
+        json!({ "id": 200, "type": "story", "by": "rustacean", "time": 1700000000, "title": "Busy", "kids": kids }),
+    );
+    let requests = stub.requests();
+    let schema = graphql::schema(stub.client().await);
+
+    // act
+    schema.execute("{ item(id: 200) { kids { id } } }").await;
+
+    // assert
+    let requests = requests.lock().unwrap();
+    assert!(requests.contains(&"item/230".to_string()));
+    assert!(!requests.contains(&"item/231".to_string()));
+}
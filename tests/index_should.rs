@@ -0,0 +1,186 @@
+#![cfg(feature = "index")]
+
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    index::{HackerNewsIndex, SearchOrder, SearchQuery},
+    items::{HackerNewsItem, HackerNewsItemType},
+};
+use time::OffsetDateTime;
+
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+const JOB_FIXTURE: &str = include_str!("fixtures/items/job.json");
+
+fn index() -> HackerNewsIndex {
+    let mut items: Vec<HackerNewsItem> = serde_json::from_str(STORY_THREAD_FIXTURE).unwrap();
+    items.push(serde_json::from_str(STORY_FIXTURE).unwrap());
+    items.push(serde_json::from_str(JOB_FIXTURE).unwrap());
+
+    items.into_iter().collect()
+}
+
+fn ids(index: &HackerNewsIndex, query: &SearchQuery) -> Vec<u32> {
+    index.search(query).iter().map(|hit| hit.item.id).collect()
+}
+
+#[test]
+fn rank_title_matches_above_text_matches() {
+    // arrange
+    let index = index();
+
+    // act
+    let hits = index.search(&SearchQuery::new("Rust"));
+
+    // assert
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].item.id, 100);
+    assert!(hits[0].relevance > 0.0);
+
+    let async_ids = ids(&index, &SearchQuery::new("async client"));
+    assert_eq!(async_ids[0], 100);
+    assert!(async_ids.contains(&101));
+    assert!(async_ids.contains(&103));
+}
+
+#[test]
+fn match_authors_and_domains() {
+    // arrange
+    let index = index();
+
+    // act
+    let by_author_term = ids(&index, &SearchQuery::new("dhouston"));
+    let by_domain_term = ids(&index, &SearchQuery::new("getdropbox"));
+    let by_author = ids(
+        &index,
+        &SearchQuery::new("")
+            .author("Alice")
+            .order(SearchOrder::Newest),
+    );
+    let by_domain = ids(&index, &SearchQuery::new("").domain("github.com"));
+
+    // assert
+    assert_eq!(by_author_term, vec![8863]);
+    assert_eq!(by_domain_term, vec![8863]);
+    assert_eq!(by_author, vec![105, 101]);
+    assert_eq!(by_domain, vec![100]);
+}
+
+#[test]
+fn filter_by_type_score_and_time() {
+    // arrange
+    let index = index();
+    let since_2020 = OffsetDateTime::from_unix_timestamp(1_577_836_800).unwrap();
+
+    // act
+    let stories = ids(
+        &index,
+        &SearchQuery::new("")
+            .item_type(HackerNewsItemType::Story)
+            .order(SearchOrder::Score),
+    );
+    let popular = ids(&index, &SearchQuery::new("").min_score(100));
+    let recent_stories = ids(
+        &index,
+        &SearchQuery::new("")
+            .item_type(HackerNewsItemType::Story)
+            .after(since_2020),
+    );
+    let older = ids(
+        &index,
+        &SearchQuery::new("")
+            .before(since_2020)
+            .order(SearchOrder::Newest),
+    );
+
+    // assert
+    assert_eq!(stories, vec![8863, 100]);
+    assert_eq!(popular, vec![8863]);
+    assert_eq!(recent_stories, vec![100]);
+    assert_eq!(older, vec![192327, 8863]);
+}
+
+#[test]
+fn replace_and_remove_items() {
+    // arrange
+    let mut index = index();
+    let mut story: HackerNewsItem = serde_json::from_str(STORY_FIXTURE).unwrap();
+    story.title = Some("Dropbox launches on Hacker News".to_string());
+
+    // act
+    index.insert(story);
+    let renamed = ids(&index, &SearchQuery::new("launches"));
+    let stale = ids(&index, &SearchQuery::new("USB"));
+    let removed = index.remove(8863);
+
+    // assert
+    assert_eq!(renamed, vec![8863]);
+    assert!(stale.is_empty());
+    assert!(removed.is_some());
+    assert!(index.get(8863).is_none());
+    assert!(ids(&index, &SearchQuery::new("dropbox")).is_empty());
+}
+
+#[test]
+fn limit_hits() {
+    // arrange
+    let index = index();
+
+    // act
+    let hits = index.search(&SearchQuery::new("").limit(3));
+
+    // assert
+    assert_eq!(index.len(), 8);
+    assert_eq!(hits.len(), 3);
+}
+
+#[test]
+fn persist_and_load_indexes() {
+    // arrange
+    let index = index();
+
+    // act
+    let serialized = serde_json::to_string(&index).unwrap();
+    let loaded: HackerNewsIndex = serde_json::from_str(&serialized).unwrap();
+
+    // assert
+    assert_eq!(loaded.len(), index.len());
+    assert_eq!(
+        ids(&loaded, &SearchQuery::new("async")),
+        ids(&index, &SearchQuery::new("async"))
+    );
+}
+
+#[tokio::test]
+async fn index_items_as_they_are_fetched() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+    let mut index = HackerNewsIndex::new();
+
+    // act
+    let indexed = index.fetch(&client, &[100, 101, 103]).await.unwrap();
+
+    // assert
+    assert_eq!(indexed, 3);
+    assert_eq!(ids(&index, &SearchQuery::new("docs")), vec![103]);
+}
+
+#[tokio::test]
+async fn skip_missing_items_when_fetching() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+    let mut index = HackerNewsIndex::new();
+
+    // act
+    let indexed = index.fetch(&client, &[103, 999]).await.unwrap();
+
+    // assert
+    assert_eq!(indexed, 1);
+    assert_eq!(ids(&index, &SearchQuery::new("docs")), vec![103]);
+}
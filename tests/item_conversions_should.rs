@@ -0,0 +1,274 @@
+use newswrap::{
+    errors::HackerNewsClientError,
+    items::{
+        comments::HackerNewsComment, jobs::HackerNewsJob, poll_options::HackerNewsPollOption,
+        polls::HackerNewsPoll, stories::HackerNewsStory, HackerNewsItem, HackerNewsItemType,
+    },
+};
+
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+const COMMENT_FIXTURE: &str = include_str!("fixtures/items/comment.json");
+const JOB_FIXTURE: &str = include_str!("fixtures/items/job.json");
+const POLL_FIXTURE: &str = include_str!("fixtures/items/poll.json");
+const POLL_OPTION_FIXTURE: &str = include_str!("fixtures/items/poll_option.json");
+const POLL_OPTION_MISSING_POLL_FIXTURE: &str =
+    include_str!("fixtures/items/poll_option_missing_poll.json");
+const STORY_MISSING_FIELDS_FIXTURE: &str = include_str!("fixtures/items/story_missing_fields.json");
+const MISSING_TYPE_FIXTURE: &str = include_str!("fixtures/items/missing_type.json");
+const MISSING_TIME_FIXTURE: &str = include_str!("fixtures/items/missing_time.json");
+const INVALID_SCORE_FIXTURE: &str = include_str!("fixtures/items/invalid_score.json");
+
+fn load_item(fixture: &str) -> HackerNewsItem {
+    serde_json::from_str(fixture).expect("fixture should be a valid item")
+}
+
+/// Converts the item into its typed variant, back into an item, and once more into the typed variant.
+fn assert_round_trip<T>(fixture: &str)
+where
+    T: TryFrom<HackerNewsItem, Error = HackerNewsClientError>
+        + Into<HackerNewsItem>
+        + Clone
+        + PartialEq
+        + std::fmt::Debug,
+{
+    let item = load_item(fixture);
+    let item_type = item.get_item_type();
+    let typed: T = item
+        .try_into()
+        .expect("fixture should convert to its typed item");
+
+    let converted_item: HackerNewsItem = typed.clone().into();
+    assert_eq!(converted_item.get_item_type(), item_type);
+
+    let round_tripped: T = converted_item
+        .try_into()
+        .expect("converted item should convert back to its typed item");
+    assert_eq!(round_tripped, typed);
+}
+
+/// Serializes the typed item and deserializes it back into the same value.
+fn assert_serde_round_trip<T>(fixture: &str)
+where
+    T: TryFrom<HackerNewsItem, Error = HackerNewsClientError>
+        + serde::Serialize
+        + for<'de> serde::Deserialize<'de>
+        + PartialEq
+        + std::fmt::Debug,
+{
+    let typed: T = load_item(fixture)
+        .try_into()
+        .expect("fixture should convert to its typed item");
+
+    let serialized = serde_json::to_string(&typed).unwrap();
+    let deserialized: T = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, typed);
+}
+
+#[test]
+fn convert_stories_both_ways() {
+    // arrange
+    let item = load_item(STORY_FIXTURE);
+
+    // act
+    let story: HackerNewsStory = item.clone().try_into().unwrap();
+    let converted_item: HackerNewsItem = story.clone().into();
+
+    // assert
+    assert_eq!(story.id, 8863);
+    assert_eq!(story.by, "dhouston");
+    assert_eq!(story.number_of_comments, 71);
+    assert_eq!(story.comments.len(), 5);
+    assert_eq!(converted_item, item);
+    assert_round_trip::<HackerNewsStory>(STORY_FIXTURE);
+    assert_serde_round_trip::<HackerNewsStory>(STORY_FIXTURE);
+}
+
+#[test]
+fn convert_comments_both_ways() {
+    // arrange
+    let item = load_item(COMMENT_FIXTURE);
+
+    // act
+    let comment: HackerNewsComment = item.clone().try_into().unwrap();
+    let converted_item: HackerNewsItem = comment.clone().into();
+
+    // assert
+    assert_eq!(comment.parent_story, 2921506);
+    assert_eq!(comment.sub_comments, vec![2922097, 2922429, 2924562]);
+    assert_eq!(converted_item, item);
+    assert_round_trip::<HackerNewsComment>(COMMENT_FIXTURE);
+    assert_serde_round_trip::<HackerNewsComment>(COMMENT_FIXTURE);
+}
+
+#[test]
+fn carry_deleted_and_dead_flags_on_comments() {
+    // arrange
+    let mut item = load_item(COMMENT_FIXTURE);
+    item.deleted = Some(true);
+    item.dead = Some(true);
+
+    // act
+    let comment: HackerNewsComment = item.clone().try_into().unwrap();
+    let converted_item: HackerNewsItem = comment.clone().into();
+
+    // assert
+    assert!(comment.deleted);
+    assert!(comment.dead);
+    assert!(!comment.is_visible());
+    assert_eq!(converted_item.deleted, Some(true));
+    assert_eq!(converted_item.dead, Some(true));
+}
+
+#[test]
+fn convert_jobs_both_ways() {
+    // arrange
+    let item = load_item(JOB_FIXTURE);
+
+    // act
+    let job: HackerNewsJob = item.clone().try_into().unwrap();
+    let converted_item: HackerNewsItem = job.clone().into();
+
+    // assert
+    assert_eq!(job.title, "Justin.tv is looking for a Lead Flash Engineer!");
+    assert_eq!(job.url, "https://www.justin.tv/jobs");
+    assert_eq!(converted_item, item);
+    assert_round_trip::<HackerNewsJob>(JOB_FIXTURE);
+    assert_serde_round_trip::<HackerNewsJob>(JOB_FIXTURE);
+}
+
+#[test]
+fn convert_polls_both_ways() {
+    // arrange
+    let item = load_item(POLL_FIXTURE);
+
+    // act
+    let poll: HackerNewsPoll = item.clone().try_into().unwrap();
+    let converted_item: HackerNewsItem = poll.clone().into();
+
+    // assert
+    assert_eq!(poll.participants, 54);
+    assert_eq!(poll.poll_options, vec![126810, 126811, 126812]);
+    assert_eq!(converted_item, item);
+    assert_round_trip::<HackerNewsPoll>(POLL_FIXTURE);
+    assert_serde_round_trip::<HackerNewsPoll>(POLL_FIXTURE);
+}
+
+#[test]
+fn convert_poll_options_both_ways() {
+    // arrange
+    let item = load_item(POLL_OPTION_FIXTURE);
+
+    // act
+    let poll_option: HackerNewsPollOption = item.clone().try_into().unwrap();
+    let converted_item: HackerNewsItem = poll_option.clone().into();
+
+    // assert
+    assert_eq!(poll_option.poll, 160704);
+    assert_eq!(poll_option.score, 335);
+    assert_eq!(converted_item, item);
+    assert_round_trip::<HackerNewsPollOption>(POLL_OPTION_FIXTURE);
+    assert_serde_round_trip::<HackerNewsPollOption>(POLL_OPTION_FIXTURE);
+}
+
+#[test]
+fn return_err_when_converting_comments_into_jobs() {
+    // arrange
+    let item = load_item(COMMENT_FIXTURE);
+
+    // act
+    let job_result: Result<HackerNewsJob, HackerNewsClientError> = item.try_into();
+
+    // assert
+    assert!(matches!(
+        job_result,
+        Err(HackerNewsClientError::InvalidTypeMapping(
+            HackerNewsItemType::Comment
+        ))
+    ));
+}
+
+#[test]
+fn return_err_when_item_type_does_not_match() {
+    // arrange
+    let story = load_item(STORY_FIXTURE);
+    let job = load_item(JOB_FIXTURE);
+    let poll = load_item(POLL_FIXTURE);
+    let poll_option = load_item(POLL_OPTION_FIXTURE);
+    let comment = load_item(COMMENT_FIXTURE);
+
+    // act
+    let story_as_comment = HackerNewsComment::try_from(story);
+    let job_as_story = HackerNewsStory::try_from(job);
+    let poll_as_poll_option = HackerNewsPollOption::try_from(poll);
+    let poll_option_as_poll = HackerNewsPoll::try_from(poll_option);
+    let comment_as_story = HackerNewsStory::try_from(comment);
+
+    // assert
+    assert!(story_as_comment.is_err());
+    assert!(job_as_story.is_err());
+    assert!(poll_as_poll_option.is_err());
+    assert!(poll_option_as_poll.is_err());
+    assert!(comment_as_story.is_err());
+}
+
+#[test]
+fn return_err_when_poll_option_is_missing_its_poll() {
+    // arrange
+    let item = load_item(POLL_OPTION_MISSING_POLL_FIXTURE);
+
+    // act
+    let poll_option_result = HackerNewsPollOption::try_from(item);
+
+    // assert
+    assert!(matches!(
+        poll_option_result,
+        Err(HackerNewsClientError::AssociatedParentNotFound(160706))
+    ));
+}
+
+#[test]
+fn default_missing_optional_fields() {
+    // arrange
+    let item = load_item(STORY_MISSING_FIELDS_FIXTURE);
+
+    // act
+    let story = HackerNewsStory::try_from(item).unwrap();
+
+    // assert
+    assert_eq!(story.score, 0);
+    assert_eq!(story.number_of_comments, 0);
+    assert!(story.comments.is_empty());
+    assert!(story.title.is_empty());
+    assert!(story.url.is_empty());
+    assert!(story.by.is_empty());
+    assert_round_trip::<HackerNewsStory>(STORY_MISSING_FIELDS_FIXTURE);
+}
+
+#[test]
+fn return_err_when_item_type_is_missing() {
+    // arrange
+    let item = load_item(MISSING_TYPE_FIXTURE);
+
+    // act
+    let story_result = HackerNewsStory::try_from(item);
+
+    // assert
+    assert!(matches!(
+        story_result,
+        Err(HackerNewsClientError::InvalidTypeMapping(
+            HackerNewsItemType::Unknown
+        ))
+    ));
+}
+
+#[test]
+fn fail_to_deserialize_items_with_missing_or_invalid_fields() {
+    // arrange, act
+    let missing_time = serde_json::from_str::<HackerNewsItem>(MISSING_TIME_FIXTURE);
+    let invalid_score = serde_json::from_str::<HackerNewsItem>(INVALID_SCORE_FIXTURE);
+
+    // assert
+    assert!(missing_time.is_err());
+    assert!(invalid_score.is_err());
+}
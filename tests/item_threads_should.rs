@@ -0,0 +1,89 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::errors::HackerNewsClientError;
+
+#[tokio::test]
+async fn return_items_in_requested_order() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+
+    // act
+    let items = client.items.get_items(&[105, 100, 103]).await.unwrap();
+
+    // assert
+    let ids: Vec<u32> = items.iter().map(|item| item.id).collect();
+    assert_eq!(ids, vec![105, 100, 103]);
+}
+
+#[tokio::test]
+async fn return_not_found_for_missing_items() {
+    // arrange
+    let client = StubApi::new().client().await;
+
+    // act
+    let item_result = client.items.get_item(999).await;
+
+    // assert
+    assert!(matches!(
+        item_result,
+        Err(HackerNewsClientError::ItemOrUserNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn assemble_comment_trees_in_display_order() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+
+    // act
+    let thread = client.items.get_thread(100).await.unwrap();
+
+    // assert
+    assert_eq!(thread.root.id, 100);
+    assert_eq!(thread.len(), 5);
+
+    let order: Vec<(usize, u32)> = thread
+        .iter()
+        .map(|(depth, node)| (depth, node.comment.id))
+        .collect();
+    assert_eq!(
+        order,
+        vec![(0, 101), (1, 103), (2, 105), (1, 104), (0, 102)]
+    );
+
+    let deleted = thread.find(102).unwrap();
+    assert!(deleted.comment.deleted);
+    assert!(!deleted.comment.is_visible());
+    assert!(thread.find(104).unwrap().comment.dead);
+    assert_eq!(thread.find(101).unwrap().reply_count(), 3);
+}
+
+#[tokio::test]
+async fn skip_comments_no_longer_returned() {
+    // arrange
+    let client = StubApi::new()
+        .with(
+            "item/1",
+            serde_json::json!({"id": 1, "type": "story", "time": 1700000000, "kids": [2, 3]}),
+        )
+        .with(
+            "item/2",
+            serde_json::json!({"id": 2, "type": "comment", "time": 1700000001, "parent": 1, "by": "pg", "text": "hi"}),
+        )
+        .client()
+        .await;
+
+    // act
+    let thread = client.items.get_thread(1).await.unwrap();
+
+    // assert
+    assert_eq!(thread.len(), 1);
+    assert_eq!(thread.comments[0].comment.by, "pg");
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "tower")]
+
+mod common;
+
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{client::HackerNewsClient, errors::HackerNewsClientError};
+use tower::{timeout::TimeoutLayer, util::MapRequestLayer};
+
+/// A layer recording the name given whenever a request passes through it.
+fn recording(
+    name: &'static str,
+    calls: Arc<Mutex<Vec<&'static str>>>,
+) -> MapRequestLayer<impl Fn(reqwest::Request) -> reqwest::Request + Clone> {
+    MapRequestLayer::new(move |request: reqwest::Request| {
+        calls.lock().unwrap().push(name);
+        request
+    })
+}
+
+async fn base_url() -> String {
+    StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .serve()
+        .await
+}
+
+#[tokio::test]
+async fn send_requests_through_layers() {
+    // arrange
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let client = HackerNewsClient::builder()
+        .base_url(base_url().await)
+        .layer(recording("recorded", calls.clone()))
+        .build()
+        .unwrap();
+
+    // act
+    let item = client.items.get_item(100).await.unwrap();
+
+    // assert
+    assert_eq!(item.id, 100);
+    assert_eq!(*calls.lock().unwrap(), vec!["recorded"]);
+}
+
+#[tokio::test]
+async fn apply_layers_with_the_first_outermost() {
+    // arrange
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let client = HackerNewsClient::builder()
+        .base_url(base_url().await)
+        .layer(recording("outer", calls.clone()))
+        .layer(recording("inner", calls.clone()))
+        .build()
+        .unwrap();
+
+    // act
+    client.items.get_item(100).await.unwrap();
+
+    // assert
+    assert_eq!(*calls.lock().unwrap(), vec!["outer", "inner"]);
+}
+
+#[tokio::test]
+async fn report_errors_raised_by_layers() {
+    // arrange
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let unresponsive = format!("http://{}/v0", listener.local_addr().unwrap());
+    let client = HackerNewsClient::builder()
+        .base_url(unresponsive)
+        .layer(TimeoutLayer::new(Duration::from_millis(50)))
+        .build()
+        .unwrap();
+
+    // act
+    let result = client.items.get_item(100).await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(HackerNewsClientError::TransportError(ref error)) if error.to_string().contains("timed out")
+    ));
+    drop(listener);
+}
+
+#[tokio::test]
+async fn preserve_client_errors_through_layers() {
+    // arrange
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let unreachable = format!("http://{}/v0", listener.local_addr().unwrap());
+    drop(listener);
+
+    let layered = |base_url: String| {
+        HackerNewsClient::builder()
+            .base_url(base_url)
+            .layer(TimeoutLayer::new(Duration::from_secs(5)))
+            .build()
+            .unwrap()
+    };
+
+    // act
+    let missing = layered(base_url().await).items.get_item(999).await;
+    let unreachable = layered(unreachable).items.get_item(100).await;
+
+    // assert
+    assert!(matches!(
+        missing,
+        Err(HackerNewsClientError::ItemOrUserNotFound(_))
+    ));
+    assert!(matches!(
+        unreachable,
+        Err(HackerNewsClientError::RequestError(_))
+    ));
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::StubApi;
+use newswrap::errors::HackerNewsClientError;
+
+#[tokio::test]
+async fn report_null_items_as_not_found() {
+    // arrange
+    let client = StubApi::new().client().await;
+
+    // act
+    let result = client.items.get_item(999).await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(HackerNewsClientError::ItemOrUserNotFound(ref id)) if id == "999"
+    ));
+}
+
+#[tokio::test]
+async fn report_null_users_as_not_found() {
+    // arrange
+    let client = StubApi::new().client().await;
+
+    // act
+    let result = client.users.get_user("nobody").await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(HackerNewsClientError::ItemOrUserNotFound(ref username)) if username == "nobody"
+    ));
+}
+
+#[tokio::test]
+async fn skip_missing_items_when_requested() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(common::STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+
+    // act
+    let items = client
+        .items
+        .get_existing_items(&[100, 999, 101])
+        .await
+        .unwrap();
+
+    // assert
+    let ids: Vec<u32> = items.iter().map(|item| item.id).collect();
+    assert_eq!(ids, vec![100, 101]);
+}
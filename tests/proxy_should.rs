@@ -0,0 +1,202 @@
+#![cfg(feature = "axum")]
+
+mod common;
+
+use std::net::TcpListener;
+
+use axum::Router;
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    client::HackerNewsClient, errors::HackerNewsClientError, items::HackerNewsItemType, proxy,
+};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+/// Serves the proxy router nested under `/hn` on a random local port, returning its base URL.
+fn serve(client: HackerNewsClient) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let app: Router = Router::new().nest("/hn", proxy::router(client));
+
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(app.into_make_service())
+            .await
+            .unwrap();
+    });
+
+    format!("http://{}/hn", address)
+}
+
+async fn proxy() -> String {
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([100, 8863]))
+        .with(
+            "user/rustacean",
+            json!({ "id": "rustacean", "created": 1600000000, "karma": 1234, "submitted": [103, 100] }),
+        )
+        .client()
+        .await;
+
+    serve(client)
+}
+
+#[tokio::test]
+async fn proxy_items_with_caching_headers() {
+    // arrange
+    let base_url = proxy().await;
+
+    // act
+    let response = reqwest::get(format!("{}/item/100", base_url))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["cache-control"], "public, max-age=60");
+
+    let item: Value = response.json().await.unwrap();
+    assert_eq!(item["id"], 100);
+    assert_eq!(item["by"], "rustacean");
+}
+
+#[tokio::test]
+async fn proxy_users_threads_and_lists() {
+    // arrange
+    let base_url = proxy().await;
+
+    // act
+    let user: Value = reqwest::get(format!("{}/user/rustacean", base_url))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let thread: Value = reqwest::get(format!("{}/thread/100", base_url))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let top: Value = reqwest::get(format!("{}/top", base_url))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(user["karma"], 1234);
+    assert_eq!(thread["root"]["id"], 100);
+    assert_eq!(thread["comments"][0]["comment"]["id"], 101);
+    assert_eq!(top, json!([100, 8863]));
+}
+
+#[tokio::test]
+async fn respond_not_found_for_missing_items() {
+    // arrange
+    let base_url = proxy().await;
+
+    // act
+    let response = reqwest::get(format!("{}/item/999", base_url))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let body: Value = response.json().await.unwrap();
+    assert!(body["error"].as_str().unwrap().contains("999"));
+}
+
+#[tokio::test]
+async fn respond_bad_request_for_invalid_ids() {
+    // arrange
+    let base_url = proxy().await;
+
+    // act
+    let response = reqwest::get(format!("{}/item/abc", base_url))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn respond_bad_gateway_when_the_api_is_unreachable() {
+    // arrange
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let unreachable = format!("http://{}/v0", listener.local_addr().unwrap());
+    drop(listener);
+
+    let client = HackerNewsClient::builder()
+        .base_url(unreachable)
+        .build()
+        .unwrap();
+    let base_url = serve(client);
+
+    // act
+    let response = reqwest::get(format!("{}/item/100", base_url))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["error"], "Bad Gateway");
+}
+
+#[test]
+fn map_stopped_operations_to_unavailable_statuses() {
+    // arrange
+    let cancelled = HackerNewsClientError::Cancelled;
+    let deadline_exceeded = HackerNewsClientError::DeadlineExceeded;
+
+    // act
+    let cancelled = cancelled.status_code();
+    let deadline_exceeded = deadline_exceeded.status_code();
+
+    // assert
+    assert_eq!(cancelled, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(deadline_exceeded, StatusCode::GATEWAY_TIMEOUT);
+}
+
+#[test]
+fn map_unexpected_item_types_to_bad_gateway() {
+    // arrange
+    let error = HackerNewsClientError::InvalidTypeMapping(HackerNewsItemType::Story);
+
+    // act
+    let status = error.status_code();
+
+    // assert
+    assert_eq!(status, StatusCode::BAD_GATEWAY);
+}
+
+#[tokio::test]
+async fn not_proxy_usernames_addressing_other_resources() {
+    // arrange
+    let api = StubApi::new().with(
+        "topstories",
+        json!({ "id": "topstories", "created": 1600000000, "karma": 1 }),
+    );
+    let requests = api.requests();
+    let base_url = serve(api.client().await);
+
+    // act
+    let traversal = reqwest::get(format!("{}/user/..%2Ftopstories", base_url))
+        .await
+        .unwrap();
+    let parent = reqwest::get(format!("{}/user/%2E%2E", base_url))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(traversal.status(), StatusCode::NOT_FOUND);
+    assert_eq!(parent.status(), StatusCode::NOT_FOUND);
+    assert!(!requests.lock().unwrap().contains(&"topstories".to_string()));
+}
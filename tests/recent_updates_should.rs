@@ -0,0 +1,29 @@
+mod common;
+
+use common::StubApi;
+use serde_json::json;
+
+#[tokio::test]
+async fn return_updated_profiles_as_usernames() {
+    // arrange
+    let client = StubApi::new()
+        .with(
+            "updates",
+            json!({ "items": [8863, 8864], "profiles": ["pg", "dang"] }),
+        )
+        .client()
+        .await;
+
+    // act
+    let updates = client.realtime.get_recent_updates().await.unwrap();
+    let profiles = client
+        .realtime
+        .get_recently_updated_profiles()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(updates.items, vec![8863, 8864]);
+    assert_eq!(updates.profiles, vec!["pg", "dang"]);
+    assert_eq!(profiles, vec!["pg", "dang"]);
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "watch")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use futures::StreamExt;
+use newswrap::watch::{ReplyCheckpoint, ReplyNotification, ReplyNotifier};
+use serde_json::json;
+
+fn stub(updated: &[u32]) -> StubApi {
+    StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("maxitem", json!(105))
+        .with(
+            "user/rustacean",
+            json!({ "id": "rustacean", "created": 1600000000, "karma": 1234, "submitted": [103, 100] }),
+        )
+        .with("updates", json!({ "items": updated, "profiles": [] }))
+}
+
+fn checkpoint(since: u32, last_seen: u32, notified: Vec<u32>) -> ReplyCheckpoint {
+    ReplyCheckpoint {
+        username: "rustacean".to_string(),
+        since: Some(since),
+        last_seen: Some(last_seen),
+        notified,
+    }
+}
+
+fn ids(notifications: &[ReplyNotification]) -> Vec<(u32, u32)> {
+    notifications
+        .iter()
+        .map(|notification| (notification.reply.id, notification.parent.id))
+        .collect()
+}
+
+#[tokio::test]
+async fn notify_new_direct_replies_to_submissions() {
+    // arrange
+    let client = stub(&[]).client().await;
+    let mut notifier = ReplyNotifier::from_checkpoint(checkpoint(99, 99, Vec::new()));
+
+    // act
+    let first = notifier.poll(&client).await.unwrap();
+    let second = notifier.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(ids(&first), vec![(101, 100), (105, 103)]);
+    assert_eq!(first[0].reply.by, "alice");
+    assert!(second.is_empty());
+
+    let checkpoint = notifier.checkpoint();
+    assert_eq!(checkpoint.last_seen, Some(105));
+    assert_eq!(checkpoint.notified, vec![101, 105]);
+}
+
+#[tokio::test]
+async fn forget_the_oldest_notified_replies_from_oversized_checkpoints() {
+    // arrange
+    let client = stub(&[]).client().await;
+    let notified = (1000..2500).collect();
+    let mut notifier = ReplyNotifier::from_checkpoint(checkpoint(99, 99, notified));
+
+    // act
+    let notifications = notifier.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(ids(&notifications), vec![(101, 100), (105, 103)]);
+
+    let notified = notifier.checkpoint().notified;
+    assert_eq!(notified.len(), 1000);
+    assert_eq!(notified[0], 1502);
+    assert_eq!(notified[998..], [101, 105]);
+}
+
+#[tokio::test]
+async fn start_from_the_latest_item_without_a_checkpoint() {
+    // arrange
+    let client = stub(&[100, 103]).client().await;
+    let mut notifier = ReplyNotifier::new("rustacean");
+
+    // act
+    let notifications = notifier.poll(&client).await.unwrap();
+
+    // assert
+    assert!(notifications.is_empty());
+    assert_eq!(notifier.checkpoint().since, Some(105));
+}
+
+#[tokio::test]
+async fn find_unscanned_replies_on_updated_submissions() {
+    // arrange
+    let client = stub(&[103]).client().await;
+    let mut notifier = ReplyNotifier::from_checkpoint(checkpoint(99, 105, Vec::new()));
+
+    // act
+    let notifications = notifier.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(ids(&notifications), vec![(105, 103)]);
+}
+
+#[tokio::test]
+async fn not_renotify_replies_after_restoring_checkpoints() {
+    // arrange
+    let client = stub(&[100, 103]).client().await;
+    let mut notifier = ReplyNotifier::from_checkpoint(checkpoint(99, 99, Vec::new()));
+    let notified = notifier.poll(&client).await.unwrap();
+
+    // act
+    let persisted = serde_json::to_string(&notifier.checkpoint()).unwrap();
+    let mut restored = ReplyNotifier::from_checkpoint(serde_json::from_str(&persisted).unwrap());
+    let renotified = restored.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(notified.len(), 2);
+    assert!(renotified.is_empty());
+}
+
+#[tokio::test]
+async fn keep_the_checkpoint_when_polls_fail() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("maxitem", json!(105))
+        .with(
+            "user/rustacean",
+            json!({ "id": "rustacean", "created": 1600000000, "karma": 1234, "submitted": [103, 100] }),
+        )
+        .client()
+        .await;
+    let mut notifier = ReplyNotifier::from_checkpoint(checkpoint(99, 99, Vec::new()));
+
+    // act
+    let result = notifier.poll(&client).await;
+
+    // assert
+    assert!(result.is_err());
+    assert_eq!(notifier.checkpoint(), checkpoint(99, 99, Vec::new()));
+}
+
+#[tokio::test]
+async fn stream_replies_as_they_are_found() {
+    // arrange
+    let client = stub(&[]).client().await;
+    let mut notifier = ReplyNotifier::from_checkpoint(checkpoint(99, 99, Vec::new()));
+
+    // act
+    let notifications: Vec<ReplyNotification> = notifier
+        .stream(&client, Duration::from_millis(10))
+        .take(2)
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    // assert
+    assert_eq!(ids(&notifications), vec![(101, 100), (105, 103)]);
+}
@@ -0,0 +1,308 @@
+mod common;
+
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    algolia::HackerNewsSearchQuery,
+    cancellation::Cancellation,
+    client::{HackerNewsClient, RequestOptions},
+    errors::HackerNewsClientError,
+    realtime::HackerNewsStoryList,
+};
+use serde_json::json;
+
+/// An upstream API responding to the first `failures` requests with a server error and every request after `delay`,
+/// recording the headers of each request received.
+#[derive(Debug, Default)]
+struct Upstream {
+    failures: usize,
+    delay: Duration,
+    requests: Vec<HeaderMap>,
+}
+
+async fn respond(State(upstream): State<Arc<Mutex<Upstream>>>, headers: HeaderMap) -> Response {
+    let (attempt, failures, delay) = {
+        let mut upstream = upstream.lock().unwrap();
+        upstream.requests.push(headers);
+        (upstream.requests.len(), upstream.failures, upstream.delay)
+    };
+
+    tokio::time::sleep(delay).await;
+
+    if attempt <= failures {
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
+    }
+
+    Json(json!({ "id": 100, "type": "story", "by": "rustacean", "time": 1700000000 }))
+        .into_response()
+}
+
+/// Starts the upstream API on a random local port, returning a client pointing to it.
+fn serve(upstream: Upstream) -> (HackerNewsClient, Arc<Mutex<Upstream>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let upstream = Arc::new(Mutex::new(upstream));
+
+    let router = Router::new()
+        .route("/v0/item/:id", get(respond))
+        .with_state(upstream.clone());
+
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .await
+            .unwrap();
+    });
+
+    let client = HackerNewsClient::builder()
+        .base_url(format!("http://{}/v0", address))
+        .build()
+        .unwrap();
+
+    (client, upstream)
+}
+
+#[tokio::test]
+async fn time_out_requests_exceeding_their_own_budget() {
+    // arrange
+    let (client, _) = serve(Upstream {
+        delay: Duration::from_millis(300),
+        ..Upstream::default()
+    });
+    let options = RequestOptions::new().timeout(Duration::from_millis(50));
+
+    // act
+    let tight = client.items.get_item_with(100, options).await;
+    let relaxed = client.items.get_item(100).await;
+
+    // assert
+    assert!(matches!(
+        tight,
+        Err(HackerNewsClientError::RequestError(ref error)) if error.is_timeout()
+    ));
+    assert_eq!(relaxed.unwrap().id, 100);
+}
+
+#[tokio::test]
+async fn retry_server_errors_up_to_the_configured_retries() {
+    // arrange
+    let failing_twice = || Upstream {
+        failures: 2,
+        ..Upstream::default()
+    };
+    let (exhausted_client, exhausted_upstream) = serve(failing_twice());
+    let (recovered_client, recovered_upstream) = serve(failing_twice());
+    let with_retries = |retries| RequestOptions::new().retries(retries);
+
+    // act
+    let exhausted = exhausted_client
+        .items
+        .get_item_with(100, with_retries(1))
+        .await;
+    let recovered = recovered_client
+        .items
+        .get_item_with(100, with_retries(2))
+        .await;
+
+    // assert
+    assert!(matches!(
+        exhausted,
+        Err(HackerNewsClientError::RequestError(ref error)) if error.status() == Some(StatusCode::SERVICE_UNAVAILABLE)
+    ));
+    assert_eq!(exhausted_upstream.lock().unwrap().requests.len(), 2);
+    assert_eq!(recovered.unwrap().id, 100);
+    assert_eq!(recovered_upstream.lock().unwrap().requests.len(), 3);
+}
+
+#[tokio::test]
+async fn back_off_between_retries() {
+    // arrange
+    let (client, upstream) = serve(Upstream {
+        failures: 2,
+        ..Upstream::default()
+    });
+    let options = RequestOptions::new().retries(2);
+    let started = Instant::now();
+
+    // act
+    let item = client.items.get_item_with(100, options).await;
+
+    // assert
+    assert_eq!(item.unwrap().id, 100);
+    assert_eq!(upstream.lock().unwrap().requests.len(), 3);
+    assert!(started.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn not_retry_missing_items() {
+    // arrange
+    let stub = StubApi::new();
+    let requests = stub.requests();
+    let client = stub.client().await;
+    let options = RequestOptions::new().retries(3);
+
+    // act
+    let result = client.items.get_item_with(999, options).await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(HackerNewsClientError::ItemOrUserNotFound(_))
+    ));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn request_fresh_responses_when_bypassing_the_cache() {
+    // arrange
+    let (client, upstream) = serve(Upstream::default());
+    let options = RequestOptions::new().bypass_cache(true);
+
+    // act
+    client.items.get_item(100).await.unwrap();
+    client.items.get_item_with(100, options).await.unwrap();
+
+    // assert
+    let requests = &upstream.lock().unwrap().requests;
+    assert!(requests[0].get(header::CACHE_CONTROL).is_none());
+    assert_eq!(requests[1][header::CACHE_CONTROL], "no-cache");
+}
+
+#[tokio::test]
+async fn apply_options_to_users_realtime_and_threads() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([100, 8863]))
+        .with("maxitem", json!(8863))
+        .with(
+            "user/rustacean",
+            json!({ "id": "rustacean", "created": 1600000000, "karma": 1234 }),
+        )
+        .client()
+        .await;
+    let options = RequestOptions::new()
+        .timeout(Duration::from_secs(1))
+        .bypass_cache(true)
+        .retries(1);
+
+    // act
+    let user = client
+        .users
+        .get_user_with("rustacean", options)
+        .await
+        .unwrap();
+    let top = client
+        .realtime
+        .get_story_list_with(HackerNewsStoryList::Top, options)
+        .await
+        .unwrap();
+    let max_item = client
+        .realtime
+        .get_latest_item_id_with(options)
+        .await
+        .unwrap();
+    let thread = client.items.get_thread_with(100, options).await.unwrap();
+
+    // assert
+    assert_eq!(user.karma, 1234);
+    assert_eq!(top, vec![100, 8863]);
+    assert_eq!(max_item, 8863);
+    assert_eq!(thread.root.id, 100);
+}
+
+#[tokio::test]
+async fn apply_options_to_typed_items_and_bounded_batches() {
+    // arrange
+    let (client, upstream) = serve(Upstream::default());
+    let options = RequestOptions::new().bypass_cache(true);
+
+    // act
+    let story = client.items.get_story_with(100, options).await.unwrap();
+    let items = client
+        .items
+        .get_items_until_with(&[100, 100], &Cancellation::new(), options)
+        .await;
+
+    // assert
+    assert_eq!(story.id, 100);
+    assert!(items.is_complete());
+    assert_eq!(items.value.len(), 2);
+
+    let requests = &upstream.lock().unwrap().requests;
+    assert_eq!(requests.len(), 3);
+    assert!(requests
+        .iter()
+        .all(|request| request[header::CACHE_CONTROL] == "no-cache"));
+}
+
+#[tokio::test]
+async fn apply_options_to_searches() {
+    // arrange
+    let client = StubApi::new()
+        .with(
+            "search",
+            json!({ "hits": [], "nbHits": 0, "page": 0, "nbPages": 0, "hitsPerPage": 20, "query": "rust" }),
+        )
+        .client()
+        .await;
+    let options = RequestOptions::new()
+        .timeout(Duration::from_secs(1))
+        .retries(1);
+
+    // act
+    let results = client
+        .search
+        .search_with(&HackerNewsSearchQuery::new("rust"), options)
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(results.nb_hits, 0);
+}
+
+#[tokio::test]
+async fn apply_options_to_search_items_threads_and_users() {
+    // arrange
+    let client = StubApi::new()
+        .with(
+            "items/100",
+            serde_json::from_str(include_str!("fixtures/search/thread.json")).unwrap(),
+        )
+        .with(
+            "users/rustacean",
+            serde_json::from_str(include_str!("fixtures/search/user.json")).unwrap(),
+        )
+        .client()
+        .await;
+    let options = RequestOptions::new()
+        .timeout(Duration::from_secs(1))
+        .retries(1);
+
+    // act
+    let item = client.search.get_item_with(100, options).await.unwrap();
+    let thread = client.search.get_thread_with(100, options).await.unwrap();
+    let user = client
+        .search
+        .get_user_with("rustacean", options)
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(item.id, 100);
+    assert_eq!(thread.len(), 4);
+    assert_eq!(user.username, "rustacean");
+}
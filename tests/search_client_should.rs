@@ -0,0 +1,198 @@
+mod common;
+
+use common::StubApi;
+use newswrap::{
+    algolia::{HackerNewsSearchQuery, HackerNewsSearchTag},
+    errors::HackerNewsClientError,
+    items::{stories::HackerNewsStory, HackerNewsItemType},
+};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+const RESULTS_FIXTURE: &str = include_str!("fixtures/search/results.json");
+const THREAD_FIXTURE: &str = include_str!("fixtures/search/thread.json");
+const USER_FIXTURE: &str = include_str!("fixtures/search/user.json");
+
+fn fixture(json: &str) -> Value {
+    serde_json::from_str(json).unwrap()
+}
+
+#[tokio::test]
+async fn search_with_tags_numeric_filters_and_pagination() {
+    // arrange
+    let client = StubApi::new()
+        .with(
+            "search?query=rust&tags=story%2C%28author_rustacean%2Cauthor_alice%29&numericFilters=points%3E%3D10%2Ccreated_at_i%3E1600000000&page=1&hitsPerPage=2",
+            fixture(RESULTS_FIXTURE),
+        )
+        .client()
+        .await;
+    let query = HackerNewsSearchQuery::new("rust")
+        .tag(HackerNewsSearchTag::Story)
+        .any_tag([
+            HackerNewsSearchTag::Author("rustacean".to_string()),
+            HackerNewsSearchTag::Author("alice".to_string()),
+        ])
+        .min_points(10)
+        .created_after(OffsetDateTime::from_unix_timestamp(1_600_000_000).unwrap())
+        .page(1)
+        .hits_per_page(2);
+
+    // act
+    let results = client.search.search(&query).await;
+
+    // assert
+    assert!(results.is_ok());
+    let results = results.unwrap();
+    assert_eq!(results.nb_hits, 3);
+    assert_eq!(results.hits.len(), 2);
+    assert_eq!(results.hits[0].item_type(), Some("story"));
+    assert!(results.has_next_page());
+}
+
+#[tokio::test]
+async fn map_search_hits_onto_items() {
+    // arrange
+    let client = StubApi::new()
+        .with("search_by_date", fixture(RESULTS_FIXTURE))
+        .client()
+        .await;
+
+    // act
+    let items = client
+        .search
+        .search_by_date(&HackerNewsSearchQuery::new("rust"))
+        .await
+        .unwrap()
+        .into_items()
+        .unwrap();
+
+    // assert
+    assert_eq!(items[0].get_item_type(), HackerNewsItemType::Story);
+    assert_eq!(items[0].score, Some(42));
+    assert_eq!(items[0].descendants, Some(5));
+    assert_eq!(items[0].created_at.unix_timestamp(), 1_700_000_000);
+    assert_eq!(items[1].get_item_type(), HackerNewsItemType::Comment);
+    assert_eq!(items[1].parent, Some(100));
+    assert_eq!(
+        items[1].text.as_deref(),
+        Some("This looks great! Does it support <i>async</i>?")
+    );
+
+    let story: Result<HackerNewsStory, _> = items[0].clone().try_into();
+    assert!(story.is_ok());
+}
+
+#[tokio::test]
+async fn retrieve_whole_threads_in_one_request() {
+    // arrange
+    let client = StubApi::new()
+        .with("items/100", fixture(THREAD_FIXTURE))
+        .client()
+        .await;
+
+    // act
+    let thread = client.search.get_thread(100).await;
+
+    // assert
+    assert!(thread.is_ok());
+    let thread = thread.unwrap();
+    assert_eq!(thread.root.kids, Some(vec![101, 102]));
+    assert_eq!(thread.root.descendants, Some(4));
+    assert_eq!(thread.len(), 4);
+    assert!(thread.comments[1].comment.deleted);
+    assert_eq!(
+        thread.find(105).map(|node| node.comment.text.as_str()),
+        Some("Thanks!")
+    );
+}
+
+#[tokio::test]
+async fn retrieve_users() {
+    // arrange
+    let client = StubApi::new()
+        .with("users/rustacean", fixture(USER_FIXTURE))
+        .client()
+        .await;
+
+    // act
+    let user = client.search.get_user("rustacean").await;
+
+    // assert
+    assert!(user.is_ok());
+    let user = user.unwrap();
+    assert_eq!(user.username, "rustacean");
+    assert_eq!(user.karma, 1234);
+}
+
+#[tokio::test]
+async fn skip_poll_options_when_retrieving_threads() {
+    // arrange
+    let mut poll = fixture(THREAD_FIXTURE);
+    poll["type"] = "poll".into();
+    poll["children"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!({
+            "author": "rustacean",
+            "children": [],
+            "created_at": "2023-11-14T22:13:20.000Z",
+            "id": 106,
+            "parent_id": 100,
+            "points": 12,
+            "story_id": 100,
+            "text": "Yes",
+            "title": null,
+            "type": "pollopt",
+            "url": null
+        }));
+    let client = StubApi::new().with("items/100", poll).client().await;
+
+    // act
+    let thread = client.search.get_thread(100).await;
+
+    // assert
+    assert!(thread.is_ok());
+    let thread = thread.unwrap();
+    assert_eq!(thread.root.kids, Some(vec![101, 102]));
+    assert_eq!(thread.root.descendants, Some(4));
+    assert_eq!(thread.len(), 4);
+}
+
+#[tokio::test]
+async fn encode_usernames_within_a_single_path_segment() {
+    // arrange
+    let api = StubApi::new().with("topstories", fixture(USER_FIXTURE));
+    let requests = api.requests();
+    let client = api.client().await;
+
+    // act
+    let traversal = client.search.get_user("../topstories").await;
+    let parent = client.search.get_user("..").await;
+
+    // assert
+    assert!(matches!(
+        traversal,
+        Err(HackerNewsClientError::ItemOrUserNotFound(_))
+    ));
+    assert!(matches!(
+        parent,
+        Err(HackerNewsClientError::ItemOrUserNotFound(_))
+    ));
+    assert!(!requests.lock().unwrap().contains(&"topstories".to_string()));
+}
+
+#[tokio::test]
+async fn return_not_found_for_missing_items() {
+    // arrange
+    let client = StubApi::new().client().await;
+
+    // act
+    let item = client.search.get_item(404).await;
+
+    // assert
+    assert!(matches!(
+        item,
+        Err(HackerNewsClientError::ItemOrUserNotFound(_))
+    ));
+}
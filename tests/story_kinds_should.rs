@@ -0,0 +1,100 @@
+use newswrap::items::{
+    stories::{HackerNewsStory, HiringThreadKind, StoryKind},
+    HackerNewsItem,
+};
+
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+
+fn story_with(title: &str, url: &str, text: &str) -> HackerNewsStory {
+    let item: HackerNewsItem = serde_json::from_str(STORY_FIXTURE).unwrap();
+    let mut story: HackerNewsStory = item.try_into().unwrap();
+    story.title = title.to_string();
+    story.url = url.to_string();
+    story.text = text.to_string();
+    story
+}
+
+#[test]
+fn classify_stories_by_title_prefix() {
+    // arrange
+    let ask = story_with("Ask HN: How do you back up your laptop?", "", "Curious.");
+    let show = story_with(
+        "Show HN: A Rust client for HN",
+        "https://github.com/a/b",
+        "",
+    );
+    let launch = story_with(
+        "Launch HN: Acme (YC W24) – Anvils as a service",
+        "",
+        "Hi HN",
+    );
+    let tell = story_with("tell hn: I quit my job", "", "It went well.");
+
+    // act, assert
+    assert_eq!(ask.kind(), StoryKind::Ask);
+    assert_eq!(show.kind(), StoryKind::Show);
+    assert_eq!(launch.kind(), StoryKind::Launch);
+    assert_eq!(tell.kind(), StoryKind::Tell);
+}
+
+#[test]
+fn classify_stories_without_prefixes_by_url() {
+    // arrange
+    let link = story_with("My YC app: Dropbox", "http://www.getdropbox.com", "");
+    let self_post = story_with("Thoughts on remote work", "", "Some text.");
+    let similar_prefix = story_with("Asking HNers for advice", "https://example.com", "");
+
+    // act, assert
+    assert_eq!(link.kind(), StoryKind::Link);
+    assert_eq!(self_post.kind(), StoryKind::SelfPost);
+    assert_eq!(similar_prefix.kind(), StoryKind::Link);
+}
+
+#[test]
+fn prefer_realtime_list_membership() {
+    // arrange
+    let story = story_with("What I learned building a compiler", "", "Details.");
+    let ask_stories = vec![1, 2, story.id];
+    let show_stories = vec![3, 4];
+
+    // act
+    let as_ask = story.kind_with_listings(&ask_stories, &show_stories);
+    let as_show = story.kind_with_listings(&[], &[story.id]);
+    let unlisted = story.kind_with_listings(&[], &[]);
+
+    // assert
+    assert_eq!(as_ask, StoryKind::Ask);
+    assert_eq!(as_show, StoryKind::Show);
+    assert_eq!(unlisted, StoryKind::SelfPost);
+}
+
+#[test]
+fn detect_hiring_threads() {
+    // arrange
+    let hiring = story_with("Ask HN: Who is hiring? (October 2023)", "", "");
+    let hired = story_with("Ask HN: Who wants to be hired? (October 2023)", "", "");
+    let freelancer = story_with(
+        "Ask HN: Freelancer? Seeking freelancer? (October 2023)",
+        "",
+        "",
+    );
+    let not_ask = story_with(
+        "Who is hiring in 2023, a retrospective",
+        "https://example.com",
+        "",
+    );
+    let unrelated = story_with("Ask HN: What are you working on?", "", "");
+
+    // act, assert
+    assert_eq!(hiring.hiring_thread(), Some(HiringThreadKind::WhoIsHiring));
+    assert_eq!(
+        hired.hiring_thread(),
+        Some(HiringThreadKind::WhoWantsToBeHired)
+    );
+    assert_eq!(
+        freelancer.hiring_thread(),
+        Some(HiringThreadKind::Freelancer)
+    );
+    assert_eq!(not_ask.hiring_thread(), None);
+    assert_eq!(unrelated.hiring_thread(), None);
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{analytics::StoryTracker, items::HackerNewsItem};
+use serde_json::json;
+use time::Duration;
+
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+
+fn story(score: u32, comments: u32) -> HackerNewsItem {
+    let mut story: HackerNewsItem = serde_json::from_str(STORY_FIXTURE).unwrap();
+    story.score = Some(score);
+    story.descendants = Some(comments);
+    story
+}
+
+fn tracker() -> StoryTracker {
+    let created_at = story(1, 0).created_at;
+    let mut tracker = StoryTracker::new([8863]);
+
+    tracker.record(&story(10, 2), Some(5), created_at + Duration::hours(1));
+    tracker.record(&story(40, 8), Some(2), created_at + Duration::hours(2));
+    tracker.record(&story(70, 12), Some(40), created_at + Duration::hours(3));
+    tracker
+}
+
+#[test]
+fn compute_velocity_between_samples() {
+    // arrange
+    let tracker = tracker();
+
+    // act
+    let series = tracker.series(8863).unwrap();
+
+    // assert
+    assert_eq!(series.samples.len(), 3);
+    assert_eq!(series.points_per_hour(), Some(30.0));
+    assert_eq!(series.comments_per_hour(), Some(5.0));
+}
+
+#[test]
+fn compute_peak_rank_and_time_on_front_page() {
+    // arrange
+    let tracker = tracker();
+
+    // act
+    let series = tracker.series(8863).unwrap();
+
+    // assert
+    assert_eq!(series.peak_rank(), Some(2));
+    assert_eq!(series.time_on_front_page(), Duration::hours(2));
+}
+
+#[test]
+fn estimate_ranking_scores() {
+    // arrange
+    let tracker = tracker();
+    let series = tracker.series(8863).unwrap();
+
+    // act
+    let scores: Vec<f64> = series
+        .samples
+        .iter()
+        .map(|sample| series.ranking_score(sample))
+        .collect();
+
+    // assert
+    let expected = 39_f64.powf(0.8) / 4_f64.powf(1.8);
+    assert!((scores[1] - expected).abs() < 1e-9);
+    assert!(scores[1] > scores[0]);
+    assert!(scores[2] > scores[1]);
+}
+
+#[test]
+fn require_multiple_samples_for_velocity() {
+    // arrange
+    let mut tracker = StoryTracker::new([]);
+    let story = story(10, 2);
+
+    // act
+    tracker.record(&story, None, story.created_at);
+
+    // assert
+    let series = tracker.series(8863).unwrap();
+    assert_eq!(tracker.ids(), &[8863]);
+    assert!(series.points_per_hour().is_none());
+    assert!(series.peak_rank().is_none());
+    assert_eq!(series.time_on_front_page(), Duration::ZERO);
+}
+
+#[tokio::test]
+async fn sample_tracked_stories_when_polled() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([1, 100, 2]))
+        .client()
+        .await;
+    let mut tracker = StoryTracker::new([100]);
+
+    // act
+    let samples = tracker.poll(&client).await.unwrap();
+    tracker.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(samples.len(), 1);
+    let (id, sample) = samples[0];
+    assert_eq!(id, 100);
+    assert_eq!(sample.score, 42);
+    assert_eq!(sample.comments, 5);
+    assert_eq!(sample.rank, Some(2));
+    assert_eq!(tracker.series(100).unwrap().samples.len(), 2);
+}
+
+#[tokio::test]
+async fn skip_missing_stories_when_polled() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([100]))
+        .client()
+        .await;
+    let mut tracker = StoryTracker::new([999, 100]);
+
+    // act
+    let samples = tracker.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].0, 100);
+    assert!(tracker
+        .series(999)
+        .is_none_or(|series| series.samples.is_empty()));
+}
+
+#[test]
+fn stop_tracking_stories() {
+    // arrange
+    let mut tracker = tracker();
+
+    // act
+    let series = tracker.untrack(8863);
+
+    // assert
+    assert!(series.is_some());
+    assert!(tracker.ids().is_empty());
+    assert!(tracker.series(8863).is_none());
+}
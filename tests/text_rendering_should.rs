@@ -0,0 +1,162 @@
+use newswrap::{
+    items::{comments::HackerNewsComment, HackerNewsItem},
+    text::{self, HackerNewsLink},
+};
+
+const COMMENT_FIXTURE: &str = include_str!("fixtures/items/comment.json");
+
+const CODE_BLOCK_HTML: &str = "Try this:<p><pre><code>  fn main() {\n      println!(&quot;hi&quot;);\n  }\n</code></pre>Works for me.";
+
+const LINK_HTML: &str = "Source: <a href=\"https:&#x2F;&#x2F;github.com&#x2F;HackerNews&#x2F;API\" rel=\"nofollow\">https:&#x2F;&#x2F;github.com&#x2F;HackerNews&#x2F;API</a> and <a href=\"https://example.com/a/very/long/path/that/hacker/news/truncates\">https://example.com/a/very/long/path/that/hack...</a><p>Also <a href=\"https://docs.rs\">the docs</a>.";
+
+#[test]
+fn decode_named_and_numeric_entities() {
+    // arrange
+    let html = "It&#x27;s &quot;fine&quot; &amp; 5 &gt; 3 &#8212; &unknown; &";
+
+    // act
+    let decoded = text::decode_entities(html);
+
+    // assert
+    assert_eq!(decoded, "It's \"fine\" & 5 > 3 — &unknown; &");
+}
+
+#[test]
+fn render_paragraphs_as_plain_text() {
+    // arrange
+    let comment: HackerNewsComment = serde_json::from_str::<HackerNewsItem>(COMMENT_FIXTURE)
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    // act
+    let plain = comment.text_plain();
+
+    // assert
+    assert_eq!(
+        plain,
+        "Aw shucks, guys ... you make me blush with your compliments.\n\nTell you what, Ill make a deal: I'll keep writing if you keep reading. K?"
+    );
+}
+
+#[test]
+fn render_formatting_as_markdown() {
+    // arrange
+    let html = "This is <i>really</i> important_stuff with [brackets].<p>Second paragraph.";
+
+    // act
+    let markdown = text::to_markdown(html);
+
+    // assert
+    assert_eq!(
+        markdown,
+        "This is *really* important\\_stuff with \\[brackets\\].\n\nSecond paragraph."
+    );
+}
+
+#[test]
+fn escape_html_in_markdown_text() {
+    // arrange
+    let html = "Try &lt;script&gt;alert(1)&lt;/script&gt; &amp;amp; see";
+
+    // act
+    let markdown = text::to_markdown(html);
+
+    // assert
+    assert_eq!(
+        markdown,
+        "Try \\<script\\>alert(1)\\</script\\> \\&amp; see"
+    );
+}
+
+#[test]
+fn percent_encode_hostile_hrefs_in_markdown_links() {
+    // arrange
+    let autolink = r#"<a href="https://example.com/a&gt; [x](javascript:alert(1))">https://example.com/a&gt; [x](javascript:alert(1))</a>"#;
+    let inline = r#"<a href="https://example.com/a) <b>bold</b>">the docs</a>"#;
+
+    // act
+    let autolink = text::to_markdown(autolink);
+    let inline = text::to_markdown(inline);
+
+    // assert
+    assert_eq!(
+        autolink,
+        "<https://example.com/a%3E%20[x]%28javascript:alert%281%29%29>"
+    );
+    assert_eq!(
+        inline,
+        "[the docs](https://example.com/a%29%20%3Cb%3Ebold%3C/b%3E)"
+    );
+}
+
+#[test]
+fn render_code_blocks() {
+    // arrange, act
+    let plain = text::to_plain_text(CODE_BLOCK_HTML);
+    let markdown = text::to_markdown(CODE_BLOCK_HTML);
+
+    // assert
+    assert_eq!(
+        plain,
+        "Try this:\n\nfn main() {\n    println!(\"hi\");\n}\n\nWorks for me."
+    );
+    assert_eq!(
+        markdown,
+        "Try this:\n\n```\nfn main() {\n    println!(\"hi\");\n}\n```\n\nWorks for me."
+    );
+}
+
+#[test]
+fn render_links() {
+    // arrange, act
+    let plain = text::to_plain_text(LINK_HTML);
+    let markdown = text::to_markdown(LINK_HTML);
+
+    // assert
+    assert_eq!(
+        plain,
+        "Source: https://github.com/HackerNews/API and https://example.com/a/very/long/path/that/hacker/news/truncates\n\nAlso the docs (https://docs.rs)."
+    );
+    assert_eq!(
+        markdown,
+        "Source: <https://github.com/HackerNews/API> and <https://example.com/a/very/long/path/that/hacker/news/truncates>\n\nAlso [the docs](https://docs.rs)."
+    );
+}
+
+#[test]
+fn extract_links_in_order() {
+    // arrange, act
+    let links = text::extract_links(LINK_HTML);
+
+    // assert
+    assert_eq!(
+        links,
+        vec![
+            HackerNewsLink {
+                url: "https://github.com/HackerNews/API".to_string(),
+                text: "https://github.com/HackerNews/API".to_string(),
+            },
+            HackerNewsLink {
+                url: "https://example.com/a/very/long/path/that/hacker/news/truncates".to_string(),
+                text: "https://example.com/a/very/long/path/that/hack...".to_string(),
+            },
+            HackerNewsLink {
+                url: "https://docs.rs".to_string(),
+                text: "the docs".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn treat_stray_angle_brackets_as_text() {
+    // arrange
+    let html = "if a &lt; b then a < b and <b";
+
+    // act
+    let plain = text::to_plain_text(html);
+
+    // assert
+    assert_eq!(plain, "if a < b then a < b and <b");
+}
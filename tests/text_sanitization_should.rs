@@ -0,0 +1,90 @@
+use newswrap::{items::HackerNewsItem, text, users::HackerNewsUser};
+
+const COMMENT_FIXTURE: &str = include_str!("fixtures/items/comment.json");
+
+#[test]
+fn retain_tags_hacker_news_emits() {
+    // arrange
+    let html = "It&#x27;s <i>fine</i>.<p><pre><code>  let x = 1 &lt; 2;\n</code></pre>";
+
+    // act
+    let sanitized = text::sanitize(html);
+
+    // assert
+    assert_eq!(
+        sanitized.as_str(),
+        "It&#x27;s <i>fine</i>.<p><pre><code>  let x = 1 &lt; 2;\n</code></pre>"
+    );
+}
+
+#[test]
+fn rewrite_links_with_safe_rel_attributes() {
+    // arrange
+    let html = "<a href=\"https:&#x2F;&#x2F;example.com&#x2F;?a=1&amp;b=2\" rel=\"nofollow\" onclick=\"steal()\" target=\"_blank\">example</a> and <a href=\"item?id=8863\">a thread</a>";
+
+    // act
+    let sanitized = text::sanitize(html);
+
+    // assert
+    assert_eq!(
+        sanitized.as_str(),
+        "<a href=\"https://example.com/?a=1&amp;b=2\" rel=\"nofollow noopener\">example</a> and <a href=\"item?id=8863\" rel=\"nofollow noopener\">a thread</a>"
+    );
+}
+
+#[test]
+fn strip_unsafe_links() {
+    // arrange
+    let html = "<a href=\"javascript:alert(1)\">one</a> <a href=\" JaVa\tScRiPt:alert(1)\">two</a> <a href=\"data:text/html,hi\">three</a> <a>four</a>";
+
+    // act
+    let sanitized = text::sanitize(html);
+
+    // assert
+    assert_eq!(sanitized.as_str(), "one two three four");
+}
+
+#[test]
+fn strip_disallowed_tags_and_dangerous_content() {
+    // arrange
+    let html = "<div class=\"x\"><b>bold</b> <img src=x onerror=alert(1)></div><script>alert(\"hi\")</script><style>p{}</style><!-- comment -->done";
+
+    // act
+    let sanitized = text::sanitize(html);
+
+    // assert
+    assert_eq!(sanitized.as_str(), "bold done");
+}
+
+#[test]
+fn balance_unclosed_and_stray_tags() {
+    // arrange
+    let html = "<i>open <a href=\"https://example.com\">link</i> text</code>";
+
+    // act
+    let sanitized = text::sanitize(html);
+
+    // assert
+    assert_eq!(
+        sanitized.as_str(),
+        "<i>open <a href=\"https://example.com\" rel=\"nofollow noopener\">link</a></i> text"
+    );
+}
+
+#[test]
+fn sanitize_item_and_user_fields() {
+    // arrange
+    let item: HackerNewsItem = serde_json::from_str(COMMENT_FIXTURE).unwrap();
+    let user: HackerNewsUser = serde_json::from_str(
+        r#"{"id":"pg","created":1160418092,"karma":155111,"about":"Bug fixer.<script>x</script>"}"#,
+    )
+    .unwrap();
+
+    // act
+    let item_text = item.text_sanitized().unwrap();
+    let about = user.about_sanitized().unwrap();
+
+    // assert
+    assert!(item_text.as_str().contains("<p>Tell you what"));
+    assert_eq!(about.to_string(), "Bug fixer.");
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    analytics::ThreadStats,
+    errors::HackerNewsClientError,
+    items::{stories::HackerNewsStory, HackerNewsItem},
+};
+use time::Duration;
+
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+const JOB_FIXTURE: &str = include_str!("fixtures/items/job.json");
+
+async fn stats() -> ThreadStats {
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+    let thread = client.items.get_thread(100).await.unwrap();
+
+    ThreadStats::try_from(&thread).unwrap()
+}
+
+#[tokio::test]
+async fn measure_depth_breadth_and_visibility() {
+    // arrange / act
+    let stats = stats().await;
+
+    // assert
+    assert_eq!(stats.story, 100);
+    assert_eq!(stats.max_depth, 3);
+    assert_eq!(stats.breadth, vec![2, 2, 1]);
+    assert_eq!(stats.total_comments, 5);
+    assert_eq!(stats.visible_comments, 3);
+    assert_eq!(stats.reported_comments, 5);
+    assert_eq!(stats.missing_comments(), 2);
+    assert_eq!(stats.visible_ratio(), Some(0.6));
+}
+
+#[tokio::test]
+async fn rank_participants_and_replied_comments() {
+    // arrange / act
+    let stats = stats().await;
+
+    // assert
+    let commenters: Vec<(&str, usize, usize)> = stats
+        .top_commenters
+        .iter()
+        .map(|participant| {
+            (
+                participant.by.as_str(),
+                participant.comments,
+                participant.replies_received,
+            )
+        })
+        .collect();
+    assert_eq!(commenters, vec![("alice", 2, 2), ("rustacean", 1, 1)]);
+
+    let replied: Vec<(u32, usize, usize)> = stats
+        .most_replied
+        .iter()
+        .map(|comment| (comment.id, comment.replies, comment.total_replies))
+        .collect();
+    assert_eq!(replied, vec![(101, 2, 3), (103, 1, 1)]);
+}
+
+#[tokio::test]
+async fn measure_reply_latency() {
+    // arrange / act
+    let latency = stats().await.reply_latency.unwrap();
+
+    // assert
+    assert_eq!(latency.count, 3);
+    assert_eq!(latency.min, Duration::minutes(10));
+    assert_eq!(latency.median, Duration::minutes(10));
+    assert_eq!(latency.mean, Duration::seconds(1000));
+    assert_eq!(latency.p90, Duration::minutes(30));
+    assert_eq!(latency.max, Duration::minutes(30));
+}
+
+#[test]
+fn handle_stories_without_comments() {
+    // arrange
+    let story: HackerNewsItem = serde_json::from_str(STORY_FIXTURE).unwrap();
+    let story = HackerNewsStory::try_from(story).unwrap();
+
+    // act
+    let stats = ThreadStats::new(&story, &[]);
+
+    // assert
+    assert_eq!(stats.max_depth, 0);
+    assert!(stats.breadth.is_empty());
+    assert!(stats.reply_latency.is_none());
+    assert!(stats.visible_ratio().is_none());
+    assert_eq!(stats.missing_comments(), story.number_of_comments);
+}
+
+#[tokio::test]
+async fn return_an_error_for_threads_not_rooted_at_stories() {
+    // arrange
+    let job: HackerNewsItem = serde_json::from_str(JOB_FIXTURE).unwrap();
+    let client = StubApi::new()
+        .with("item/192327", serde_json::to_value(&job).unwrap())
+        .client()
+        .await;
+    let thread = client.items.get_thread(192327).await.unwrap();
+
+    // act
+    let stats = ThreadStats::try_from(&thread);
+
+    // assert
+    assert!(matches!(
+        stats,
+        Err(HackerNewsClientError::InvalidTypeMapping(_))
+    ));
+}
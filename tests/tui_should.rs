@@ -0,0 +1,158 @@
+#![cfg(feature = "tui")]
+
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    realtime::HackerNewsStoryList,
+    tui::{App, Screen},
+};
+use ratatui::{
+    backend::TestBackend,
+    crossterm::event::{KeyCode, KeyEvent},
+    Terminal,
+};
+use serde_json::json;
+
+async fn app() -> App {
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([100, 103]))
+        .with("showstories", json!([100]))
+        .client()
+        .await;
+
+    let mut app = App::new(client);
+    app.refresh().await;
+    app
+}
+
+async fn press(app: &mut App, code: KeyCode) {
+    app.handle_key(KeyEvent::from(code)).await;
+}
+
+fn screen_text(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let mut text = String::new();
+
+    for y in 0..buffer.area.height {
+        for x in 0..buffer.area.width {
+            text.push_str(buffer[(x, y)].symbol());
+        }
+
+        text.push('\n');
+    }
+
+    text
+}
+
+#[tokio::test]
+async fn load_and_render_top_stories() {
+    // arrange, act
+    let app = app().await;
+
+    // assert
+    assert_eq!(app.list(), HackerNewsStoryList::Top);
+    assert_eq!(app.stories().len(), 2);
+    assert!(app.status().is_none());
+
+    let text = screen_text(&app);
+    assert!(text.contains("Show HN: A Hacker News client written in Rust"));
+    assert!(text.contains("(github.com/rustacean)"));
+    assert!(text.contains("42 points by rustacean | 5 comments"));
+}
+
+#[tokio::test]
+async fn switch_lists_and_move_selection() {
+    // arrange
+    let mut app = app().await;
+
+    // act
+    press(&mut app, KeyCode::Char('j')).await;
+    let selected = app.selected_story().map(|story| story.id);
+    press(&mut app, KeyCode::Char('5')).await;
+
+    // assert
+    assert_eq!(selected, Some(103));
+    assert_eq!(app.list(), HackerNewsStoryList::Show);
+    assert_eq!(app.stories().len(), 1);
+    assert_eq!(app.selected_story().map(|story| story.id), Some(100));
+}
+
+#[tokio::test]
+async fn open_and_collapse_threads() {
+    // arrange
+    let mut app = app().await;
+
+    // act
+    press(&mut app, KeyCode::Enter).await;
+    let expanded = screen_text(&app);
+    press(&mut app, KeyCode::Char(' ')).await;
+    let collapsed = screen_text(&app);
+
+    // assert
+    let Screen::Thread(view) = app.screen() else {
+        panic!("thread should be open");
+    };
+    assert!(view.is_collapsed(101));
+    assert_eq!(view.visible_comments().len(), 2);
+
+    assert!(expanded.contains("This looks great! Does it support async?"));
+    assert!(expanded.contains("    [-] alice"));
+    assert!(expanded.contains("[dead]"));
+    assert!(expanded.contains("[deleted]"));
+    assert!(collapsed.contains("[+] alice (3 hidden)"));
+    assert!(!collapsed.contains("Thanks!"));
+}
+
+#[tokio::test]
+async fn return_to_stories_and_quit() {
+    // arrange
+    let mut app = app().await;
+
+    // act
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Esc).await;
+    let returned = matches!(app.screen(), Screen::Stories);
+    press(&mut app, KeyCode::Char('q')).await;
+
+    // assert
+    assert!(returned);
+    assert!(app.should_quit());
+}
+
+#[tokio::test]
+async fn report_failed_requests_in_the_status_line() {
+    // arrange
+    let mut app = app().await;
+
+    // act
+    press(&mut app, KeyCode::Char('2')).await;
+
+    // assert
+    assert_eq!(app.list(), HackerNewsStoryList::New);
+    assert!(app.status().is_some());
+    assert_eq!(app.stories().len(), 2);
+}
+
+#[tokio::test]
+async fn skip_missing_stories_when_refreshing() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([999, 100]))
+        .client()
+        .await;
+    let mut app = App::new(client);
+
+    // act
+    app.refresh().await;
+
+    // assert
+    assert!(app.status().is_none());
+    assert_eq!(app.stories().len(), 1);
+    assert_eq!(app.stories()[0].id, 100);
+}
@@ -0,0 +1,141 @@
+use newswrap::{
+    items::{stories::HackerNewsStory, HackerNewsItem},
+    urls,
+    users::HackerNewsUser,
+};
+
+const STORY_FIXTURE: &str = include_str!("fixtures/items/story.json");
+
+fn parse(url: &str) -> urls::Url {
+    urls::parse_url(url).expect("url should be valid")
+}
+
+#[test]
+fn ignore_empty_or_malformed_urls() {
+    // arrange, act
+    let empty = urls::parse_url("");
+    let whitespace = urls::parse_url("   ");
+    let relative = urls::parse_url("/not/absolute");
+
+    // assert
+    assert!(empty.is_none());
+    assert!(whitespace.is_none());
+    assert!(relative.is_none());
+}
+
+#[test]
+fn compute_registrable_domains() {
+    // arrange, act, assert
+    assert_eq!(urls::registrable_domain("www.nytimes.com"), "nytimes.com");
+    assert_eq!(
+        urls::registrable_domain("blog.example.co.uk"),
+        "example.co.uk"
+    );
+    assert_eq!(urls::registrable_domain("jvns.github.io"), "jvns.github.io");
+    assert_eq!(urls::registrable_domain("localhost"), "localhost");
+    assert_eq!(urls::registrable_domain("127.0.0.1"), "127.0.0.1");
+}
+
+#[test]
+fn compute_sites_as_hacker_news_displays_them() {
+    // arrange, act, assert
+    assert_eq!(
+        urls::site(&parse(
+            "https://www.nytimes.com/2023/01/01/technology/ai.html"
+        ))
+        .unwrap(),
+        "nytimes.com"
+    );
+    assert_eq!(
+        urls::site(&parse("https://github.com/rust-lang/rust/pull/1")).unwrap(),
+        "github.com/rust-lang"
+    );
+    assert_eq!(
+        urls::site(&parse("https://medium.com/@someone/a-post-1234")).unwrap(),
+        "medium.com/@someone"
+    );
+    assert_eq!(
+        urls::site(&parse("https://github.com/")).unwrap(),
+        "github.com"
+    );
+}
+
+#[test]
+fn canonicalize_away_tracking_parameters() {
+    // arrange
+    let url = parse("https://Example.com/posts/1/?utm_source=hn&b=2&fbclid=abc&a=1#comments");
+
+    // act
+    let canonical = urls::canonicalize(&url);
+
+    // assert
+    assert_eq!(canonical.as_str(), "https://example.com/posts/1?a=1&b=2");
+}
+
+#[test]
+fn keep_parameters_selecting_content_when_canonicalizing() {
+    // arrange
+    let main = parse("https://github.com/rust-lang/rust/tree?ref=main");
+    let beta = parse("https://github.com/rust-lang/rust/tree?ref=beta");
+
+    // act
+    let main = urls::canonicalize(&main);
+    let beta = urls::canonicalize(&beta);
+
+    // assert
+    assert_eq!(
+        main.as_str(),
+        "https://github.com/rust-lang/rust/tree?ref=main"
+    );
+    assert_ne!(main, beta);
+}
+
+#[test]
+fn produce_equal_deduplication_keys_for_equivalent_links() {
+    // arrange
+    let first = parse("http://www.example.com/posts/1/?utm_campaign=launch");
+    let second = parse("https://example.com/posts/1#top");
+    let different = parse("https://example.com/posts/2");
+
+    // act
+    let first_key = urls::deduplication_key(&first);
+    let second_key = urls::deduplication_key(&second);
+    let different_key = urls::deduplication_key(&different);
+
+    // assert
+    assert_eq!(first_key, "example.com/posts/1");
+    assert_eq!(first_key, second_key);
+    assert_ne!(first_key, different_key);
+}
+
+#[test]
+fn generate_permalinks() {
+    // arrange
+    let item: HackerNewsItem = serde_json::from_str(STORY_FIXTURE).unwrap();
+    let story: HackerNewsStory = item.clone().try_into().unwrap();
+    let user: HackerNewsUser =
+        serde_json::from_str(r#"{"id":"dhouston","created":1175289813,"karma":1000}"#).unwrap();
+
+    // act, assert
+    assert_eq!(
+        item.permalink(),
+        "https://news.ycombinator.com/item?id=8863"
+    );
+    assert_eq!(
+        story.permalink(),
+        "https://news.ycombinator.com/item?id=8863"
+    );
+    assert_eq!(
+        user.permalink(),
+        "https://news.ycombinator.com/user?id=dhouston"
+    );
+    assert_eq!(story.site().unwrap(), "getdropbox.com");
+    assert_eq!(
+        story.site_permalink().unwrap(),
+        "https://news.ycombinator.com/from?site=getdropbox.com"
+    );
+    assert_eq!(
+        urls::site_permalink("github.com/rust-lang"),
+        "https://news.ycombinator.com/from?site=github.com/rust-lang"
+    );
+}
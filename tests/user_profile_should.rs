@@ -0,0 +1,141 @@
+mod common;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    analytics::{KarmaTracker, UserProfile},
+    items::{HackerNewsItem, HackerNewsItemType},
+    users::HackerNewsUser,
+};
+use serde_json::{json, Value};
+use time::{Duration, OffsetDateTime, Weekday};
+
+fn user(karma: u32) -> Value {
+    json!({
+        "id": "rustacean",
+        "created": 1600000000,
+        "karma": karma,
+        "submitted": [103, 102, 100]
+    })
+}
+
+#[test]
+fn profile_submissions_by_type_and_time() {
+    // arrange
+    let user: HackerNewsUser = serde_json::from_value(user(1234)).unwrap();
+    let items: Vec<HackerNewsItem> = serde_json::from_str(STORY_THREAD_FIXTURE).unwrap();
+    let as_of = user.created + Duration::days(365);
+
+    // act
+    let profile = UserProfile::new(&user, &items, as_of);
+
+    // assert
+    assert_eq!(profile.username, "rustacean");
+    assert_eq!(profile.karma, 1234);
+    assert_eq!(profile.account_age, Duration::days(365));
+    assert_eq!(profile.total_submissions, 3);
+    assert_eq!(profile.submission_mix.count(HackerNewsItemType::Story), 1);
+    assert_eq!(profile.submission_mix.count(HackerNewsItemType::Comment), 4);
+    assert_eq!(profile.submission_mix.total(), 5);
+    assert_eq!(profile.average_story_score, Some(42.0));
+}
+
+#[test]
+fn build_activity_heatmaps_in_utc() {
+    // arrange
+    let user: HackerNewsUser = serde_json::from_value(user(1234)).unwrap();
+    let items: Vec<HackerNewsItem> = serde_json::from_str(STORY_THREAD_FIXTURE).unwrap();
+
+    // act
+    let profile = UserProfile::new(&user, &items, OffsetDateTime::now_utc());
+
+    // assert
+    assert_eq!(profile.activity.count(Weekday::Tuesday, 22), 4);
+    assert_eq!(profile.activity.count(Weekday::Tuesday, 23), 1);
+    assert_eq!(profile.activity.count(Weekday::Monday, 22), 0);
+    assert_eq!(profile.activity.busiest(), Some((Weekday::Tuesday, 22, 4)));
+}
+
+#[tokio::test]
+async fn fetch_profiles_with_limited_submissions() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("user/rustacean", user(1234))
+        .client()
+        .await;
+
+    // act
+    let profile = UserProfile::fetch(&client, "rustacean", 2).await.unwrap();
+
+    // assert
+    assert_eq!(profile.total_submissions, 3);
+    assert_eq!(profile.submission_mix.total(), 1);
+    assert_eq!(profile.submission_mix.count(HackerNewsItemType::Comment), 1);
+    assert!(profile.average_story_score.is_none());
+}
+
+#[tokio::test]
+async fn leave_missing_submissions_out_of_profiles() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with(
+            "user/rustacean",
+            json!({
+                "id": "rustacean",
+                "created": 1600000000,
+                "karma": 1234,
+                "submitted": [999, 103, 100]
+            }),
+        )
+        .client()
+        .await;
+
+    // act
+    let profile = UserProfile::fetch(&client, "rustacean", 2).await.unwrap();
+
+    // assert
+    assert_eq!(profile.total_submissions, 3);
+    assert_eq!(profile.submission_mix.total(), 1);
+    assert_eq!(profile.submission_mix.count(HackerNewsItemType::Comment), 1);
+}
+
+#[test]
+fn compute_karma_deltas_over_time() {
+    // arrange
+    let mut tracker = KarmaTracker::new(["rustacean"]);
+    let start = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+
+    // act
+    for (day, karma) in [(0, 100), (1, 130), (2, 125), (4, 160)] {
+        let user: HackerNewsUser = serde_json::from_value(user(karma)).unwrap();
+        tracker.record(&user, start + Duration::days(day));
+    }
+
+    // assert
+    let history = tracker.history("rustacean").unwrap();
+    assert_eq!(history.deltas(), vec![30, -5, 35]);
+    assert_eq!(history.net_change(), 60);
+    assert_eq!(history.karma_per_day(), Some(15.0));
+}
+
+#[tokio::test]
+async fn sample_tracked_users_when_polled() {
+    // arrange
+    let client = StubApi::new()
+        .with("user/rustacean", user(1234))
+        .client()
+        .await;
+    let mut tracker = KarmaTracker::new(["rustacean"]);
+
+    // act
+    let samples = tracker.poll(&client).await.unwrap();
+    let removed = tracker.untrack("rustacean");
+
+    // assert
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].0, "rustacean");
+    assert_eq!(samples[0].1.karma, 1234);
+    assert_eq!(removed.unwrap().samples.len(), 1);
+    assert!(tracker.usernames().is_empty());
+}
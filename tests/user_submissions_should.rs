@@ -0,0 +1,27 @@
+use newswrap::users::HackerNewsUser;
+use serde_json::json;
+
+#[test]
+fn read_submissions_from_the_submitted_field() {
+    // arrange
+    let response =
+        json!({ "id": "pg", "created": 1160418092, "karma": 157236, "submitted": [8863, 121003] });
+
+    // act
+    let user: HackerNewsUser = serde_json::from_value(response).unwrap();
+
+    // assert
+    assert_eq!(user.stories, Some(vec![8863, 121003]));
+}
+
+#[test]
+fn read_submissions_from_the_stories_field() {
+    // arrange
+    let response = json!({ "id": "pg", "created": 1160418092, "karma": 157236, "stories": [8863] });
+
+    // act
+    let user: HackerNewsUser = serde_json::from_value(response).unwrap();
+
+    // assert
+    assert_eq!(user.stories, Some(vec![8863]));
+}
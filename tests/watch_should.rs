@@ -0,0 +1,242 @@
+#![cfg(feature = "watch")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    items::HackerNewsItem,
+    watch::{ItemPoller, WatchCondition, WatchEvent, WatchRule, Watcher},
+};
+use regex::Regex;
+use serde_json::json;
+
+fn stub() -> StubApi {
+    StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("maxitem", json!(105))
+}
+
+fn matched(events: &[WatchEvent], rule: &str) -> Vec<u32> {
+    events
+        .iter()
+        .filter(|event| event.rule == rule)
+        .map(|event| event.item.id)
+        .collect()
+}
+
+#[tokio::test]
+async fn emit_events_for_new_items_matching_rules() {
+    // arrange
+    let client = stub().client().await;
+    let mut watcher = Watcher::new()
+        .poller(ItemPoller::new().starting_after(99))
+        .rule(WatchRule::new(
+            "async",
+            WatchCondition::Keyword("async".to_string()),
+        ))
+        .rule(WatchRule::new(
+            "github",
+            WatchCondition::Domain("github.com".to_string()),
+        ))
+        .rule(WatchRule::new(
+            "alice",
+            WatchCondition::Author("Alice".to_string()),
+        ))
+        .rule(WatchRule::new(
+            "replies",
+            WatchCondition::ReplyTo("rustacean".to_string()),
+        ));
+
+    // act
+    let events = watcher.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(matched(&events, "async"), vec![101, 103]);
+    assert_eq!(matched(&events, "github"), vec![100]);
+    assert_eq!(matched(&events, "alice"), vec![101, 105]);
+    assert_eq!(matched(&events, "replies"), vec![101, 105]);
+    assert_eq!(watcher.checkpoint(), Some(105));
+}
+
+#[tokio::test]
+async fn match_whole_keywords_patterns_and_combined_conditions() {
+    // arrange
+    let client = StubApi::new().client().await;
+    let items: Vec<HackerNewsItem> = serde_json::from_str(STORY_THREAD_FIXTURE).unwrap();
+    let mut watcher = Watcher::new()
+        .rule(WatchRule::new(
+            "rust",
+            WatchCondition::Keyword("RUST".to_string()),
+        ))
+        .rule(WatchRule::new(
+            "sync",
+            WatchCondition::Keyword("sync".to_string()),
+        ))
+        .rule(WatchRule::new(
+            "docs",
+            WatchCondition::Pattern(Regex::new(r"(?i)see the docs").unwrap()),
+        ))
+        .rule(WatchRule::new(
+            "alice-replies",
+            WatchCondition::All(vec![
+                WatchCondition::Author("alice".to_string()),
+                WatchCondition::ReplyToItems(vec![103]),
+            ]),
+        ));
+
+    // act
+    let mut events = Vec::new();
+    for item in &items {
+        events.extend(watcher.evaluate(&client, item).await.unwrap());
+    }
+
+    // assert
+    assert_eq!(matched(&events, "rust"), vec![100]);
+    assert!(matched(&events, "sync").is_empty());
+    assert_eq!(matched(&events, "docs"), vec![103]);
+    assert_eq!(matched(&events, "alice-replies"), vec![105]);
+}
+
+#[tokio::test]
+async fn hold_the_checkpoint_before_items_not_yet_available() {
+    // arrange
+    let client = stub().with("maxitem", json!(107)).client().await;
+    let mut poller = ItemPoller::new().starting_after(99);
+
+    // act
+    let items = poller.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(items.len(), 6);
+    assert_eq!(poller.last_seen(), Some(105));
+}
+
+#[tokio::test]
+async fn start_from_the_latest_item_and_catch_up_in_batches() {
+    // arrange
+    let client = stub().client().await;
+    let mut fresh = ItemPoller::new();
+    let mut behind = ItemPoller::new().starting_after(99).max_items_per_poll(2);
+
+    // act
+    let fresh_items = fresh.poll(&client).await.unwrap();
+    let first_batch = behind.poll(&client).await.unwrap();
+    let second_batch = behind.poll(&client).await.unwrap();
+
+    // assert
+    assert!(fresh_items.is_empty());
+    assert_eq!(fresh.last_seen(), Some(105));
+
+    let ids: Vec<u32> = first_batch
+        .iter()
+        .chain(second_batch.iter())
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec![100, 101, 102, 103]);
+    assert_eq!(behind.last_seen(), Some(103));
+}
+
+#[tokio::test]
+async fn notify_score_thresholds_from_updated_items_once() {
+    // arrange
+    let client = stub()
+        .with("updates", json!({ "items": [100], "profiles": [] }))
+        .client()
+        .await;
+    let mut watcher = Watcher::new()
+        .poller(ItemPoller::new().starting_after(105))
+        .rule(WatchRule::new("popular", WatchCondition::MinScore(40)));
+
+    // act
+    let first = watcher.poll(&client).await.unwrap();
+    let second = watcher.poll(&client).await.unwrap();
+
+    // assert
+    assert_eq!(matched(&first, "popular"), vec![100]);
+    assert!(second.is_empty());
+}
+
+#[tokio::test]
+async fn keep_the_checkpoint_and_matches_when_polls_fail() {
+    // arrange
+    let client = stub().client().await;
+    let mut watcher = Watcher::new()
+        .poller(ItemPoller::new().starting_after(99))
+        .rule(WatchRule::new(
+            "async",
+            WatchCondition::Keyword("async".to_string()),
+        ))
+        .rule(WatchRule::new("popular", WatchCondition::MinScore(40)));
+
+    // act
+    let result = watcher.poll(&client).await;
+    let item = client.items.get_item(101).await.unwrap();
+    let events = watcher.evaluate(&client, &item).await.unwrap();
+
+    // assert
+    assert!(result.is_err());
+    assert_eq!(watcher.checkpoint(), Some(99));
+    assert_eq!(matched(&events, "async"), vec![101]);
+}
+
+#[tokio::test]
+async fn forward_events_until_the_receiver_is_dropped() {
+    // arrange
+    let client = stub().client().await;
+    let mut watcher = Watcher::new()
+        .poller(ItemPoller::new().starting_after(99))
+        .rule(WatchRule::new(
+            "alice",
+            WatchCondition::Author("alice".to_string()),
+        ));
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+
+    // act
+    let forwarding = tokio::spawn(async move {
+        watcher
+            .forward(&client, Duration::from_millis(10), sender)
+            .await
+    });
+    let event = receiver.recv().await;
+    drop(receiver);
+
+    // assert
+    assert_eq!(event.map(|event| event.item.id), Some(101));
+    assert!(forwarding.await.unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn keep_forwarding_after_failed_polls() {
+    // arrange
+    let api = stub();
+    let requests = api.requests();
+    let client = api.client().await;
+    let mut watcher = Watcher::new()
+        .poller(ItemPoller::new().starting_after(105))
+        .rule(WatchRule::new("popular", WatchCondition::MinScore(40)));
+    let (sender, receiver) = tokio::sync::mpsc::channel(1);
+
+    // act
+    let forwarding = tokio::spawn(async move {
+        watcher
+            .forward(&client, Duration::from_millis(10), sender)
+            .await
+    });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let still_running = !forwarding.is_finished();
+    drop(receiver);
+
+    // assert
+    assert!(still_running);
+    assert!(forwarding.await.unwrap().is_ok());
+
+    let updates = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|path| path.as_str() == "updates")
+        .count();
+    assert!(updates > 1);
+}
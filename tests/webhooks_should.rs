@@ -0,0 +1,399 @@
+#![cfg(feature = "webhooks")]
+
+mod common;
+
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{extract::State, http::HeaderMap, http::StatusCode, routing::post, Router};
+use bytes::Bytes;
+use common::{StubApi, STORY_THREAD_FIXTURE};
+use newswrap::{
+    client::HackerNewsClient,
+    errors::HackerNewsClientError,
+    watch::{ItemPoller, WatchCondition, WatchRule, Watcher},
+    webhooks::{
+        DeadLetter, EventSource, Webhook, WebhookDispatcher, WebhookEvent, WebhookEventKind,
+    },
+};
+use serde_json::{json, Value};
+
+/// Records deliveries received, responding with an error to the first `failures` requests.
+#[derive(Debug, Default)]
+struct Receiver {
+    failures: usize,
+    attempts: usize,
+    deliveries: Vec<(HeaderMap, Bytes)>,
+}
+
+async fn receive(
+    State(receiver): State<Arc<Mutex<Receiver>>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let mut receiver = receiver.lock().unwrap();
+    receiver.attempts += 1;
+
+    if receiver.attempts <= receiver.failures {
+        return StatusCode::INTERNAL_SERVER_ERROR;
+    }
+
+    receiver.deliveries.push((headers, body));
+    StatusCode::NO_CONTENT
+}
+
+/// Starts a receiver on a random local port, returning its URL.
+fn serve(failures: usize) -> (String, Arc<Mutex<Receiver>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let receiver = Arc::new(Mutex::new(Receiver {
+        failures,
+        ..Receiver::default()
+    }));
+
+    let router = Router::new()
+        .route("/hook", post(receive))
+        .with_state(receiver.clone());
+
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .await
+            .unwrap();
+    });
+
+    (format!("http://{}/hook", address), receiver)
+}
+
+async fn event() -> WebhookEvent {
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .client()
+        .await;
+
+    WebhookEvent::ItemUpdated {
+        item: client.items.get_item(100).await.unwrap(),
+    }
+}
+
+async fn source_client() -> HackerNewsClient {
+    StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("maxitem", json!(105))
+        .with("topstories", json!([1, 100, 2]))
+        .with("updates", json!({ "items": [101], "profiles": [] }))
+        .client()
+        .await
+}
+
+#[tokio::test]
+async fn deliver_signed_json_payloads() {
+    // arrange
+    let (url, receiver) = serve(0);
+    let event = event().await;
+    let mut dispatcher = WebhookDispatcher::new().webhook(Webhook::new(url).secret("shh"));
+
+    // act
+    let delivered = dispatcher.dispatch(&event).await.unwrap();
+
+    // assert
+    assert_eq!(delivered, 1);
+
+    let receiver = receiver.lock().unwrap();
+    let (headers, body) = &receiver.deliveries[0];
+    let payload: Value = serde_json::from_slice(body).unwrap();
+
+    assert_eq!(payload["event"], "item_updated");
+    assert_eq!(payload["item"]["id"], 100);
+    assert_eq!(headers["x-newswrap-event"], "item_updated");
+    assert_eq!(headers["content-type"], "application/json");
+    let timestamp: i64 = headers["x-newswrap-timestamp"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(
+        headers["x-newswrap-signature"].to_str().unwrap(),
+        Webhook::signature("shh", timestamp, body).unwrap()
+    );
+    assert_ne!(
+        Webhook::signature("shh", timestamp + 1, body).unwrap(),
+        Webhook::signature("shh", timestamp, body).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn retry_failed_deliveries() {
+    // arrange
+    let (url, receiver) = serve(2);
+    let event = event().await;
+    let mut dispatcher = WebhookDispatcher::new()
+        .webhook(Webhook::new(url))
+        .max_attempts(3)
+        .retry_delay(Duration::from_millis(10));
+
+    // act
+    let delivered = dispatcher.dispatch(&event).await.unwrap();
+
+    // assert
+    assert_eq!(delivered, 1);
+    assert_eq!(receiver.lock().unwrap().attempts, 3);
+    assert!(dispatcher.dead_letters().is_empty());
+}
+
+#[tokio::test]
+async fn record_undeliverable_events_as_dead_letters() {
+    // arrange
+    let (url, receiver) = serve(usize::MAX);
+    let event = event().await;
+    let log = std::env::temp_dir().join(format!(
+        "newswrap-dead-letters-{}.jsonl",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&log);
+    let mut dispatcher = WebhookDispatcher::new()
+        .webhook(Webhook::new(url.clone()))
+        .max_attempts(2)
+        .retry_delay(Duration::from_millis(10))
+        .dead_letter_log(&log);
+
+    // act
+    let delivered = dispatcher.dispatch(&event).await.unwrap();
+
+    // assert
+    assert_eq!(delivered, 0);
+    assert_eq!(receiver.lock().unwrap().attempts, 2);
+
+    let dead_letters = dispatcher.dead_letters();
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].url, url);
+    assert_eq!(dead_letters[0].attempts, 2);
+    assert_eq!(dead_letters[0].event, event);
+
+    let logged: Vec<DeadLetter> = std::fs::read_to_string(&log)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(logged, dead_letters);
+
+    std::fs::remove_file(&log).unwrap();
+}
+
+#[tokio::test]
+async fn bound_retry_delays_for_long_retry_schedules() {
+    // arrange
+    let (url, receiver) = serve(usize::MAX);
+    let event = event().await;
+    let mut dispatcher = WebhookDispatcher::new()
+        .webhook(Webhook::new(url))
+        .max_attempts(40)
+        .retry_delay(Duration::ZERO);
+
+    // act
+    let delivered = dispatcher.dispatch(&event).await.unwrap();
+
+    // assert
+    assert_eq!(delivered, 0);
+    assert_eq!(receiver.lock().unwrap().attempts, 40);
+    assert_eq!(dispatcher.dead_letters()[0].attempts, 40);
+}
+
+#[tokio::test]
+async fn keep_only_the_most_recent_dead_letters_in_memory() {
+    // arrange
+    let (url, receiver) = serve(usize::MAX);
+    let event = event().await;
+    let mut dispatcher = WebhookDispatcher::new()
+        .webhook(Webhook::new(url))
+        .max_attempts(1);
+
+    // act
+    for _ in 0..1001 {
+        dispatcher.dispatch(&event).await.unwrap();
+    }
+
+    // assert
+    assert_eq!(receiver.lock().unwrap().attempts, 1001);
+    assert_eq!(dispatcher.dead_letters().len(), 1000);
+}
+
+#[tokio::test]
+async fn return_errors_with_their_source_when_dead_letters_cannot_be_logged() {
+    // arrange
+    let (url, _) = serve(usize::MAX);
+    let event = event().await;
+    let mut dispatcher = WebhookDispatcher::new()
+        .webhook(Webhook::new(url))
+        .max_attempts(1)
+        .dead_letter_log(std::env::temp_dir());
+
+    // act
+    let result = dispatcher.dispatch(&event).await;
+
+    // assert
+    let error = result.unwrap_err();
+    assert!(matches!(error, HackerNewsClientError::WebhookError(_)));
+    assert!(std::error::Error::source(&error)
+        .unwrap()
+        .is::<std::io::Error>());
+}
+
+#[tokio::test]
+async fn only_deliver_subscribed_events() {
+    // arrange
+    let (matched_url, matched) = serve(0);
+    let (updated_url, updated) = serve(0);
+    let event = event().await;
+    let mut dispatcher = WebhookDispatcher::new()
+        .webhook(Webhook::new(matched_url).events([WebhookEventKind::ItemMatched]))
+        .webhook(Webhook::new(updated_url).events([WebhookEventKind::ItemUpdated]));
+
+    // act
+    let delivered = dispatcher.dispatch(&event).await.unwrap();
+
+    // assert
+    assert_eq!(delivered, 1);
+    assert!(matched.lock().unwrap().deliveries.is_empty());
+    assert_eq!(updated.lock().unwrap().deliveries.len(), 1);
+}
+
+#[tokio::test]
+async fn discover_events_from_the_api() {
+    // arrange
+    let client = source_client().await;
+    let mut source = EventSource::new()
+        .watcher(
+            Watcher::new()
+                .poller(ItemPoller::new().starting_after(104))
+                .rule(WatchRule::new(
+                    "alice",
+                    WatchCondition::Author("alice".to_string()),
+                )),
+        )
+        .top_stories(true)
+        .known_top_stories([1, 2])
+        .updates(true);
+
+    // act
+    let events = source.poll(&client).await.unwrap();
+
+    // assert
+    let summary: Vec<(WebhookEventKind, u32)> = events
+        .iter()
+        .map(|event| (event.kind(), event.item().id))
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (WebhookEventKind::ItemMatched, 105),
+            (WebhookEventKind::EnteredTopStories, 100),
+            (WebhookEventKind::ItemUpdated, 101),
+        ]
+    );
+    assert!(matches!(
+        &events[1],
+        WebhookEvent::EnteredTopStories { rank: 2, .. }
+    ));
+    assert_eq!(source.current_top_stories(), &[1, 100, 2]);
+}
+
+#[tokio::test]
+async fn not_emit_entered_events_for_the_initial_top_stories() {
+    // arrange
+    let client = source_client().await;
+    let mut source = EventSource::new().top_stories(true);
+
+    // act
+    let first = source.poll(&client).await.unwrap();
+    let second = source.poll(&client).await.unwrap();
+
+    // assert
+    assert!(first.is_empty());
+    assert!(second.is_empty());
+    assert_eq!(source.current_top_stories(), &[1, 100, 2]);
+}
+
+#[tokio::test]
+async fn keep_the_known_top_stories_when_polls_fail() {
+    // arrange
+    let client = StubApi::new()
+        .with_items(STORY_THREAD_FIXTURE)
+        .with("topstories", json!([1, 100, 2]))
+        .client()
+        .await;
+    let mut source = EventSource::new()
+        .top_stories(true)
+        .known_top_stories([1, 2])
+        .updates(true);
+
+    // act
+    let result = source.poll(&client).await;
+
+    // assert
+    assert!(result.is_err());
+    assert_eq!(source.current_top_stories(), &[1, 2]);
+}
+
+#[tokio::test]
+async fn keep_polling_after_failed_polls() {
+    // arrange
+    let api = StubApi::new().with_items(STORY_THREAD_FIXTURE);
+    let requests = api.requests();
+    let client = api.client().await;
+    let mut source = EventSource::new().updates(true);
+    let mut dispatcher = WebhookDispatcher::new();
+
+    // act
+    let running = tokio::spawn(async move {
+        dispatcher
+            .run(&client, &mut source, Duration::from_millis(10))
+            .await
+    });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let still_running = !running.is_finished();
+    running.abort();
+
+    // assert
+    assert!(still_running);
+
+    let updates = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|path| path.as_str() == "updates")
+        .count();
+    assert!(updates > 1);
+}
+
+#[tokio::test]
+async fn stop_polling_past_the_deadline() {
+    use newswrap::cancellation::Cancellation;
+
+    // arrange
+    let client = StubApi::new().client().await;
+    let mut source = EventSource::new().updates(true);
+    let mut dispatcher = WebhookDispatcher::new();
+    let cancellation = Cancellation::new().timeout(Duration::from_millis(300));
+
+    // act
+    let result = dispatcher
+        .run_until(
+            &client,
+            &mut source,
+            Duration::from_millis(10),
+            &cancellation,
+        )
+        .await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(HackerNewsClientError::DeadlineExceeded)
+    ));
+}